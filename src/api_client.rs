@@ -1,15 +1,37 @@
-use std::fmt::{Debug, Display};
+use std::{fmt::Debug, sync::Arc, time::Duration};
 
-use anyhow::{anyhow, Result};
-use serde::{Deserialize, Serialize};
+use futures_util::{stream, Stream, TryStreamExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::{
+    error::{MoySkladError, Result},
     models::{
+        audit::{AuditContext, AuditEvent},
         characteristic::{CharResponse, VariantCharacteristic},
+        custom_entity::{
+            CompanySettingsMetadata, CreateCustomEntity, CustomDictionary,
+            CustomDictionaryMetadata, CustomDictionaryName, UpdateCustomEntity,
+        },
+        document::Template,
+        employee::Employee,
+        file::MsFile,
+        image::{Image, ImageSize, Upload},
+        ms_ref::MsRef,
         CustomEntity, EntityResponse, Meta,
     },
+    query::{FilterOperator, Query, MAX_AUDIT_LIMIT},
+    rate_limit::{
+        RateLimiter, DEFAULT_MAX_CONCURRENT_REQUESTS, DEFAULT_MAX_RETRIES, DEFAULT_RATE_LIMIT,
+        DEFAULT_RATE_LIMIT_PERIOD,
+    },
+    reports::{
+        counterparty::CounterpartyReport,
+        dashboard::{Dashboard, DashboardPeriod},
+        money::MoneyPlotSeries,
+        stock::{CurrentStock, CurrentStockQuery},
+    },
     PriceType,
 };
 static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
@@ -17,7 +39,7 @@ static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_P
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
 /// use anyhow::Result;
 /// use rust_moysklad::{Assortment, MoySkladApiClient};
 /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -41,17 +63,73 @@ static APP_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_P
 #[derive(Debug, Clone)]
 pub struct MoySkladApiClient {
     token: String,
+    base_url: String,
     client: reqwest::Client,
+    limiter: Arc<RateLimiter>,
+}
+/// Корень JSON API по умолчанию
+pub const DEFAULT_BASE_URL: &str = "https://api.moysklad.ru/api/remap/1.2";
+/// Сущность JSON API
+///
+/// Адрес сущности задается путем [`MsEntity::path`] относительно корня API клиента
+pub trait MsEntity: for<'a> Deserialize<'a> + Serialize + Clone + Debug + Send + 'static {
+    /// Путь к сущности относительно корня API, например `entity/product`
+    fn path() -> String;
+    /// Полный адрес сущности с корнем API по умолчанию
+    #[deprecated(note = "use `MsEntity::path`, the API root is configured on the client")]
+    fn url() -> String {
+        format!("{DEFAULT_BASE_URL}/{}", Self::path())
+    }
+    /// Тип сущности в `meta.type`, например `product`
+    fn entity_type() -> String {
+        let path = Self::path();
+        path.rsplit('/').next().unwrap_or_default().to_string()
+    }
 }
-pub trait MsEntity: for<'a> Deserialize<'a> + Serialize + Clone + Debug {
-    fn url() -> String;
+/// Вложенная коллекция сущности `{Parent::path}/{id}/{nested_path}`, например зоны склада
+pub trait MsNested: for<'a> Deserialize<'a> + Serialize + Clone + Debug + Send + 'static {
+    type Parent: MsEntity;
+    /// Путь коллекции относительно родительской сущности, например `zones`
+    fn nested_path() -> String;
+}
+/// Отчет с постраничной выдачей строк, например `report/stock/all`
+pub trait MsReport: for<'a> Deserialize<'a> + Serialize + Clone + Debug + Send + 'static {
+    /// Путь к отчету относительно корня API
+    fn path() -> String;
+}
+/// Сущность с изображениями `{path}/{id}/images`
+pub trait HasImages: MsEntity {}
+/// Сущность с прикрепленными файлами `{path}/{id}/files`
+pub trait HasFiles: MsEntity {}
+/// Документ с вложенной коллекцией позиций `{path}/{id}/positions`
+pub trait HasPositions: MsEntity {
+    type Position: for<'a> Deserialize<'a> + Serialize + Clone + Debug + Send + 'static;
+}
+async fn parse_response<T>(response: reqwest::Response) -> Result<T>
+where
+    T: DeserializeOwned,
+{
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(MoySkladError::from_response(status, body));
+    }
+    serde_json::from_str(&body).map_err(|source| MoySkladError::Deserialize { source, body })
+}
+async fn check_response(response: reqwest::Response) -> Result<()> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    let body = response.text().await?;
+    Err(MoySkladError::from_response(status, body))
 }
 impl MoySkladApiClient {
     /// initialize api client
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::MoySkladApiClient;
     /// #[tokio::main]
@@ -63,17 +141,13 @@ impl MoySkladApiClient {
     /// ```
     pub fn from_env() -> Result<Self> {
         let token = std::env::var("MS_TOKEN")?;
-        let client = reqwest::Client::builder()
-            .user_agent(APP_USER_AGENT)
-            .gzip(true)
-            .build()?;
-        Ok(Self { token, client })
+        Self::builder(token).build()
     }
     /// initialize api client
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::MoySkladApiClient;
     /// #[tokio::main]
@@ -85,18 +159,65 @@ impl MoySkladApiClient {
     /// }
     /// ```
     pub fn new(token: impl AsRef<str>) -> Result<Self> {
-        let token = token.as_ref().to_owned();
-        let client = reqwest::Client::builder()
-            .user_agent(APP_USER_AGENT)
-            .gzip(true)
-            .build()?;
-        Ok(Self { token, client })
+        Self::builder(token).build()
+    }
+    /// configure api client
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use anyhow::Result;
+    /// use rust_moysklad::MoySkladApiClient;
+    /// #[tokio::main]
+    /// async fn main() -> Result<()> {
+    ///     let token = std::env::var("MS_TOKEN")?;
+    ///     let client = MoySkladApiClient::builder(token)
+    ///         .base_url("http://localhost:8080/api/remap/1.2")
+    ///         .build()?;
+    ///     //...do something...
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn builder(token: impl AsRef<str>) -> MoySkladApiClientBuilder {
+        MoySkladApiClientBuilder::new(token)
+    }
+    /// Корень API, относительно которого строятся адреса сущностей
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+    fn endpoint(&self, path: impl AsRef<str>) -> String {
+        format!(
+            "{}/{}",
+            self.base_url,
+            path.as_ref().trim_start_matches('/')
+        )
+    }
+    pub(crate) fn url<E>(&self) -> String
+    where
+        E: MsEntity,
+    {
+        self.endpoint(E::path())
+    }
+    fn nested_url<N>(&self, parent_id: Uuid) -> String
+    where
+        N: MsNested,
+    {
+        format!(
+            "{}/{parent_id}/{}",
+            self.url::<N::Parent>(),
+            N::nested_path()
+        )
+    }
+    /// Отправляет запрос через общий для всех клонов клиента ограничитель запросов
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let request = request.bearer_auth(&self.token).build()?;
+        self.limiter.execute(&self.client, request).await
     }
     /// retrieve list of entity
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::{Assortment, MoySkladApiClient};
     /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -120,48 +241,152 @@ impl MoySkladApiClient {
     where
         E: MsEntity,
     {
-        let limit = 500;
-        let mut offset = 0;
-        let mut result = Vec::new();
-        loop {
-            let uri = format!("{}?limit={limit}&offset={offset}", E::url());
-            let response = self
-                .client
-                .get(&uri)
-                .bearer_auth(&self.token)
-                .send()
-                .await?;
-            match response.status() {
-                reqwest::StatusCode::OK => {
-                    // let res: EntityResponse<E> = response.json().await?;
-                    let val: serde_json::Value = response.json().await?;
-                    if let Ok(res) = serde_json::from_value::<EntityResponse<E>>(val.clone()) {
-                        if res.rows.is_empty() {
-                            break;
-                        } else {
-                            result.extend(res.rows);
-                            offset += limit;
-                        }
-                    } else {
-                        let msg = format!("{val:#?}\n");
-                        return Err(anyhow::Error::msg(msg));
-                    }
-                }
-                _ => {
-                    let err_res: serde_json::Value = response.json().await?;
-                    let msg = format!("{err_res:#?}\n");
-                    return Err(anyhow::Error::msg(msg));
+        self.stream::<E>().try_collect().await
+    }
+
+    /// stream entities page by page
+    ///
+    /// Страницы запрашиваются по мере чтения потока, если поток больше не нужен - запросы прекращаются.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    /// use rust_moysklad::{Assortment, MoySkladApiClient};
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+    ///     let mut assortment = std::pin::pin!(client.stream::<Assortment>());
+    ///     while let Some(item) = assortment.try_next().await? {
+    ///         // ...do something...
+    ///         dbg!(item.name);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn stream<E>(&self) -> impl Stream<Item = Result<E>> + Send + 'static
+    where
+        E: MsEntity,
+    {
+        self.stream_with::<E>(&Query::default())
+    }
+    /// stream search results page by page, see [`MoySkladApiClient::search`]
+    pub fn stream_search<E>(
+        &self,
+        search_string: impl Into<String>,
+    ) -> impl Stream<Item = Result<E>> + Send + 'static
+    where
+        E: MsEntity,
+    {
+        self.stream_with::<E>(Query::new().search(search_string))
+    }
+    /// stream filtered entities page by page, see [`MoySkladApiClient::filter`]
+    pub fn stream_filter<E>(
+        &self,
+        field: impl Into<String>,
+        operator: FilterOperator,
+        value: impl Into<String>,
+    ) -> impl Stream<Item = Result<E>> + Send + 'static
+    where
+        E: MsEntity,
+    {
+        self.stream_with::<E>(Query::new().filter(field, operator, value))
+    }
+    /// stream entities matching the query page by page
+    ///
+    /// `offset` запроса задает начало выборки, `limit` - размер страницы
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    /// use rust_moysklad::{FilterOperator, MoySkladApiClient, Product, Query};
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+    ///     let mut query = Query::new();
+    ///     query
+    ///         .filter("archived", FilterOperator::Equal, "false")
+    ///         .expand("productFolder");
+    ///     let mut products = std::pin::pin!(client.stream_with::<Product>(&query));
+    ///     while let Some(product) = products.try_next().await? {
+    ///         dbg!(product.name);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn stream_with<E>(&self, query: &Query) -> impl Stream<Item = Result<E>> + Send + 'static
+    where
+        E: MsEntity,
+    {
+        self.paginate(self.url::<E>(), query.clone())
+    }
+    /// retrieve all entities matching the query, see [`MoySkladApiClient::stream_with`]
+    #[instrument(skip(self))]
+    pub async fn get_all_with<E>(&self, query: &Query) -> Result<Vec<E>>
+    where
+        E: MsEntity,
+    {
+        self.stream_with::<E>(query).try_collect().await
+    }
+    /// Постраничный обход коллекции по limit/offset
+    fn paginate<T>(
+        &self,
+        url: String,
+        query: Query,
+    ) -> impl Stream<Item = Result<T>> + Send + 'static
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let client = self.clone();
+        let start = query.get_offset();
+        stream::try_unfold(Some(start), move |offset| {
+            let client = client.clone();
+            let url = url.clone();
+            let query = query.clone();
+            async move {
+                match offset {
+                    Some(offset) => client.fetch_page(&url, &query, offset).await,
+                    None => Ok(None),
                 }
             }
+        })
+        .map_ok(|rows| stream::iter(rows.into_iter().map(Ok)))
+        .try_flatten()
+    }
+    /// Загружает страницу коллекции, возвращает строки и offset следующей страницы
+    async fn fetch_page<T>(
+        &self,
+        url: &str,
+        query: &Query,
+        offset: usize,
+    ) -> Result<Option<(Vec<T>, Option<usize>)>>
+    where
+        T: DeserializeOwned,
+    {
+        let limit = query.page_limit()?;
+        let request = self
+            .client
+            .get(url)
+            .query(&query.params()?)
+            .query(&[("limit", limit), ("offset", offset)]);
+        let response = self.send(request).await?;
+        let res: EntityResponse<T> = parse_response(response).await?;
+        if res.rows.is_empty() {
+            return Ok(None);
         }
-        Ok(result)
+        let next = match res.meta.size {
+            Some(size) if offset + limit >= size.max(0) as usize => None,
+            _ => Some(offset + limit),
+        };
+        Ok(Some((res.rows, next)))
     }
 
     /// list entities with limit, offset and optional search
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use rust_moysklad::{MoySkladApiClient, Product};
     /// use tracing::info;
     /// #[tokio::main]
@@ -207,39 +432,52 @@ impl MoySkladApiClient {
         E: MsEntity,
     {
         if limit == 0 || limit > 1000 || offset > 1000 {
-            return Err(anyhow!("Wrong arguments"));
+            return Err(MoySkladError::InvalidArgument(format!(
+                "limit must be in 1..=1000 and offset <= 1000, got limit={limit}, offset={offset}"
+            )));
         }
-        let uri = if let Some(search_string) = search.map(|s| s.as_ref().to_lowercase()) {
-            format!(
-                "{}?limit={limit}&offset={offset}&search={search_string}",
-                E::url()
-            )
-        } else {
-            format!("{}?limit={limit}&offset={offset}", E::url())
-        };
-        let response = self
-            .client
-            .get(&uri)
-            .bearer_auth(&self.token)
-            .send()
-            .await?;
-        match response.status() {
-            reqwest::StatusCode::OK => {
-                let res: EntityResponse<E> = response.json().await?;
-                Ok(res)
-            }
-            _ => {
-                let err_res: serde_json::Value = response.json().await?;
-                let msg = format!("{err_res:#?}\n");
-                Err(anyhow::Error::msg(msg))
-            }
+        let mut query = Query::new();
+        query.limit(limit.into()).offset(offset.into());
+        if let Some(search_string) = search {
+            query.search(search_string.as_ref().to_lowercase());
         }
+        self.list_with::<E>(&query).await
+    }
+    /// list one page of entities matching the query
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rust_moysklad::{MoySkladApiClient, Order, Product, Query};
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+    ///     let page = client
+    ///         .list_with::<Product>(Query::new().order("updated", Order::Desc).limit(10))
+    ///         .await?;
+    ///     dbg!(page.rows.len());
+    ///     Ok(())
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn list_with<E>(&self, query: &Query) -> Result<EntityResponse<E>>
+    where
+        E: MsEntity,
+    {
+        let limit = query.page_limit()?;
+        let request = self
+            .client
+            .get(self.url::<E>())
+            .query(&query.params()?)
+            .query(&[("limit", limit), ("offset", query.get_offset())]);
+        let response = self.send(request).await?;
+        parse_response::<EntityResponse<E>>(response).await
     }
     /// get entity
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::{Currency, MoySkladApiClient};
     /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -266,30 +504,41 @@ impl MoySkladApiClient {
     where
         E: MsEntity,
     {
-        let uri = format!("{}/{id}", E::url());
+        self.get_with::<E>(id, &Query::default()).await
+    }
+    /// get entity with expand/fields from the query
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rust_moysklad::{MoySkladApiClient, Product, Query};
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+    ///     let id = uuid::Uuid::nil();
+    ///     let product = client
+    ///         .get_with::<Product>(id, Query::new().expand("productFolder"))
+    ///         .await?;
+    ///     dbg!(product);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn get_with<E>(&self, id: Uuid, query: &Query) -> Result<E>
+    where
+        E: MsEntity,
+    {
+        let uri = format!("{}/{id}", self.url::<E>());
         let response = self
-            .client
-            .get(&uri)
-            .bearer_auth(&self.token)
-            .send()
+            .send(self.client.get(&uri).query(&query.params()?))
             .await?;
-        match response.status() {
-            reqwest::StatusCode::OK => {
-                let res: E = response.json().await?;
-                Ok(res)
-            }
-            _ => {
-                let err_res: serde_json::Value = response.json().await?;
-                let msg = format!("{err_res:#?}\n");
-                Err(anyhow::Error::msg(msg))
-            }
-        }
+        parse_response::<E>(response).await
     }
     /// Create entity
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::{FilterOperator, MoySkladApiClient, ProductFolder, TaxSystem};
     /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -355,29 +604,15 @@ impl MoySkladApiClient {
         //     .gzip(true)
         //     .build()?;
         let response = self
-            .client
-            .post(E::url())
-            .json(&object)
-            .bearer_auth(&self.token)
-            .send()
+            .send(self.client.post(self.url::<E>()).json(&object))
             .await?;
-        match response.status() {
-            reqwest::StatusCode::OK => {
-                let res: E = response.json().await?;
-                Ok(res)
-            }
-            _ => {
-                let err_res: serde_json::Value = response.json().await?;
-                let msg = format!("{err_res:#?}\n");
-                Err(anyhow::Error::msg(msg))
-            }
-        }
+        parse_response::<E>(response).await
     }
     /// Update entity
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::{FilterOperator, MoySkladApiClient, ProductFolder, TaxSystem};
     /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -442,31 +677,15 @@ impl MoySkladApiClient {
         //     .user_agent(APP_USER_AGENT)
         //     .gzip(true)
         //     .build()?;
-        let uri = format!("{}/{id}", E::url());
-        let response = self
-            .client
-            .put(&uri)
-            .bearer_auth(&self.token)
-            .json(&object)
-            .send()
-            .await?;
-        match response.status() {
-            reqwest::StatusCode::OK => {
-                let res: E = response.json().await?;
-                Ok(res)
-            }
-            _ => {
-                let err_res: serde_json::Value = response.json().await?;
-                let msg = format!("{err_res:#?}\n");
-                Err(anyhow::Error::msg(msg))
-            }
-        }
+        let uri = format!("{}/{id}", self.url::<E>());
+        let response = self.send(self.client.put(&uri).json(&object)).await?;
+        parse_response::<E>(response).await
     }
     /// Delete entity
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::{FilterOperator, MoySkladApiClient, ProductFolder, TaxSystem};
     /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -530,28 +749,16 @@ impl MoySkladApiClient {
         //     .user_agent(APP_USER_AGENT)
         //     .gzip(true)
         //     .build()?;
-        let uri = format!("{}/{id}", E::url());
-        let response = self
-            .client
-            .delete(&uri)
-            .bearer_auth(&self.token)
-            .send()
-            .await?;
-        match response.status() {
-            reqwest::StatusCode::OK => Ok(()),
-            _ => {
-                let err_res: serde_json::Value = response.json().await?;
-                let msg = format!("{err_res:#?}\n");
-                Err(anyhow::Error::msg(msg))
-            }
-        }
+        let uri = format!("{}/{id}", self.url::<E>());
+        let response = self.send(self.client.delete(&uri)).await?;
+        check_response(response).await
     }
     /// Batch create/update entities
     /// for updates required meta field
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::{FilterOperator, MoySkladApiClient, ProductFolder, TaxSystem};
     /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -617,23 +824,9 @@ impl MoySkladApiClient {
         //     .gzip(true)
         //     .build()?;
         let response = self
-            .client
-            .post(E::url())
-            .json(&objects)
-            .bearer_auth(&self.token)
-            .send()
+            .send(self.client.post(self.url::<E>()).json(&objects))
             .await?;
-        match response.status() {
-            reqwest::StatusCode::OK => {
-                let res: Vec<E> = response.json().await?;
-                Ok(res)
-            }
-            _ => {
-                let err_res: serde_json::Value = response.json().await?;
-                let msg = format!("{err_res:#?}\n");
-                Err(anyhow::Error::msg(msg))
-            }
-        }
+        parse_response::<Vec<E>>(response).await
     }
     pub async fn batch_delete<E>(&self, objects: Vec<impl Serialize>) -> Result<()>
     where
@@ -645,22 +838,155 @@ impl MoySkladApiClient {
         //     .user_agent(APP_USER_AGENT)
         //     .gzip(true)
         //     .build()?;
-        let uri = format!("{}/delete", E::url());
-        let response = self
-            .client
-            .post(&uri)
-            .json(&objects)
-            .bearer_auth(&self.token)
-            .send()
-            .await?;
-        match response.status() {
-            reqwest::StatusCode::OK => Ok(()),
-            _ => {
-                let err_res: serde_json::Value = response.json().await?;
-                let msg = format!("{err_res:#?}\n");
-                Err(anyhow::Error::msg(msg))
-            }
-        }
+        let uri = format!("{}/delete", self.url::<E>());
+        let response = self.send(self.client.post(&uri).json(&objects)).await?;
+        check_response(response).await
+    }
+    /// Все элементы вложенной коллекции
+    #[instrument(skip(self))]
+    pub async fn get_all_nested<N>(&self, parent_id: Uuid) -> Result<Vec<N>>
+    where
+        N: MsNested,
+    {
+        self.paginate(self.nested_url::<N>(parent_id), Query::default())
+            .try_collect()
+            .await
+    }
+    /// Элемент вложенной коллекции
+    #[instrument(skip(self))]
+    pub async fn get_nested<N>(&self, parent_id: Uuid, id: Uuid) -> Result<N>
+    where
+        N: MsNested,
+    {
+        let uri = format!("{}/{id}", self.nested_url::<N>(parent_id));
+        let response = self.send(self.client.get(&uri)).await?;
+        parse_response::<N>(response).await
+    }
+    /// Создать элемент вложенной коллекции
+    #[instrument(skip(self))]
+    pub async fn create_nested<N, C>(&self, parent_id: Uuid, object: C) -> Result<N>
+    where
+        N: MsNested,
+        C: Serialize + Debug,
+    {
+        let uri = self.nested_url::<N>(parent_id);
+        let response = self.send(self.client.post(&uri).json(&object)).await?;
+        parse_response::<N>(response).await
+    }
+    /// Изменить элемент вложенной коллекции
+    #[instrument(skip(self))]
+    pub async fn update_nested<N, U>(&self, parent_id: Uuid, id: Uuid, object: U) -> Result<N>
+    where
+        N: MsNested,
+        U: Serialize + Debug,
+    {
+        let uri = format!("{}/{id}", self.nested_url::<N>(parent_id));
+        let response = self.send(self.client.put(&uri).json(&object)).await?;
+        parse_response::<N>(response).await
+    }
+    /// Удалить элемент вложенной коллекции
+    #[instrument(skip(self))]
+    pub async fn delete_nested<N>(&self, parent_id: Uuid, id: Uuid) -> Result<()>
+    where
+        N: MsNested,
+    {
+        let uri = format!("{}/{id}", self.nested_url::<N>(parent_id));
+        let response = self.send(self.client.delete(&uri)).await?;
+        check_response(response).await
+    }
+    /// Шаблон документа на основе другого документа, `PUT {path}/new`
+    ///
+    /// Возвращает предзаполненный, но не сохраненный документ. Его можно изменить и передать в
+    /// [`MoySkladApiClient::create`]
+    #[instrument(skip(self))]
+    pub async fn template<E>(&self, template: &Template) -> Result<serde_json::Value>
+    where
+        E: MsEntity,
+    {
+        let uri = format!("{}/new", self.url::<E>());
+        let response = self.send(self.client.put(&uri).json(template)).await?;
+        parse_response::<serde_json::Value>(response).await
+    }
+    /// Создать документ из шаблона на основе другого документа
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rust_moysklad::{CustomerOrder, Demand, MoySkladApiClient, Template};
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+    ///     let order = client.get::<CustomerOrder>(uuid::Uuid::nil()).await?;
+    ///     let demand: Demand = client
+    ///         .create_from_template(&Template::customer_order(&order.meta))
+    ///         .await?;
+    ///     dbg!(demand.positions.meta.size);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn create_from_template<E>(&self, template: &Template) -> Result<E>
+    where
+        E: MsEntity,
+    {
+        let document = self.template::<E>(template).await?;
+        self.create::<E, _>(document).await
+    }
+    /// Все позиции документа
+    #[instrument(skip(self))]
+    pub async fn get_positions<E>(&self, id: Uuid) -> Result<Vec<E::Position>>
+    where
+        E: HasPositions,
+    {
+        let uri = format!("{}/{id}/positions", self.url::<E>());
+        self.paginate(uri, Query::default()).try_collect().await
+    }
+    /// Позиция документа
+    #[instrument(skip(self))]
+    pub async fn get_position<E>(&self, id: Uuid, position_id: Uuid) -> Result<E::Position>
+    where
+        E: HasPositions,
+    {
+        let uri = format!("{}/{id}/positions/{position_id}", self.url::<E>());
+        let response = self.send(self.client.get(&uri)).await?;
+        parse_response::<E::Position>(response).await
+    }
+    /// Добавить позиции в документ
+    #[instrument(skip(self))]
+    pub async fn add_positions<E, P>(&self, id: Uuid, positions: Vec<P>) -> Result<Vec<E::Position>>
+    where
+        E: HasPositions,
+        P: Serialize + Debug,
+    {
+        let uri = format!("{}/{id}/positions", self.url::<E>());
+        let response = self.send(self.client.post(&uri).json(&positions)).await?;
+        parse_response::<Vec<E::Position>>(response).await
+    }
+    /// Изменить позицию документа
+    #[instrument(skip(self))]
+    pub async fn update_position<E, U>(
+        &self,
+        id: Uuid,
+        position_id: Uuid,
+        position: U,
+    ) -> Result<E::Position>
+    where
+        E: HasPositions,
+        U: Serialize + Debug,
+    {
+        let uri = format!("{}/{id}/positions/{position_id}", self.url::<E>());
+        let response = self.send(self.client.put(&uri).json(&position)).await?;
+        parse_response::<E::Position>(response).await
+    }
+    /// Удалить позицию документа
+    #[instrument(skip(self))]
+    pub async fn delete_position<E>(&self, id: Uuid, position_id: Uuid) -> Result<()>
+    where
+        E: HasPositions,
+    {
+        let uri = format!("{}/{id}/positions/{position_id}", self.url::<E>());
+        let response = self.send(self.client.delete(&uri)).await?;
+        check_response(response).await
     }
     /// Контекстный поиск
     /// В JSON API можно осуществлять контекстный поиск среди списка сущностей определенного типа по их строковым полям. Для этого используется URI параметр фильтрации search
@@ -726,50 +1052,7 @@ impl MoySkladApiClient {
     where
         E: MsEntity,
     {
-        // static APP_USER_AGENT: &str =
-        //     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
-        // let client = reqwest::Client::builder()
-        //     .user_agent(APP_USER_AGENT)
-        //     .gzip(true)
-        //     .build()?;
-        let limit = 1000;
-        let mut offset = 0;
-        let mut result = Vec::new();
-        let search_string: String = search_string.into();
-        loop {
-            let response = self
-                .client
-                .get(E::url())
-                .bearer_auth(&self.token)
-                .query(&[
-                    (limit.to_string(), offset.to_string()),
-                    ("search".to_string(), search_string.clone()),
-                ])
-                .send()
-                .await?;
-            match response.status() {
-                reqwest::StatusCode::OK => {
-                    let res: EntityResponse<E> = response.json().await?;
-                    if res.rows.is_empty() {
-                        break;
-                    } else {
-                        result.extend(res.rows);
-                        if let Some(size) = res.meta.size {
-                            if limit + offset > size {
-                                break;
-                            }
-                        }
-                        offset += limit;
-                    }
-                }
-                _ => {
-                    let err_res: serde_json::Value = response.json().await?;
-                    let msg = format!("{err_res:#?}\n");
-                    return Err(anyhow::Error::msg(msg));
-                }
-            }
-        }
-        Ok(result)
+        self.stream_search::<E>(search_string).try_collect().await
     }
     /// Фильтрация выборки с помощью параметра filter
     /// Для фильтрации выборки по нескольким полям можно использовать url параметр filter. Значение этого параметра - urlencoded строка с поисковыми условиями, перечисленными через ;. Для использования самого символа ; в текстовых фильтрах необходимо указывать два символа \;. (Все примеры ниже указаны без urlencoded для лучшей читаемости) Каждое поисковое условие - это сочетание названия поля, оператора и константы. Фильтровать можно по всем полям, значения которых являются примитивными типами. Т.е. нельзя фильтровать поля-объекты и поля-массивы, все остальные поля могут быть использованы в параметре filter.
@@ -793,7 +1076,7 @@ impl MoySkladApiClient {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::{FilterOperator, MoySkladApiClient, ProductFolder, TaxSystem};
     /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -855,153 +1138,461 @@ impl MoySkladApiClient {
     where
         E: MsEntity,
     {
-        // static APP_USER_AGENT: &str =
-        //     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
-        // let client = reqwest::Client::builder()
-        //     .user_agent(APP_USER_AGENT)
-        //     .gzip(true)
-        //     .build()?;
-        let limit = 1000;
-        let mut offset = 0;
-        let mut result = Vec::new();
-        let search_string = format!("{}{}{}", field.into(), operator, value.into());
-        loop {
-            let response = self
-                .client
-                .get(E::url())
-                .bearer_auth(&self.token)
-                .query(&[
-                    (limit.to_string(), offset.to_string()),
-                    ("filter".to_string(), search_string.clone()),
-                ])
-                .send()
-                .await?;
-            match response.status() {
-                reqwest::StatusCode::OK => {
-                    let res: EntityResponse<E> = response.json().await?;
-                    if res.rows.is_empty() {
-                        break;
-                    } else {
-                        result.extend(res.rows);
-                        if let Some(size) = res.meta.size {
-                            if limit + offset > size {
-                                break;
-                            }
-                        }
-                        offset += limit;
-                    }
-                }
-                _ => {
-                    let err_res: serde_json::Value = response.json().await?;
-                    let msg = format!("{err_res:#?}\n");
-                    return Err(anyhow::Error::msg(msg));
-                }
-            }
+        self.stream_filter::<E>(field, operator, value)
+            .try_collect()
+            .await
+    }
+    /// Строки отчета постранично, см. [`MoySkladApiClient::stream_with`]
+    pub fn report_stream<R>(&self, query: &Query) -> impl Stream<Item = Result<R>> + Send + 'static
+    where
+        R: MsReport,
+    {
+        self.paginate(self.endpoint(R::path()), query.clone())
+    }
+    /// Все строки отчета
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rust_moysklad::{reports::stock::StockByStoreRow, MoySkladApiClient, Query};
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+    ///     let rows = client.get_report::<StockByStoreRow>(&Query::new()).await?;
+    ///     for row in rows {
+    ///         dbg!(row.meta.href, row.stock_by_store.len());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn get_report<R>(&self, query: &Query) -> Result<Vec<R>>
+    where
+        R: MsReport,
+    {
+        self.report_stream::<R>(query).try_collect().await
+    }
+    /// Краткий отчет об остатках `report/stock/all/current` или `report/stock/bystore/current`
+    #[instrument(skip(self))]
+    pub async fn get_current_stock(&self, query: &CurrentStockQuery) -> Result<Vec<CurrentStock>> {
+        let request = self
+            .client
+            .get(self.endpoint(query.path()))
+            .query(&query.params());
+        let response = self.send(request).await?;
+        parse_response(response).await
+    }
+    /// Изображения сущности
+    #[instrument(skip(self))]
+    pub async fn get_images<E>(&self, id: Uuid) -> Result<Vec<Image>>
+    where
+        E: HasImages,
+    {
+        self.paginate(format!("{}/{id}/images", self.url::<E>()), Query::default())
+            .try_collect()
+            .await
+    }
+    /// Добавить изображения к сущности. Возвращает все изображения сущности
+    #[instrument(skip(self, images))]
+    pub async fn add_images<E>(&self, id: Uuid, images: Vec<Upload>) -> Result<Vec<Image>>
+    where
+        E: HasImages,
+    {
+        let uri = format!("{}/{id}/images", self.url::<E>());
+        let response = self.send(self.client.post(&uri).json(&images)).await?;
+        parse_response(response).await
+    }
+    /// Удалить изображение сущности
+    #[instrument(skip(self))]
+    pub async fn delete_image<E>(&self, id: Uuid, image_id: Uuid) -> Result<()>
+    where
+        E: HasImages,
+    {
+        let uri = format!("{}/{id}/images/{image_id}", self.url::<E>());
+        let response = self.send(self.client.delete(&uri)).await?;
+        check_response(response).await
+    }
+    /// Скачать изображение указанного размера
+    #[instrument(skip(self, image))]
+    pub async fn download_image(&self, image: &Image, size: ImageSize) -> Result<Vec<u8>> {
+        let href = image
+            .download_href(size)
+            .ok_or(MoySkladError::InvalidArgument(format!(
+                "image '{}' has no {size:?} version",
+                image.filename
+            )))?;
+        self.download(href).await
+    }
+    /// Файлы сущности
+    #[instrument(skip(self))]
+    pub async fn get_files<E>(&self, id: Uuid) -> Result<Vec<MsFile>>
+    where
+        E: HasFiles,
+    {
+        self.paginate(format!("{}/{id}/files", self.url::<E>()), Query::default())
+            .try_collect()
+            .await
+    }
+    /// Прикрепить файлы к сущности. Возвращает все файлы сущности
+    #[instrument(skip(self, files))]
+    pub async fn add_files<E>(&self, id: Uuid, files: Vec<Upload>) -> Result<Vec<MsFile>>
+    where
+        E: HasFiles,
+    {
+        let uri = format!("{}/{id}/files", self.url::<E>());
+        let response = self.send(self.client.post(&uri).json(&files)).await?;
+        parse_response(response).await
+    }
+    /// Удалить файл сущности
+    #[instrument(skip(self))]
+    pub async fn delete_file<E>(&self, id: Uuid, file_id: Uuid) -> Result<()>
+    where
+        E: HasFiles,
+    {
+        let uri = format!("{}/{id}/files/{file_id}", self.url::<E>());
+        let response = self.send(self.client.delete(&uri)).await?;
+        check_response(response).await
+    }
+    /// Скачать файл
+    #[instrument(skip(self, file))]
+    pub async fn download_file(&self, file: &MsFile) -> Result<Vec<u8>> {
+        let href = file
+            .meta
+            .download_href
+            .as_deref()
+            .ok_or(MoySkladError::InvalidArgument(format!(
+                "file '{}' has no downloadHref",
+                file.filename
+            )))?;
+        self.download(href).await
+    }
+    /// Скачать файл по `downloadHref` с авторизацией
+    #[instrument(skip(self))]
+    pub async fn download(&self, download_href: &str) -> Result<Vec<u8>> {
+        let response = self.send(self.client.get(download_href)).await?;
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await?;
+            return Err(MoySkladError::from_response(status, body));
         }
-        Ok(result)
+        Ok(response.bytes().await?.to_vec())
+    }
+    /// Контексты аудита постранично. Фильтры по `moment`, `employee`, `entityType`, `eventType`
+    /// задаются через [`Query::filter`], размер страницы по умолчанию - 100
+    pub fn audit_stream(
+        &self,
+        query: &Query,
+    ) -> impl Stream<Item = Result<AuditContext>> + Send + 'static {
+        self.paginate(
+            self.endpoint("audit"),
+            query.with_default_limit(MAX_AUDIT_LIMIT),
+        )
+    }
+    /// Все контексты аудита, см. [`MoySkladApiClient::audit_stream`]
+    #[instrument(skip(self))]
+    pub async fn get_audit(&self, query: &Query) -> Result<Vec<AuditContext>> {
+        self.audit_stream(query).try_collect().await
+    }
+    /// События контекста аудита `audit/{id}/events`
+    #[instrument(skip(self))]
+    pub async fn get_audit_events(&self, audit_id: Uuid, query: &Query) -> Result<Vec<AuditEvent>> {
+        self.paginate(
+            self.endpoint(format!("audit/{audit_id}/events")),
+            query.with_default_limit(MAX_AUDIT_LIMIT),
+        )
+        .try_collect()
+        .await
+    }
+    /// События аудита по сущности `{path}/{id}/audit`
+    #[instrument(skip(self))]
+    pub async fn get_entity_audit<E>(&self, id: Uuid, query: &Query) -> Result<Vec<AuditEvent>>
+    where
+        E: MsEntity,
+    {
+        self.paginate(
+            format!("{}/{id}/audit", self.url::<E>()),
+            query.with_default_limit(MAX_AUDIT_LIMIT),
+        )
+        .try_collect()
+        .await
+    }
+    /// Показатели продаж, заказов и денег за день, неделю или месяц
+    #[instrument(skip(self))]
+    pub async fn get_dashboard(&self, period: DashboardPeriod) -> Result<Dashboard> {
+        let response = self
+            .send(self.client.get(self.endpoint(period.path())))
+            .await?;
+        parse_response(response).await
+    }
+    /// Динамика движения денег, период и интервал задаются через [`crate::reports::ReportQuery`]
+    #[instrument(skip(self))]
+    pub async fn get_money_plot_series(&self, query: &Query) -> Result<MoneyPlotSeries> {
+        let request = self
+            .client
+            .get(self.endpoint("report/money/plotseries"))
+            .query(&query.params()?);
+        let response = self.send(request).await?;
+        parse_response(response).await
+    }
+    /// Показатели по одному контрагенту `report/counterparty/{id}`
+    #[instrument(skip(self))]
+    pub async fn get_counterparty_report(
+        &self,
+        counterparty_id: Uuid,
+    ) -> Result<CounterpartyReport> {
+        let uri = self.endpoint(format!("report/counterparty/{counterparty_id}"));
+        let response = self.send(self.client.get(&uri)).await?;
+        parse_response(response).await
+    }
+    /// Загрузить сущность по типизированной ссылке
+    ///
+    /// Запрос выполняется по `meta.href` ссылки, даже если сущность уже раскрыта через `expand`
+    #[instrument(skip(self))]
+    pub async fn resolve<T>(&self, reference: &MsRef<T>) -> Result<T>
+    where
+        T: DeserializeOwned + Debug,
+    {
+        self.get_by_url(&reference.meta.href).await
+    }
+    /// GET по абсолютной ссылке из ответа API, например `meta.href` или `reportUrl`
+    pub(crate) async fn get_by_url<T>(&self, url: &str) -> Result<T>
+    where
+        T: DeserializeOwned,
+    {
+        let response = self.send(self.client.get(url)).await?;
+        parse_response::<T>(response).await
+    }
+    /// Сотрудник, от имени которого выполняются запросы
+    ///
+    /// Ответ содержит права Сотрудника, поэтому метод удобно использовать для проверки токена при старте
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rust_moysklad::MoySkladApiClient;
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+    ///     let employee = client.current_employee().await?;
+    ///     dbg!(employee.uid, employee.permissions.map(|p| p.len()));
+    ///     Ok(())
+    /// }
+    /// ```
+    #[instrument(skip(self))]
+    pub async fn current_employee(&self) -> Result<Employee> {
+        let uri = self.endpoint("context/employee");
+        let response = self.send(self.client.get(&uri)).await?;
+        parse_response(response).await
     }
     /// Типы цен
     pub async fn get_price_types(&self) -> Result<Vec<PriceType>> {
-        let uri = "https://api.moysklad.ru/api/remap/1.2/context/companysettings/pricetype";
+        let uri = self.endpoint("context/companysettings/pricetype");
         // static APP_USER_AGENT: &str =
         //     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
         // let client = reqwest::Client::builder()
         //     .user_agent(APP_USER_AGENT)
         //     .gzip(true)
         //     .build()?;
-        let result: Vec<PriceType> = self
-            .client
-            .get(uri)
-            .bearer_auth(&self.token)
-            .send()
-            .await?
-            .json()
-            .await?;
-        Ok(result)
+        let response = self.send(self.client.get(&uri)).await?;
+        parse_response(response).await
     }
-    /// Получить элементы справочника
+    /// Получить все элементы справочника по метаданным справочника
     pub async fn get_custom_entities(&self, customentity_meta: &Meta) -> Result<Vec<CustomEntity>> {
-        let path = customentity_meta.href.clone();
-        let id_vec = path.split('/').collect::<Vec<&str>>();
-        let id = id_vec
-            .last()
-            .ok_or(anyhow::Error::msg("error getting dictionary id"))?;
-        let uri = format!("https://api.moysklad.ru/api/remap/1.2/entity/customentity/{id}");
-        static APP_USER_AGENT: &str =
-            concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
-        let client = reqwest::Client::builder()
-            .user_agent(APP_USER_AGENT)
-            .gzip(true)
-            .build()?;
-        let response = client.get(uri).bearer_auth(&self.token).send().await?;
-        match response.status() {
-            reqwest::StatusCode::OK => {
-                let res: EntityResponse<CustomEntity> = response.json().await?;
-                Ok(res.rows)
-            }
-            _ => {
-                let err_res: serde_json::Value = response.json().await?;
-                let msg = format!("{err_res:#?}\n");
-                Err(anyhow::Error::msg(msg))
-            }
-        }
+        let id = customentity_meta
+            .id()
+            .ok_or(MoySkladError::InvalidArgument(format!(
+                "error getting dictionary id from '{}'",
+                customentity_meta.href
+            )))?;
+        self.get_custom_elements(id, &Query::default()).await
+    }
+    /// Пользовательские справочники из настроек компании
+    #[instrument(skip(self))]
+    pub async fn get_custom_dictionaries(&self) -> Result<Vec<CustomDictionaryMetadata>> {
+        let uri = self.endpoint("context/companysettings/metadata");
+        let response = self.send(self.client.get(&uri)).await?;
+        let res: CompanySettingsMetadata = parse_response(response).await?;
+        Ok(res.custom_entities)
+    }
+    /// Создать справочник
+    #[instrument(skip(self))]
+    pub async fn create_custom_dictionary(
+        &self,
+        name: impl Into<String> + Debug,
+    ) -> Result<CustomDictionary> {
+        let body = CustomDictionaryName { name: name.into() };
+        let uri = self.endpoint("entity/customentity");
+        let response = self.send(self.client.post(&uri).json(&body)).await?;
+        parse_response(response).await
+    }
+    /// Переименовать справочник
+    #[instrument(skip(self))]
+    pub async fn rename_custom_dictionary(
+        &self,
+        dictionary_id: Uuid,
+        name: impl Into<String> + Debug,
+    ) -> Result<CustomDictionary> {
+        let body = CustomDictionaryName { name: name.into() };
+        let uri = self.endpoint(format!("entity/customentity/{dictionary_id}"));
+        let response = self.send(self.client.put(&uri).json(&body)).await?;
+        parse_response(response).await
+    }
+    /// Удалить справочник вместе с элементами
+    #[instrument(skip(self))]
+    pub async fn delete_custom_dictionary(&self, dictionary_id: Uuid) -> Result<()> {
+        let uri = self.endpoint(format!("entity/customentity/{dictionary_id}"));
+        let response = self.send(self.client.delete(&uri)).await?;
+        check_response(response).await
+    }
+    /// Элементы справочника постранично, см. [`MoySkladApiClient::stream_with`]
+    pub fn custom_elements_stream(
+        &self,
+        dictionary_id: Uuid,
+        query: &Query,
+    ) -> impl Stream<Item = Result<CustomEntity>> + Send + 'static {
+        self.paginate(
+            self.endpoint(format!("entity/customentity/{dictionary_id}")),
+            query.clone(),
+        )
+    }
+    /// Все элементы справочника, подходящие под запрос
+    #[instrument(skip(self))]
+    pub async fn get_custom_elements(
+        &self,
+        dictionary_id: Uuid,
+        query: &Query,
+    ) -> Result<Vec<CustomEntity>> {
+        self.custom_elements_stream(dictionary_id, query)
+            .try_collect()
+            .await
+    }
+    /// Элемент справочника
+    #[instrument(skip(self))]
+    pub async fn get_custom_element(&self, dictionary_id: Uuid, id: Uuid) -> Result<CustomEntity> {
+        let uri = self.endpoint(format!("entity/customentity/{dictionary_id}/{id}"));
+        let response = self.send(self.client.get(&uri)).await?;
+        parse_response(response).await
+    }
+    /// Создать элемент справочника
+    #[instrument(skip(self))]
+    pub async fn create_custom_element(
+        &self,
+        dictionary_id: Uuid,
+        element: CreateCustomEntity,
+    ) -> Result<CustomEntity> {
+        let uri = self.endpoint(format!("entity/customentity/{dictionary_id}"));
+        let response = self.send(self.client.post(&uri).json(&element)).await?;
+        parse_response(response).await
+    }
+    /// Изменить элемент справочника
+    #[instrument(skip(self))]
+    pub async fn update_custom_element(
+        &self,
+        dictionary_id: Uuid,
+        id: Uuid,
+        element: UpdateCustomEntity,
+    ) -> Result<CustomEntity> {
+        let uri = self.endpoint(format!("entity/customentity/{dictionary_id}/{id}"));
+        let response = self.send(self.client.put(&uri).json(&element)).await?;
+        parse_response(response).await
+    }
+    /// Удалить элемент справочника
+    #[instrument(skip(self))]
+    pub async fn delete_custom_element(&self, dictionary_id: Uuid, id: Uuid) -> Result<()> {
+        let uri = self.endpoint(format!("entity/customentity/{dictionary_id}/{id}"));
+        let response = self.send(self.client.delete(&uri)).await?;
+        check_response(response).await
     }
     /// Характеристики модификаций
     pub async fn get_variants_characteristics(&self) -> Result<Vec<VariantCharacteristic>> {
-        let uri = "https://api.moysklad.ru/api/remap/1.2/entity/variant/metadata";
+        let uri = self.endpoint("entity/variant/metadata");
         // static APP_USER_AGENT: &str =
         //     concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
         // let client = reqwest::Client::builder()
         //     .user_agent(APP_USER_AGENT)
         //     .gzip(true)
         //     .build()?;
-        let response = self.client.get(uri).bearer_auth(&self.token).send().await?;
-        match response.status() {
-            reqwest::StatusCode::OK => {
-                let res: CharResponse = response.json().await?;
-                Ok(res.characteristics)
-            }
-            _ => {
-                let err_res: serde_json::Value = response.json().await?;
-                let msg = format!("{err_res:#?}\n");
-                Err(anyhow::Error::msg(msg))
-            }
-        }
+        let response = self.send(self.client.get(&uri)).await?;
+        let res: CharResponse = parse_response(response).await?;
+        Ok(res.characteristics)
     }
 }
-/// Доступные операторы для фильтрации
-pub enum FilterOperator {
-    /// `=` - фильтрация по значению
-    Equal,
-    /// `~` - частичное совпадение
-    PartialMatch,
-    /// `!~` - частичное совпадение не выводится
-    NoPartialMatch,
-    /// `~=` - полное совпадение в начале значения
-    FullMatchAtTheBeginning,
-    /// `=~` - полное совпадение в конце значения
-    CompleteMatchAtTheEnd,
-    /// `>` - больше
-    GreaterThan,
-    /// `<` - меньше
-    LesserThan,
-    /// `>=` - больше или равно
-    GreaterThanOrEqual,
-    /// `<=` - меньше или равно
-    LesserThanOrEqual,
+/// Настройка клиента API
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use anyhow::Result;
+/// use rust_moysklad::MoySkladApiClientBuilder;
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let token = std::env::var("MS_TOKEN")?;
+///     let client = MoySkladApiClientBuilder::new(token)
+///         .base_url("http://127.0.0.1:3000/api/remap/1.2")
+///         .build()?;
+///     //...do something...
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MoySkladApiClientBuilder {
+    token: String,
+    base_url: String,
+    rate_limit: usize,
+    rate_limit_period: Duration,
+    max_concurrent_requests: usize,
+    max_retries: u32,
 }
-impl Display for FilterOperator {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            FilterOperator::Equal => write!(f, "="),
-            FilterOperator::PartialMatch => write!(f, "~"),
-            FilterOperator::NoPartialMatch => write!(f, "!~"),
-            FilterOperator::FullMatchAtTheBeginning => write!(f, "~="),
-            FilterOperator::CompleteMatchAtTheEnd => write!(f, "=~"),
-            FilterOperator::GreaterThan => write!(f, ">"),
-            FilterOperator::LesserThan => write!(f, "<"),
-            FilterOperator::GreaterThanOrEqual => write!(f, ">="),
-            FilterOperator::LesserThanOrEqual => write!(f, "<="),
+impl MoySkladApiClientBuilder {
+    pub fn new(token: impl AsRef<str>) -> Self {
+        Self {
+            token: token.as_ref().to_owned(),
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            rate_limit: DEFAULT_RATE_LIMIT,
+            rate_limit_period: DEFAULT_RATE_LIMIT_PERIOD,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            max_retries: DEFAULT_MAX_RETRIES,
         }
     }
+    /// Корень JSON API, например адрес локального тестового сервера или прокси.
+    /// По умолчанию `https://api.moysklad.ru/api/remap/1.2`
+    pub fn base_url(&mut self, base_url: impl AsRef<str>) -> &mut Self {
+        self.base_url = base_url.as_ref().trim_end_matches('/').to_owned();
+        self
+    }
+    /// Не более `max_requests` запросов за период `period`.
+    /// По умолчанию 45 запросов за 3 секунды
+    pub fn rate_limit(&mut self, max_requests: usize, period: Duration) -> &mut Self {
+        self.rate_limit = max_requests;
+        self.rate_limit_period = period;
+        self
+    }
+    /// Максимальное количество параллельных запросов. По умолчанию 5
+    pub fn max_concurrent_requests(&mut self, max_concurrent_requests: usize) -> &mut Self {
+        self.max_concurrent_requests = max_concurrent_requests;
+        self
+    }
+    /// Количество повторов запроса после ответов 429 и 503. По умолчанию 5
+    pub fn max_retries(&mut self, max_retries: u32) -> &mut Self {
+        self.max_retries = max_retries;
+        self
+    }
+    pub fn build(&self) -> Result<MoySkladApiClient> {
+        let client = reqwest::Client::builder()
+            .user_agent(APP_USER_AGENT)
+            .gzip(true)
+            .build()?;
+        let limiter = RateLimiter::new(
+            self.rate_limit,
+            self.rate_limit_period,
+            self.max_concurrent_requests,
+            self.max_retries,
+        );
+        Ok(MoySkladApiClient {
+            token: self.token.to_owned(),
+            base_url: self.base_url.to_owned(),
+            client,
+            limiter: Arc::new(limiter),
+        })
+    }
 }
@@ -0,0 +1,149 @@
+use std::fmt::Display;
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+pub type Result<T> = std::result::Result<T, MoySkladError>;
+
+/// Ошибки при работе с API "Мой Склад"
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{MoySkladApiClient, MoySkladError, Product};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env()?;
+///     match client.get::<Product>(uuid::Uuid::nil()).await {
+///         Ok(product) => println!("{product:?}"),
+///         Err(MoySkladError::Api { status, errors }) if status.as_u16() == 404 => {
+///             println!("not found: {errors:?}")
+///         }
+///         Err(e) => return Err(e.into()),
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub enum MoySkladError {
+    /// API вернул ошибку с массивом `errors` в теле ответа
+    Api {
+        status: StatusCode,
+        errors: Vec<ApiError>,
+    },
+    /// API вернул неуспешный статус, тело ответа не содержит `errors`
+    Status { status: StatusCode, body: String },
+    /// Не удалось десериализовать ответ, `body` - исходное тело ответа
+    Deserialize {
+        source: serde_json::Error,
+        body: String,
+    },
+    /// Ошибка соединения или HTTP клиента
+    Transport(reqwest::Error),
+    /// Неверные параметры запроса
+    InvalidArgument(String),
+    /// Не задана переменная окружения
+    Env(std::env::VarError),
+}
+impl MoySkladError {
+    /// HTTP статус ответа, если ошибка вернулась от API
+    pub fn status(&self) -> Option<StatusCode> {
+        match self {
+            MoySkladError::Api { status, .. } | MoySkladError::Status { status, .. } => {
+                Some(*status)
+            }
+            MoySkladError::Transport(e) => e.status(),
+            _ => None,
+        }
+    }
+    /// Ошибки из массива `errors` ответа API
+    pub fn api_errors(&self) -> &[ApiError] {
+        match self {
+            MoySkladError::Api { errors, .. } => errors,
+            _ => &[],
+        }
+    }
+    pub(crate) fn from_response(status: StatusCode, body: String) -> Self {
+        match serde_json::from_str::<ErrorResponse>(&body) {
+            Ok(res) if !res.errors.is_empty() => MoySkladError::Api {
+                status,
+                errors: res.errors,
+            },
+            _ => MoySkladError::Status { status, body },
+        }
+    }
+}
+impl Display for MoySkladError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MoySkladError::Api { status, errors } => {
+                write!(f, "API error {status}")?;
+                for error in errors {
+                    write!(f, "; {error}")?;
+                }
+                Ok(())
+            }
+            MoySkladError::Status { status, body } => write!(f, "HTTP error {status}: {body}"),
+            MoySkladError::Deserialize { source, .. } => {
+                write!(f, "failed to deserialize response: {source}")
+            }
+            MoySkladError::Transport(e) => write!(f, "transport error: {e}"),
+            MoySkladError::InvalidArgument(msg) => write!(f, "invalid argument: {msg}"),
+            MoySkladError::Env(e) => write!(f, "environment variable error: {e}"),
+        }
+    }
+}
+impl std::error::Error for MoySkladError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MoySkladError::Deserialize { source, .. } => Some(source),
+            MoySkladError::Transport(e) => Some(e),
+            MoySkladError::Env(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<reqwest::Error> for MoySkladError {
+    fn from(value: reqwest::Error) -> Self {
+        MoySkladError::Transport(value)
+    }
+}
+impl From<std::env::VarError> for MoySkladError {
+    fn from(value: std::env::VarError) -> Self {
+        MoySkladError::Env(value)
+    }
+}
+
+/// Ошибка из массива `errors` ответа API
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiError {
+    /// Заголовок ошибки
+    pub error: String,
+    /// Код ошибки
+    pub code: Option<i32>,
+    /// Параметр, на котором произошла ошибка
+    pub parameter: Option<String>,
+    /// Ссылка на документацию с описанием ошибки
+    pub more_info: Option<String>,
+    /// Сообщение, прилагаемое к ошибке
+    pub error_message: Option<String>,
+}
+impl Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(code) = self.code {
+            write!(f, "[{code}] ")?;
+        }
+        write!(f, "{}", self.error)?;
+        if let Some(parameter) = &self.parameter {
+            write!(f, " (parameter: {parameter})")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    errors: Vec<ApiError>,
+}
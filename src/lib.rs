@@ -3,18 +3,58 @@
 //! `rust-moysklad` Библиотека для работы с API сервиса "Мой Склад".
 
 mod api_client;
+mod error;
 mod models;
-pub use api_client::{FilterOperator, MoySkladApiClient};
+mod query;
+mod rate_limit;
+pub mod reports;
+#[cfg(feature = "webhook")]
+pub mod webhook;
+pub use api_client::{
+    HasFiles, HasImages, HasPositions, MoySkladApiClient, MoySkladApiClientBuilder, MsEntity,
+    MsNested, MsReport, DEFAULT_BASE_URL,
+};
+pub use error::{ApiError, MoySkladError, Result};
 pub use models::{
     assortment::Assortment,
+    audit::{AuditContext, AuditDiff, AuditEvent, AuditEventType},
     characteristic::Characteristic,
-    counterparty::{CompanyType, Counterparty, Sex},
+    counterparty::{Counterparty, Sex},
     country::Country,
     currency::Currency,
+    custom_entity::{CustomDictionary, CustomDictionaryMetadata, CustomEntity},
+    customer_order::{CustomerOrder, CustomerOrderPosition},
+    demand::{Demand, DemandPosition},
+    document::{
+        CreatePosition, CreatePositionBuilder, DiscountPositions, Overhead, OverheadDistribution,
+        Positions, Rate, ReasonPositions, ReservePositions, SlotPositions, Template,
+        ThingPositions, TransferPositions, UpdatePosition, UpdatePositionBuilder,
+    },
+    employee::{Cashier, Employee, EntityPermission, Permission, PermissionScope},
+    enter::{Enter, EnterPosition},
+    file::MsFile,
+    image::{Image, ImageSize, Upload},
+    inventory::{Inventory, InventoryPosition},
+    invoice_in::{InvoiceIn, InvoiceInPosition},
+    legal::{Address, CompanyType, LegalDetails},
+    loss::{Loss, LossPosition},
+    move_document::{Move, MovePosition},
+    ms_ref::MsRef,
+    organization::{BankAccount, CounterpartyAccount, Organization, OrganizationAccount},
     product::Product,
     product_folder::ProductFolder,
+    purchase_order::{PurchaseOrder, PurchaseOrderPosition},
+    purchase_return::{PurchaseReturn, PurchaseReturnPosition},
     region::Region,
+    sales_return::{SalesReturn, SalesReturnPosition},
+    state::{State, StateType},
+    store::{Store, StoreSlot, StoreZone},
+    supply::{Supply, SupplyPosition},
     uom::Uom,
     variant::Variant,
-    Attribute, AttributeValue, CustomValue, PriceType, ProductsCustomField, TaxSystem,
+    webhook::{DiffType, StockReportType, Webhook, WebhookAction, WebhookStock},
+    Attribute, AttributeMetadata, AttributeType, AttributeValue, CreateAttributeMetadata,
+    CustomValue, DownloadMeta, FileValue, FromAttributeValue, HasAttributes, Meta, MetaWrapper,
+    PriceType, ProductsCustomField, TaxSystem,
 };
+pub use query::{FilterOperator, Order, Query};
@@ -6,7 +6,9 @@ use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
 use super::{
-    characteristic::Characteristic, deserialize_option_date_from_str, Attribute, PriceType,
+    characteristic::Characteristic, counterparty::Counterparty, country::Country,
+    deserialize_option_date_from_str, employee::Employee, ms_ref::MsRef, product::Product,
+    product_folder::ProductFolder, uom::Uom, Attribute, PriceType,
 };
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -15,7 +17,7 @@ pub struct Assortment {
     pub meta: Meta,
     pub id: uuid::Uuid,
     pub account_id: uuid::Uuid,
-    pub owner: Option<MetaWrapper>,
+    pub owner: Option<MsRef<Employee>>,
     pub shared: Option<bool>,
     pub group: Option<MetaWrapper>,
     #[serde(deserialize_with = "deserialize_option_date_from_str")]
@@ -25,21 +27,21 @@ pub struct Assortment {
     pub external_code: Option<String>,
     pub archived: Option<bool>,
     pub path_name: Option<String>,
-    pub product_folder: Option<MetaWrapper>,
+    pub product_folder: Option<MsRef<ProductFolder>>,
     pub use_parent_vat: Option<bool>,
     pub vat: Option<i64>,
     pub vat_enabled: Option<bool>,
     pub effective_vat: Option<i64>,
     pub effective_vat_enabled: Option<bool>,
-    pub uom: Option<MetaWrapper>,
+    pub uom: Option<MsRef<Uom>>,
     pub images: Option<MetaWrapper>,
     pub min_price: Option<MinPrice>,
     pub sale_prices: Vec<SalePrice>,
-    pub supplier: Option<MetaWrapper>,
+    pub supplier: Option<MsRef<Counterparty>>,
     pub attributes: Option<Vec<Attribute>>,
     pub payment_item_type: Option<String>,
     pub discount_prohibited: Option<bool>,
-    pub country: Option<MetaWrapper>,
+    pub country: Option<MsRef<Country>>,
     pub buy_price: Option<BuyPrice>,
     pub article: Option<String>,
     pub weight: Option<f64>,
@@ -57,13 +59,14 @@ pub struct Assortment {
     pub assortment: Option<MetaWrapper>,
     pub components: Option<MetaWrapper>,
     pub characteristics: Option<Vec<Characteristic>>,
-    pub product: Option<MetaWrapper>,
+    pub product: Option<MsRef<Product>>,
 }
 impl MsEntity for Assortment {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/assortment")
+    fn path() -> String {
+        String::from("entity/assortment")
     }
 }
+impl_has_attributes!(Assortment);
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -0,0 +1,268 @@
+use std::{collections::HashMap, fmt::Display};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{deserialize_date_from_str, Meta, MetaWrapper};
+
+/// Контекст изменения: операция сотрудника, в рамках которой изменены одна или несколько сущностей
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{AuditEventType, FilterOperator, MoySkladApiClient, Product, Query};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let mut query = Query::new();
+///     query
+///         .filter("moment", FilterOperator::GreaterThanOrEqual, "2024-01-01 00:00:00")
+///         .filter("entityType", FilterOperator::Equal, "product")
+///         .filter("eventType", FilterOperator::Equal, AuditEventType::Update.to_string());
+///     for context in client.get_audit(&query).await? {
+///         if let Some(id) = context.id {
+///             let events = client.get_audit_events(id, &Query::new()).await?;
+///             dbg!(context.uid, context.moment, events.len());
+///         }
+///     }
+///     let events = client
+///         .get_entity_audit::<Product>(uuid::Uuid::nil(), &Query::new())
+///         .await?;
+///     for event in events {
+///         if let Some(change) = event.diff.as_ref().and_then(|diff| diff.get("salePrices")) {
+///             dbg!(event.uid, event.moment, change);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditContext {
+    /// Метаданные контекста
+    pub meta: Meta,
+    /// ID контекста. Не заполняется в запросах вебхуков
+    pub id: Option<Uuid>,
+    /// Логин сотрудника
+    pub uid: String,
+    /// Момент изменения
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Тип события
+    pub event_type: Option<AuditEventType>,
+    /// Тип сущности, например `product`. Для изменений нескольких типов не заполняется
+    pub entity_type: Option<String>,
+    /// Количество измененных сущностей
+    pub object_count: Option<i64>,
+    /// Источник изменения, например `jsonapi` или `dataimport`
+    pub source: Option<String>,
+    /// Краткое описание
+    pub info: Option<String>,
+    /// Выполнено ли изменение службой поддержки
+    pub support_access: Option<bool>,
+    /// Метаданные списка событий контекста
+    pub events: Option<MetaWrapper>,
+}
+/// Событие аудита по отдельной сущности
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEvent {
+    /// Дополнительная информация о событии
+    pub additional_info: Option<String>,
+    /// Метаданные контекста
+    pub audit: MetaWrapper,
+    /// Изменения полей сущности по ключу поля. Заполняется для событий изменения
+    pub diff: Option<HashMap<String, AuditDiff>>,
+    /// Метаданные измененной сущности
+    pub entity: Option<MetaWrapper>,
+    /// Тип сущности
+    pub entity_type: String,
+    /// Тип события
+    pub event_type: AuditEventType,
+    /// Момент события
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование сущности
+    pub name: Option<String>,
+    /// Количество измененных сущностей
+    pub object_count: Option<i64>,
+    /// Тип измененных сущностей
+    pub object_type: Option<String>,
+    /// Источник изменения
+    pub source: Option<String>,
+    /// Выполнено ли изменение службой поддержки
+    pub support_access: Option<bool>,
+    /// Логин сотрудника
+    pub uid: String,
+}
+/// Изменение поля сущности
+///
+/// [`AuditDiff::Change`] - объект, в котором есть только `oldValue` и/или `newValue`,
+/// любые другие формы изменения остаются в [`AuditDiff::Other`]
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum AuditDiff {
+    /// Старое и новое значение поля
+    Change {
+        #[serde(rename = "oldValue")]
+        old_value: Option<serde_json::Value>,
+        #[serde(rename = "newValue")]
+        new_value: Option<serde_json::Value>,
+    },
+    /// Изменение в другом формате, например для коллекций
+    Other(serde_json::Value),
+}
+impl<'de> Deserialize<'de> for AuditDiff {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const OLD_VALUE: &str = "oldValue";
+        const NEW_VALUE: &str = "newValue";
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let is_change = value.as_object().is_some_and(|object| {
+            !object.is_empty()
+                && object
+                    .keys()
+                    .all(|key| key == OLD_VALUE || key == NEW_VALUE)
+        });
+        if !is_change {
+            return Ok(AuditDiff::Other(value));
+        }
+        let field = |key: &str| value.get(key).filter(|v| !v.is_null()).cloned();
+        Ok(AuditDiff::Change {
+            old_value: field(OLD_VALUE),
+            new_value: field(NEW_VALUE),
+        })
+    }
+}
+/// Тип события аудита
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditEventType {
+    /// Регистрация
+    Registration,
+    /// Массовая операция
+    BulkOperation,
+    /// Снятие публикации
+    ClosePublication,
+    /// Создание
+    Create,
+    /// Удаление
+    Delete,
+    /// Публикация
+    OpenPublication,
+    /// Печать
+    Print,
+    /// Помещение в корзину
+    PutToTrash,
+    /// Замена токена
+    ReplaceToken,
+    /// Восстановление из корзины
+    RestoreFromTrash,
+    /// Отправка письма
+    SendEmailFromEntity,
+    /// Изменение
+    #[default]
+    Update,
+    /// Тип, не поддерживаемый библиотекой
+    #[serde(other)]
+    Other,
+}
+impl Display for AuditEventType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuditEventType::Registration => write!(f, "registration"),
+            AuditEventType::BulkOperation => write!(f, "bulkoperation"),
+            AuditEventType::ClosePublication => write!(f, "closepublication"),
+            AuditEventType::Create => write!(f, "create"),
+            AuditEventType::Delete => write!(f, "delete"),
+            AuditEventType::OpenPublication => write!(f, "openpublication"),
+            AuditEventType::Print => write!(f, "print"),
+            AuditEventType::PutToTrash => write!(f, "puttotrash"),
+            AuditEventType::ReplaceToken => write!(f, "replacetoken"),
+            AuditEventType::RestoreFromTrash => write!(f, "restorefromtrash"),
+            AuditEventType::SendEmailFromEntity => write!(f, "sendemailfromentity"),
+            AuditEventType::Update => write!(f, "update"),
+            AuditEventType::Other => write!(f, "other"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn change_diff_keeps_values() {
+        let diff: AuditDiff =
+            serde_json::from_value(json!({ "oldValue": "Ковер", "newValue": "Ковролин" })).unwrap();
+        assert_eq!(
+            diff,
+            AuditDiff::Change {
+                old_value: Some(json!("Ковер")),
+                new_value: Some(json!("Ковролин")),
+            }
+        );
+    }
+
+    #[test]
+    fn change_diff_with_one_side() {
+        let diff: AuditDiff = serde_json::from_value(json!({ "newValue": 10 })).unwrap();
+        assert_eq!(
+            diff,
+            AuditDiff::Change {
+                old_value: None,
+                new_value: Some(json!(10)),
+            }
+        );
+        let diff: AuditDiff =
+            serde_json::from_value(json!({ "oldValue": "x", "newValue": null })).unwrap();
+        assert_eq!(
+            diff,
+            AuditDiff::Change {
+                old_value: Some(json!("x")),
+                new_value: None,
+            }
+        );
+    }
+
+    #[test]
+    fn collection_diff_is_other() {
+        let raw = json!({
+            "addedItems": [{ "name": "Цена продажи", "value": 1000 }],
+            "removedItems": []
+        });
+        let diff: AuditDiff = serde_json::from_value(raw.clone()).unwrap();
+        assert_eq!(diff, AuditDiff::Other(raw));
+    }
+
+    #[test]
+    fn partially_matching_object_is_other() {
+        let raw = json!({ "oldValue": 1, "newValue": 2, "attribute": "Вес" });
+        let diff: AuditDiff = serde_json::from_value(raw.clone()).unwrap();
+        assert_eq!(diff, AuditDiff::Other(raw));
+    }
+
+    #[test]
+    fn empty_object_and_scalars_are_other() {
+        for raw in [json!({}), json!("изменено"), json!([1, 2])] {
+            let diff: AuditDiff = serde_json::from_value(raw.clone()).unwrap();
+            assert_eq!(diff, AuditDiff::Other(raw));
+        }
+    }
+
+    #[test]
+    fn diff_map_mixes_shapes() {
+        let diff: HashMap<String, AuditDiff> = serde_json::from_value(json!({
+            "name": { "oldValue": "a", "newValue": "b" },
+            "salePrices": { "addedItems": [], "removedItems": [] }
+        }))
+        .unwrap();
+        assert!(matches!(diff["name"], AuditDiff::Change { .. }));
+        assert!(matches!(diff["salePrices"], AuditDiff::Other(_)));
+    }
+}
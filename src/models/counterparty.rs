@@ -1,9 +1,14 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use crate::api_client::MsEntity;
+use crate::api_client::{HasFiles, MsEntity};
 
-use super::{deserialize_date_from_str, Attribute, Meta, MetaWrapper, PriceType};
+use super::{
+    deserialize_date_from_str, employee::Employee, legal::LegalDetails, ms_ref::MsRef,
+    state::State, Attribute, Meta, MetaWrapper, PriceType,
+};
+
+pub use super::legal::{Address, CompanyType};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -56,7 +61,7 @@ pub struct Counterparty {
     /// Массив событий Контрагента. Подробнее тут
     pub notes: MetaWrapper,
     /// Владелец (Сотрудник)
-    pub owner: Option<MetaWrapper>,
+    pub owner: Option<MsRef<Employee>>,
     /// Номер городского телефона
     pub phone: Option<String>,
     /// Тип цены Контрагента. Подробнее тут
@@ -66,7 +71,7 @@ pub struct Counterparty {
     /// Общий доступ
     pub shared: bool,
     /// Метаданные Статуса Контрагента
-    pub state: Option<MetaWrapper>,
+    pub state: Option<MsRef<State>>,
     /// ID синхронизации
     pub sync_id: Option<uuid::Uuid>,
     /// Группы контрагента
@@ -74,57 +79,11 @@ pub struct Counterparty {
     /// Момент последнего обновления Контрагента
     #[serde(deserialize_with = "deserialize_date_from_str")]
     pub updated: NaiveDateTime,
-    /// Полное наименование Контрагента
-    pub legal_title: Option<String>,
-    /// Юридического адреса Контрагента
-    pub legal_address: Option<String>,
-    /// Юридический адрес Контрагента с детализацией по отдельным полям
-    pub legal_address_full: Option<Address>,
-    /// ИНН
-    pub inn: Option<String>,
-    /// КПП
-    pub kpp: Option<String>,
-    /// ОГРН
-    pub ogrn: Option<String>,
-    /// ОКПО
-    pub okpo: Option<String>,
-    /// ОКПО
-    pub ogrnip: Option<String>,
-    /// Фамилия для Контрагента типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для Контрагентов типа [Юридическое лицо]
-    pub legal_last_name: Option<String>,
-    /// Имя для Контрагента типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для Контрагентов типа [Юридическое лицо]
-    pub legal_first_name: Option<String>,
-    /// Отчество для Контрагента типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для Контрагентов типа [Юридическое лицо]
-    pub legal_middle_name: Option<String>,
     /// Пол Контрагента
     pub sex: Option<Sex>,
-    /// Номер свидетельства
-    pub certificate_number: Option<String>,
-    /// Дата свидетельства
-    pub certificate_date: Option<String>,
-}
-/// Адрес
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct Address {
-    /// Другое
-    pub add_info: Option<String>,
-    /// Почтовый индекс
-    pub postal_code: Option<String>,
-    /// Метаданные страны
-    pub country: Option<MetaWrapper>,
-    /// Метаданные региона
-    pub region: Option<MetaWrapper>,
-    /// Город
-    pub city: Option<String>,
-    /// Улица
-    pub street: Option<String>,
-    /// Дом
-    pub house: Option<String>,
-    /// Квартира
-    pub apartment: Option<String>,
-    /// Комментарий
-    pub comment: Option<String>,
+    /// Реквизиты Контрагента
+    #[serde(flatten)]
+    pub legal: LegalDetails,
 }
 impl Counterparty {
     pub fn create(name: impl Into<String>) -> CreateCounterpartyBuilder {
@@ -134,18 +93,6 @@ impl Counterparty {
         UpdateCounterpartyBuilder::default()
     }
 }
-/// Тип Контрагента
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum CompanyType {
-    /// Юридическое лицо
-    Legal,
-    /// Индивидуальный предприниматель
-    Entrepreneur,
-    /// Физическое лицо
-    #[default]
-    Individual,
-}
 /// Пол Контрагента
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -157,10 +104,12 @@ pub enum Sex {
     Female,
 }
 impl MsEntity for Counterparty {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/counterparty")
+    fn path() -> String {
+        String::from("entity/counterparty")
     }
 }
+impl_has_attributes!(Counterparty);
+impl HasFiles for Counterparty {}
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateCounterparty {
@@ -196,33 +145,9 @@ pub struct CreateCounterparty {
     #[serde(skip_serializing_if = "Option::is_none")]
     tags: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    legal_title: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_address: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_address_full: Option<Address>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    inn: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    kpp: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    ogrn: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    okpo: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    ogrnip: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_last_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_first_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_middle_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     sex: Option<Sex>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    certificate_number: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    certificate_date: Option<String>,
+    #[serde(flatten)]
+    legal: LegalDetails,
 }
 #[derive(Default)]
 pub struct CreateCounterpartyBuilder {
@@ -242,20 +167,8 @@ pub struct CreateCounterpartyBuilder {
     shared: Option<bool>,
     state: Option<MetaWrapper>,
     tags: Option<Vec<String>>,
-    legal_title: Option<String>,
-    legal_address: Option<String>,
-    legal_address_full: Option<Address>,
-    inn: Option<String>,
-    kpp: Option<String>,
-    ogrn: Option<String>,
-    okpo: Option<String>,
-    ogrnip: Option<String>,
-    legal_last_name: Option<String>,
-    legal_first_name: Option<String>,
-    legal_middle_name: Option<String>,
     sex: Option<Sex>,
-    certificate_number: Option<String>,
-    certificate_date: Option<String>,
+    legal: LegalDetails,
 }
 impl CreateCounterpartyBuilder {
     pub fn new(name: impl Into<String>) -> Self {
@@ -290,7 +203,7 @@ impl CreateCounterpartyBuilder {
             .actual_address_full
             .get_or_insert(Address::default())
             .country
-            .insert(MetaWrapper { meta: country_meta });
+            .insert(MsRef::new(country_meta));
         self
     }
     pub fn actual_address_full_region(&mut self, region_meta: Meta) -> &mut Self {
@@ -298,7 +211,7 @@ impl CreateCounterpartyBuilder {
             .actual_address_full
             .get_or_insert(Address::default())
             .region
-            .insert(MetaWrapper { meta: region_meta });
+            .insert(MsRef::new(region_meta));
         self
     }
     pub fn actual_address_full_city(&mut self, city: impl Into<String>) -> &mut Self {
@@ -417,17 +330,18 @@ impl CreateCounterpartyBuilder {
     }
     /// Полное наименование Контрагента
     pub fn legal_title(&mut self, legal_title: impl Into<String>) -> &mut Self {
-        let _ = self.legal_title.insert(legal_title.into());
+        let _ = self.legal.legal_title.insert(legal_title.into());
         self
     }
     /// Юридического адреса Контрагента
     pub fn legal_address(&mut self, legal_address: impl Into<String>) -> &mut Self {
-        let _ = self.legal_address.insert(legal_address.into());
+        let _ = self.legal.legal_address.insert(legal_address.into());
         self
     }
     /// Юридический адрес Контрагента с детализацией по отдельным полям
     pub fn legal_address_full_add_info(&mut self, add_info: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .add_info
@@ -436,6 +350,7 @@ impl CreateCounterpartyBuilder {
     }
     pub fn legal_address_full_postal_code(&mut self, postal_code: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .postal_code
@@ -444,22 +359,25 @@ impl CreateCounterpartyBuilder {
     }
     pub fn legal_address_full_country(&mut self, country_meta: Meta) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .country
-            .insert(MetaWrapper { meta: country_meta });
+            .insert(MsRef::new(country_meta));
         self
     }
     pub fn legal_address_full_region(&mut self, region_meta: Meta) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .region
-            .insert(MetaWrapper { meta: region_meta });
+            .insert(MsRef::new(region_meta));
         self
     }
     pub fn legal_address_full_city(&mut self, city: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .city
@@ -468,6 +386,7 @@ impl CreateCounterpartyBuilder {
     }
     pub fn legal_address_full_street(&mut self, street: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .street
@@ -476,6 +395,7 @@ impl CreateCounterpartyBuilder {
     }
     pub fn legal_address_full_house(&mut self, house: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .house
@@ -484,6 +404,7 @@ impl CreateCounterpartyBuilder {
     }
     pub fn legal_address_full_apartmen(&mut self, apartment: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .apartment
@@ -492,6 +413,7 @@ impl CreateCounterpartyBuilder {
     }
     pub fn legal_address_full_comment(&mut self, comment: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .comment
@@ -500,42 +422,45 @@ impl CreateCounterpartyBuilder {
     }
     /// ИНН
     pub fn inn(&mut self, inn: impl Into<String>) -> &mut Self {
-        let _ = self.inn.insert(inn.into());
+        let _ = self.legal.inn.insert(inn.into());
         self
     }
     /// КПП
     pub fn kpp(&mut self, kpp: impl Into<String>) -> &mut Self {
-        let _ = self.kpp.insert(kpp.into());
+        let _ = self.legal.kpp.insert(kpp.into());
         self
     }
     /// ОГРН
     pub fn ogrn(&mut self, ogrn: impl Into<String>) -> &mut Self {
-        let _ = self.ogrn.insert(ogrn.into());
+        let _ = self.legal.ogrn.insert(ogrn.into());
         self
     }
     /// ОКПО
     pub fn okpo(&mut self, okpo: impl Into<String>) -> &mut Self {
-        let _ = self.okpo.insert(okpo.into());
+        let _ = self.legal.okpo.insert(okpo.into());
         self
     }
     /// ОКПО
     pub fn ogrnip(&mut self, ogrnip: impl Into<String>) -> &mut Self {
-        let _ = self.ogrnip.insert(ogrnip.into());
+        let _ = self.legal.ogrnip.insert(ogrnip.into());
         self
     }
     /// Фамилия для Контрагента типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для Контрагентов типа [Юридическое лицо]
     pub fn legal_last_name(&mut self, legal_last_name: impl Into<String>) -> &mut Self {
-        let _ = self.legal_last_name.insert(legal_last_name.into());
+        let _ = self.legal.legal_last_name.insert(legal_last_name.into());
         self
     }
     /// Имя для Контрагента типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для Контрагентов типа [Юридическое лицо]
     pub fn legal_first_name(&mut self, legal_first_name: impl Into<String>) -> &mut Self {
-        let _ = self.legal_first_name.insert(legal_first_name.into());
+        let _ = self.legal.legal_first_name.insert(legal_first_name.into());
         self
     }
     /// Отчество для Контрагента типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для Контрагентов типа [Юридическое лицо]
     pub fn legal_middle_name(&mut self, legal_middle_name: impl Into<String>) -> &mut Self {
-        let _ = self.legal_middle_name.insert(legal_middle_name.into());
+        let _ = self
+            .legal
+            .legal_middle_name
+            .insert(legal_middle_name.into());
         self
     }
     /// Пол Контрагента
@@ -545,12 +470,20 @@ impl CreateCounterpartyBuilder {
     }
     /// Номер свидетельства
     pub fn certificate_number(&mut self, certificate_number: impl Into<String>) -> &mut Self {
-        let _ = self.certificate_number.insert(certificate_number.into());
+        let _ = self
+            .legal
+            .certificate_number
+            .insert(certificate_number.into());
         self
     }
     /// Дата свидетельства
     pub fn certificate_date(&mut self, certificate_date: impl Into<String>) -> &mut Self {
-        let _ = self.certificate_date.insert(certificate_date.into());
+        let _ = self.legal.certificate_date.insert(certificate_date.into());
+        self
+    }
+    /// Реквизиты Контрагента целиком
+    pub fn legal_details(&mut self, legal: LegalDetails) -> &mut Self {
+        self.legal = legal;
         self
     }
     pub fn build(&self) -> CreateCounterparty {
@@ -571,20 +504,8 @@ impl CreateCounterpartyBuilder {
             shared: self.shared,
             state: self.state.to_owned(),
             tags: self.tags.to_owned(),
-            legal_title: self.legal_title.to_owned(),
-            legal_address: self.legal_address.to_owned(),
-            legal_address_full: self.legal_address_full.to_owned(),
-            inn: self.inn.to_owned(),
-            kpp: self.kpp.to_owned(),
-            ogrn: self.ogrn.to_owned(),
-            okpo: self.okpo.to_owned(),
-            ogrnip: self.ogrnip.to_owned(),
-            legal_last_name: self.legal_last_name.to_owned(),
-            legal_first_name: self.legal_first_name.to_owned(),
-            legal_middle_name: self.legal_middle_name.to_owned(),
             sex: self.sex.to_owned(),
-            certificate_number: self.certificate_number.to_owned(),
-            certificate_date: self.certificate_date.to_owned(),
+            legal: self.legal.to_owned(),
         }
     }
 }
@@ -630,33 +551,9 @@ pub struct UpdateCounterparty {
     #[serde(skip_serializing_if = "Option::is_none")]
     tags: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    legal_title: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_address: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_address_full: Option<Address>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    inn: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    kpp: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    ogrn: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    okpo: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    ogrnip: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_last_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_first_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    legal_middle_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
     sex: Option<Sex>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    certificate_number: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    certificate_date: Option<String>,
+    #[serde(flatten)]
+    legal: LegalDetails,
 }
 #[derive(Default)]
 pub struct UpdateCounterpartyBuilder {
@@ -679,20 +576,8 @@ pub struct UpdateCounterpartyBuilder {
     shared: Option<bool>,
     state: Option<MetaWrapper>,
     tags: Option<Vec<String>>,
-    legal_title: Option<String>,
-    legal_address: Option<String>,
-    legal_address_full: Option<Address>,
-    inn: Option<String>,
-    kpp: Option<String>,
-    ogrn: Option<String>,
-    okpo: Option<String>,
-    ogrnip: Option<String>,
-    legal_last_name: Option<String>,
-    legal_first_name: Option<String>,
-    legal_middle_name: Option<String>,
     sex: Option<Sex>,
-    certificate_number: Option<String>,
-    certificate_date: Option<String>,
+    legal: LegalDetails,
 }
 impl UpdateCounterpartyBuilder {
     /// Фактический адрес Контрагента
@@ -722,7 +607,7 @@ impl UpdateCounterpartyBuilder {
             .actual_address_full
             .get_or_insert(Address::default())
             .country
-            .insert(MetaWrapper { meta: country_meta });
+            .insert(MsRef::new(country_meta));
         self
     }
     pub fn actual_address_full_region(&mut self, region_meta: Meta) -> &mut Self {
@@ -730,7 +615,7 @@ impl UpdateCounterpartyBuilder {
             .actual_address_full
             .get_or_insert(Address::default())
             .region
-            .insert(MetaWrapper { meta: region_meta });
+            .insert(MsRef::new(region_meta));
         self
     }
     pub fn actual_address_full_city(&mut self, city: impl Into<String>) -> &mut Self {
@@ -783,6 +668,14 @@ impl UpdateCounterpartyBuilder {
         self.attributes.get_or_insert(vec![]).push(attribute);
         self
     }
+    /// Весь набор доп. полей, например из [`crate::HasAttributes::attribute_list`]
+    pub fn attributes<I>(&mut self, attributes: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Attribute>,
+    {
+        let _ = self.attributes.insert(attributes.into_iter().collect());
+        self
+    }
     /// Метаданные активной Бонусной программы
     pub fn bonus_program(&mut self, bonus_programm_meta: Meta) -> &mut Self {
         let _ = self.bonus_program.insert(MetaWrapper {
@@ -875,17 +768,18 @@ impl UpdateCounterpartyBuilder {
     }
     /// Полное наименование Контрагента
     pub fn legal_title(&mut self, legal_title: impl Into<String>) -> &mut Self {
-        let _ = self.legal_title.insert(legal_title.into());
+        let _ = self.legal.legal_title.insert(legal_title.into());
         self
     }
     /// Юридического адреса Контрагента
     pub fn legal_address(&mut self, legal_address: impl Into<String>) -> &mut Self {
-        let _ = self.legal_address.insert(legal_address.into());
+        let _ = self.legal.legal_address.insert(legal_address.into());
         self
     }
     /// Юридический адрес Контрагента с детализацией по отдельным полям
     pub fn legal_address_full_add_info(&mut self, add_info: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .add_info
@@ -894,6 +788,7 @@ impl UpdateCounterpartyBuilder {
     }
     pub fn legal_address_full_postal_code(&mut self, postal_code: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .postal_code
@@ -902,22 +797,25 @@ impl UpdateCounterpartyBuilder {
     }
     pub fn legal_address_full_country(&mut self, country_meta: Meta) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .country
-            .insert(MetaWrapper { meta: country_meta });
+            .insert(MsRef::new(country_meta));
         self
     }
     pub fn legal_address_full_region(&mut self, region_meta: Meta) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .region
-            .insert(MetaWrapper { meta: region_meta });
+            .insert(MsRef::new(region_meta));
         self
     }
     pub fn legal_address_full_city(&mut self, city: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .city
@@ -926,6 +824,7 @@ impl UpdateCounterpartyBuilder {
     }
     pub fn legal_address_full_street(&mut self, street: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .street
@@ -934,6 +833,7 @@ impl UpdateCounterpartyBuilder {
     }
     pub fn legal_address_full_house(&mut self, house: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .house
@@ -942,6 +842,7 @@ impl UpdateCounterpartyBuilder {
     }
     pub fn legal_address_full_apartmen(&mut self, apartment: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .apartment
@@ -950,6 +851,7 @@ impl UpdateCounterpartyBuilder {
     }
     pub fn legal_address_full_comment(&mut self, comment: impl Into<String>) -> &mut Self {
         let _ = self
+            .legal
             .legal_address_full
             .get_or_insert(Address::default())
             .comment
@@ -958,42 +860,45 @@ impl UpdateCounterpartyBuilder {
     }
     /// ИНН
     pub fn inn(&mut self, inn: impl Into<String>) -> &mut Self {
-        let _ = self.inn.insert(inn.into());
+        let _ = self.legal.inn.insert(inn.into());
         self
     }
     /// КПП
     pub fn kpp(&mut self, kpp: impl Into<String>) -> &mut Self {
-        let _ = self.kpp.insert(kpp.into());
+        let _ = self.legal.kpp.insert(kpp.into());
         self
     }
     /// ОГРН
     pub fn ogrn(&mut self, ogrn: impl Into<String>) -> &mut Self {
-        let _ = self.ogrn.insert(ogrn.into());
+        let _ = self.legal.ogrn.insert(ogrn.into());
         self
     }
     /// ОКПО
     pub fn okpo(&mut self, okpo: impl Into<String>) -> &mut Self {
-        let _ = self.okpo.insert(okpo.into());
+        let _ = self.legal.okpo.insert(okpo.into());
         self
     }
     /// ОКПО
     pub fn ogrnip(&mut self, ogrnip: impl Into<String>) -> &mut Self {
-        let _ = self.ogrnip.insert(ogrnip.into());
+        let _ = self.legal.ogrnip.insert(ogrnip.into());
         self
     }
     /// Фамилия для Контрагента типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для Контрагентов типа [Юридическое лицо]
     pub fn legal_last_name(&mut self, legal_last_name: impl Into<String>) -> &mut Self {
-        let _ = self.legal_last_name.insert(legal_last_name.into());
+        let _ = self.legal.legal_last_name.insert(legal_last_name.into());
         self
     }
     /// Имя для Контрагента типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для Контрагентов типа [Юридическое лицо]
     pub fn legal_first_name(&mut self, legal_first_name: impl Into<String>) -> &mut Self {
-        let _ = self.legal_first_name.insert(legal_first_name.into());
+        let _ = self.legal.legal_first_name.insert(legal_first_name.into());
         self
     }
     /// Отчество для Контрагента типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для Контрагентов типа [Юридическое лицо]
     pub fn legal_middle_name(&mut self, legal_middle_name: impl Into<String>) -> &mut Self {
-        let _ = self.legal_middle_name.insert(legal_middle_name.into());
+        let _ = self
+            .legal
+            .legal_middle_name
+            .insert(legal_middle_name.into());
         self
     }
     /// Пол Контрагента
@@ -1003,12 +908,20 @@ impl UpdateCounterpartyBuilder {
     }
     /// Номер свидетельства
     pub fn certificate_number(&mut self, certificate_number: impl Into<String>) -> &mut Self {
-        let _ = self.certificate_number.insert(certificate_number.into());
+        let _ = self
+            .legal
+            .certificate_number
+            .insert(certificate_number.into());
         self
     }
     /// Дата свидетельства
     pub fn certificate_date(&mut self, certificate_date: impl Into<String>) -> &mut Self {
-        let _ = self.certificate_date.insert(certificate_date.into());
+        let _ = self.legal.certificate_date.insert(certificate_date.into());
+        self
+    }
+    /// Реквизиты Контрагента целиком
+    pub fn legal_details(&mut self, legal: LegalDetails) -> &mut Self {
+        self.legal = legal;
         self
     }
     pub fn build(&self) -> UpdateCounterparty {
@@ -1029,23 +942,11 @@ impl UpdateCounterpartyBuilder {
             shared: self.shared,
             state: self.state.to_owned(),
             tags: self.tags.to_owned(),
-            legal_title: self.legal_title.to_owned(),
-            legal_address: self.legal_address.to_owned(),
-            legal_address_full: self.legal_address_full.to_owned(),
-            inn: self.inn.to_owned(),
-            kpp: self.kpp.to_owned(),
-            ogrn: self.ogrn.to_owned(),
-            okpo: self.okpo.to_owned(),
-            ogrnip: self.ogrnip.to_owned(),
-            legal_last_name: self.legal_last_name.to_owned(),
-            legal_first_name: self.legal_first_name.to_owned(),
-            legal_middle_name: self.legal_middle_name.to_owned(),
             sex: self.sex.to_owned(),
-            certificate_number: self.certificate_number.to_owned(),
-            certificate_date: self.certificate_date.to_owned(),
             archived: self.archived,
             code: self.code.to_owned(),
             meta: self.meta.to_owned(),
+            legal: self.legal.to_owned(),
         }
     }
 }
@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::api_client::MsEntity;
 
-use super::{deserialize_date_from_str, Meta, MetaWrapper};
+use super::{deserialize_date_from_str, employee::Employee, ms_ref::MsRef, Meta, MetaWrapper};
 
 /// Страны
 /// Средствами JSON API можно создавать и обновлять сведения о Странах, запрашивать списки Стран и сведения по отдельным Странам. Кодом сущности для Страны в составе JSON API является ключевое слово country.
@@ -18,13 +18,13 @@ pub struct Country {
     pub id: uuid::Uuid,
     pub meta: Meta,
     pub name: String,
-    pub owner: Option<MetaWrapper>,
+    pub owner: Option<MsRef<Employee>>,
     pub shared: Option<bool>,
     #[serde(deserialize_with = "deserialize_date_from_str")]
     pub updated: NaiveDateTime,
 }
 impl MsEntity for Country {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/country")
+    fn path() -> String {
+        String::from("entity/country")
     }
 }
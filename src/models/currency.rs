@@ -6,7 +6,7 @@ use super::Meta;
 /// Средствами JSON API можно запрашивать списки валют и сведения по отдельным валютам, а также создавать новые и обновлять сведения по уже существующим валютам. Кодом сущности для валют в составе JSON API является ключевое слово currency. По данной сущности можно осуществлять контекстный поиск с помощью специального параметра search. Поиск с параметром search отличается от других тем, что поиск не префиксный, без токенизации и идет только по одному полю одновременно. Ищет такие строки, в которые входит значение строки поиска.
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
 /// use anyhow::Result;
 /// use rust_moysklad::{Currency, MoySkladApiClient};
 /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -65,8 +65,8 @@ pub struct Currency {
     pub system: bool,
 }
 impl MsEntity for Currency {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/currency")
+    fn path() -> String {
+        String::from("entity/currency")
     }
 }
 
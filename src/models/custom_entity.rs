@@ -0,0 +1,248 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{deserialize_date_from_str, employee::Employee, ms_ref::MsRef, Meta, MetaWrapper};
+
+/// Пользовательский справочник из `context/companysettings/metadata`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{AttributeValue, CustomEntity, CustomValue, MoySkladApiClient, Query};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let dictionary = client.create_custom_dictionary("Коллекции").await?;
+///     let element = CustomEntity::create("Весна-2024").code("SS24").build();
+///     let element = client.create_custom_element(dictionary.id, element).await?;
+///     // значение для доп. поля типа "справочник"
+///     let value = AttributeValue::Custom(CustomValue::from(&element));
+///     dbg!(value);
+///     let renamed = CustomEntity::update().name("Весна-лето 2024").build();
+///     client
+///         .update_custom_element(dictionary.id, element.id, renamed)
+///         .await?;
+///     for dictionary in client.get_custom_dictionaries().await? {
+///         if let Some(id) = dictionary.id() {
+///             let elements = client.get_custom_elements(id, &Query::new()).await?;
+///             dbg!(dictionary.name, elements.len());
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomDictionaryMetadata {
+    /// Метаданные справочника в настройках компании
+    pub meta: Meta,
+    /// Наименование справочника
+    pub name: String,
+    /// Метаданные элементов справочника `entity/customentity/{id}`
+    pub entity_meta: Meta,
+    /// Создавать ли новые элементы с общим доступом
+    pub create_shared: Option<bool>,
+}
+impl CustomDictionaryMetadata {
+    /// ID справочника
+    pub fn id(&self) -> Option<Uuid> {
+        self.entity_meta.id()
+    }
+}
+/// Настройки компании `context/companysettings/metadata`
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct CompanySettingsMetadata {
+    #[serde(default)]
+    pub custom_entities: Vec<CustomDictionaryMetadata>,
+}
+/// Пользовательский справочник
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomDictionary {
+    /// ID справочника
+    pub id: Uuid,
+    /// Метаданные справочника
+    pub meta: Meta,
+    /// Наименование справочника
+    pub name: String,
+}
+/// Элемент пользовательского справочника
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomEntity {
+    pub account_id: Uuid,
+    pub code: Option<String>,
+    pub description: Option<String>,
+    pub external_code: String,
+    pub id: Uuid,
+    pub meta: Meta,
+    pub name: String,
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    pub group: MetaWrapper,
+    pub owner: MsRef<Employee>,
+    pub shared: Option<bool>,
+}
+impl CustomEntity {
+    pub fn create(name: impl Into<String>) -> CreateCustomEntityBuilder {
+        CreateCustomEntityBuilder::new(name)
+    }
+    pub fn update() -> UpdateCustomEntityBuilder {
+        UpdateCustomEntityBuilder::default()
+    }
+}
+/// Наименование справочника для создания и переименования
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CustomDictionaryName {
+    pub name: String,
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCustomEntity {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreateCustomEntityBuilder {
+    name: String,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    shared: Option<bool>,
+}
+impl CreateCustomEntityBuilder {
+    /// Наименование элемента
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn build(&self) -> CreateCustomEntity {
+        CreateCustomEntity {
+            name: self.name.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            owner: self.owner.to_owned(),
+            shared: self.shared,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCustomEntity {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateCustomEntityBuilder {
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    name: Option<String>,
+    owner: Option<MetaWrapper>,
+    shared: Option<bool>,
+}
+impl UpdateCustomEntityBuilder {
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn build(&self) -> UpdateCustomEntity {
+        UpdateCustomEntity {
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            name: self.name.to_owned(),
+            owner: self.owner.to_owned(),
+            shared: self.shared,
+        }
+    }
+}
@@ -0,0 +1,840 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    counterparty::{Address, Counterparty},
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{
+        CreatePosition, CreatePositionBuilder, DiscountPositions, Positions, Rate,
+        ReservePositions, UpdatePositionBuilder,
+    },
+    employee::Employee,
+    ms_ref::MsRef,
+    organization::Organization,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    Attribute, Meta, MetaWrapper, TaxSystem,
+};
+
+/// Заказ покупателя
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{CustomerOrder, CustomerOrderPosition, MoySkladApiClient, Product};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let orders = client.list::<CustomerOrder>(1, 0, None::<String>).await?;
+///     let template = orders.rows.first().expect("no orders");
+///     let product = client.list::<Product>(1, 0, None::<String>).await?.rows.remove(0);
+///     let order = CustomerOrder::create(&template.organization.meta, &template.agent.meta)
+///         .description("Заказ из интернет-магазина")
+///         .position(
+///             CustomerOrderPosition::create(&product.meta, 2.0)
+///                 .price(150000.0)
+///                 .discount(5.0)
+///                 .reserve(2.0)
+///                 .build(),
+///         )
+///         .build();
+///     let created: CustomerOrder = client.create(order).await?;
+///     let positions = client.get_positions::<CustomerOrder>(created.id).await?;
+///     let update = CustomerOrderPosition::update().quantity(3.0).build();
+///     client
+///         .update_position::<CustomerOrder, _>(created.id, positions[0].id, update)
+///         .await?;
+///     client
+///         .delete_position::<CustomerOrder>(created.id, positions[0].id)
+///         .await?;
+///     client.delete::<CustomerOrder>(created.id).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerOrder {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные контрагента
+    pub agent: MsRef<Counterparty>,
+    /// Метаданные счета контрагента
+    pub agent_account: Option<MetaWrapper>,
+    /// Отметка о проведении
+    pub applicable: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Заказа покупателя
+    pub code: Option<String>,
+    /// Метаданные договора
+    pub contract: Option<MetaWrapper>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Момент последнего удаления Заказа покупателя
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Планируемая дата отгрузки
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub delivery_planned_moment: Option<NaiveDateTime>,
+    /// Комментарий Заказа покупателя
+    pub description: Option<String>,
+    /// Внешний код Заказа покупателя
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Заказа покупателя
+    pub id: Uuid,
+    /// Сумма счетов покупателю
+    pub invoiced_sum: f64,
+    /// Метаданные Заказа покупателя
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Заказа покупателя
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Метаданные счета юрлица
+    pub organization_account: Option<MetaWrapper>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Сумма входящих платежей по Заказу
+    pub payed_sum: f64,
+    /// Метаданные позиций Заказа покупателя
+    pub positions: Positions<CustomerOrderPosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Метаданные проекта
+    pub project: Option<MetaWrapper>,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Валюта
+    pub rate: Rate,
+    /// Сумма товаров в резерве
+    pub reserved_sum: f64,
+    /// Метаданные канала продаж
+    pub sales_channel: Option<MetaWrapper>,
+    /// Общий доступ
+    pub shared: bool,
+    /// Адрес доставки Заказа покупателя
+    pub shipment_address: Option<String>,
+    /// Адрес доставки Заказа покупателя с детализацией по отдельным полям
+    pub shipment_address_full: Option<Address>,
+    /// Сумма отгруженного
+    pub shipped_sum: f64,
+    /// Метаданные статуса Заказа покупателя
+    pub state: Option<MsRef<State>>,
+    /// Метаданные склада
+    pub store: Option<MsRef<Store>>,
+    /// Сумма Заказа покупателя в копейках
+    pub sum: f64,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Код системы налогообложения
+    pub tax_system: Option<TaxSystem>,
+    /// Момент последнего обновления Заказа покупателя
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Учитывается ли НДС
+    pub vat_enabled: bool,
+    /// Включен ли НДС в цену
+    pub vat_included: Option<bool>,
+    /// Сумма НДС
+    pub vat_sum: f64,
+}
+impl CustomerOrder {
+    pub fn create(organization_meta: &Meta, agent_meta: &Meta) -> CreateCustomerOrderBuilder {
+        CreateCustomerOrderBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            agent: MetaWrapper {
+                meta: agent_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateCustomerOrderBuilder {
+        UpdateCustomerOrderBuilder::default()
+    }
+}
+impl MsEntity for CustomerOrder {
+    fn path() -> String {
+        String::from("entity/customerorder")
+    }
+}
+impl_has_attributes!(CustomerOrder);
+impl HasFiles for CustomerOrder {}
+impl HasPositions for CustomerOrder {
+    type Position = CustomerOrderPosition;
+}
+impl DiscountPositions for CustomerOrder {}
+impl ReservePositions for CustomerOrder {}
+/// Позиция Заказа покупателя
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomerOrderPosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// Процент скидки или наценки
+    pub discount: f64,
+    /// ID позиции
+    pub id: Uuid,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+    /// Резерв данной позиции
+    pub reserve: Option<f64>,
+    /// Доставлено
+    pub shipped: f64,
+    /// Код системы налогообложения
+    pub tax_system: Option<TaxSystem>,
+    /// НДС, которым облагается текущая позиция
+    pub vat: i32,
+    /// Включен ли НДС для позиции
+    pub vat_enabled: bool,
+}
+impl CustomerOrderPosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<CustomerOrder> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<CustomerOrder> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCustomerOrder {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    delivery_planned_moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<CustomerOrder>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sales_channel: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipment_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreateCustomerOrderBuilder {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    delivery_planned_moment: Option<NaiveDateTime>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    positions: Option<Vec<CreatePosition<CustomerOrder>>>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    sales_channel: Option<MetaWrapper>,
+    shared: Option<bool>,
+    shipment_address: Option<String>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl CreateCustomerOrderBuilder {
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn delivery_planned_moment(&mut self, delivery_planned_moment: NaiveDateTime) -> &mut Self {
+        let _ = self.delivery_planned_moment.insert(delivery_planned_moment);
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<CustomerOrder>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn sales_channel(&mut self, sales_channel_meta: &Meta) -> &mut Self {
+        let _ = self.sales_channel.insert(MetaWrapper {
+            meta: sales_channel_meta.to_owned(),
+        });
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn shipment_address(&mut self, shipment_address: impl Into<String>) -> &mut Self {
+        let _ = self.shipment_address.insert(shipment_address.into());
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> CreateCustomerOrder {
+        CreateCustomerOrder {
+            organization: self.organization.to_owned(),
+            agent: self.agent.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            delivery_planned_moment: self.delivery_planned_moment,
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            positions: self.positions.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            sales_channel: self.sales_channel.to_owned(),
+            shared: self.shared,
+            shipment_address: self.shipment_address.to_owned(),
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCustomerOrder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    delivery_planned_moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sales_channel: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipment_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateCustomerOrderBuilder {
+    agent: Option<MetaWrapper>,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    delivery_planned_moment: Option<NaiveDateTime>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    sales_channel: Option<MetaWrapper>,
+    shared: Option<bool>,
+    shipment_address: Option<String>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl UpdateCustomerOrderBuilder {
+    pub fn agent(&mut self, agent_meta: &Meta) -> &mut Self {
+        let _ = self.agent.insert(MetaWrapper {
+            meta: agent_meta.to_owned(),
+        });
+        self
+    }
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn delivery_planned_moment(&mut self, delivery_planned_moment: NaiveDateTime) -> &mut Self {
+        let _ = self.delivery_planned_moment.insert(delivery_planned_moment);
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn sales_channel(&mut self, sales_channel_meta: &Meta) -> &mut Self {
+        let _ = self.sales_channel.insert(MetaWrapper {
+            meta: sales_channel_meta.to_owned(),
+        });
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn shipment_address(&mut self, shipment_address: impl Into<String>) -> &mut Self {
+        let _ = self.shipment_address.insert(shipment_address.into());
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> UpdateCustomerOrder {
+        UpdateCustomerOrder {
+            agent: self.agent.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            delivery_planned_moment: self.delivery_planned_moment,
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            sales_channel: self.sales_channel.to_owned(),
+            shared: self.shared,
+            shipment_address: self.shipment_address.to_owned(),
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const ORDER_ID: &str = "5e1b8a32-6c3e-11ee-0a80-0c5e00000101";
+    const POSITION_ID: &str = "5e1b8a32-6c3e-11ee-0a80-0c5e00000102";
+
+    fn meta(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn order_json(positions: Value) -> Value {
+        json!({
+            "meta": meta(&format!("customerorder/{ORDER_ID}")),
+            "id": ORDER_ID,
+            "accountId": "5e1b8a32-6c3e-11ee-0a80-0c5e00000001",
+            "owner": { "meta": meta("employee/5e1b8a32-6c3e-11ee-0a80-0c5e00000002") },
+            "shared": false,
+            "group": { "meta": meta("group/5e1b8a32-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-01 12:00:00.000",
+            "name": "00042",
+            "externalCode": "ext-42",
+            "moment": "2024-05-01 11:59:00.000",
+            "applicable": true,
+            "rate": { "currency": { "meta": meta("currency/5e1b8a32-6c3e-11ee-0a80-0c5e00000004") } },
+            "sum": 285000.0,
+            "store": { "meta": meta("store/5e1b8a32-6c3e-11ee-0a80-0c5e00000005") },
+            "agent": { "meta": meta("counterparty/5e1b8a32-6c3e-11ee-0a80-0c5e00000006") },
+            "organization": { "meta": meta("organization/5e1b8a32-6c3e-11ee-0a80-0c5e00000007") },
+            "state": { "meta": meta("customerorder/metadata/states/5e1b8a32-6c3e-11ee-0a80-0c5e00000008") },
+            "created": "2024-05-01 11:59:30.000",
+            "printed": false,
+            "published": false,
+            "files": { "meta": meta(&format!("customerorder/{ORDER_ID}/files")) },
+            "positions": positions,
+            "vatEnabled": true,
+            "vatIncluded": true,
+            "vatSum": 47500.0,
+            "payedSum": 0.0,
+            "shippedSum": 0.0,
+            "invoicedSum": 0.0,
+            "reservedSum": 285000.0
+        })
+    }
+
+    fn position_json() -> Value {
+        json!({
+            "meta": meta(&format!("customerorder/{ORDER_ID}/positions/{POSITION_ID}")),
+            "id": POSITION_ID,
+            "accountId": "5e1b8a32-6c3e-11ee-0a80-0c5e00000001",
+            "quantity": 2.0,
+            "price": 150000.0,
+            "discount": 5.0,
+            "vat": 20,
+            "vatEnabled": true,
+            "assortment": { "meta": meta("product/5e1b8a32-6c3e-11ee-0a80-0c5e00000009") },
+            "shipped": 0.0,
+            "reserve": 2.0
+        })
+    }
+
+    #[test]
+    fn order_without_expanded_positions() {
+        let positions = json!({
+            "meta": {
+                "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/customerorder/{ORDER_ID}/positions"),
+                "type": "customerorderposition",
+                "mediaType": "application/json",
+                "size": 1,
+                "limit": 1000,
+                "offset": 0
+            }
+        });
+        let order: CustomerOrder = serde_json::from_value(order_json(positions)).unwrap();
+        assert_eq!(order.id.to_string(), ORDER_ID);
+        assert_eq!(order.name, "00042");
+        assert_eq!(order.agent.entity_type(), Some("counterparty"));
+        assert!(order.store.is_some());
+        assert_eq!(order.positions.meta.size, Some(1));
+        assert!(order.positions.rows.is_empty());
+        assert!(order.deleted.is_none());
+    }
+
+    #[test]
+    fn order_with_expanded_positions_round_trip() {
+        let positions = json!({
+            "meta": {
+                "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/customerorder/{ORDER_ID}/positions"),
+                "type": "customerorderposition",
+                "mediaType": "application/json",
+                "size": 1
+            },
+            "rows": [position_json()]
+        });
+        let order: CustomerOrder = serde_json::from_value(order_json(positions)).unwrap();
+        let position = &order.positions.rows[0];
+        assert_eq!(position.id.to_string(), POSITION_ID);
+        assert_eq!(position.reserve, Some(2.0));
+        assert_eq!(position.discount, 5.0);
+        assert_eq!(position.assortment.entity_type(), Some("product"));
+
+        // даты документа сериализуются в формате chrono, поэтому обратно разбираются только позиции
+        let serialized = serde_json::to_value(&order.positions).unwrap();
+        assert_eq!(serialized["rows"][0]["reserve"], json!(2.0));
+        let again: Positions<CustomerOrderPosition> = serde_json::from_value(serialized).unwrap();
+        assert_eq!(again, order.positions);
+    }
+
+    #[test]
+    fn create_order_serializes_set_fields_only() {
+        let organization: Meta =
+            serde_json::from_value(meta("organization/5e1b8a32-6c3e-11ee-0a80-0c5e00000007"))
+                .unwrap();
+        let agent: Meta =
+            serde_json::from_value(meta("counterparty/5e1b8a32-6c3e-11ee-0a80-0c5e00000006"))
+                .unwrap();
+        let product: Meta =
+            serde_json::from_value(meta("product/5e1b8a32-6c3e-11ee-0a80-0c5e00000009")).unwrap();
+        let order = CustomerOrder::create(&organization, &agent)
+            .description("Заказ из интернет-магазина")
+            .position(
+                CustomerOrderPosition::create(&product, 2.0)
+                    .price(150000.0)
+                    .reserve(2.0)
+                    .build(),
+            )
+            .build();
+        let value = serde_json::to_value(&order).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(keys, ["agent", "description", "organization", "positions"]);
+        assert_eq!(value["agent"]["meta"]["type"], "counterparty");
+        assert_eq!(
+            value["positions"][0],
+            json!({
+                "assortment": { "meta": serde_json::to_value(&product).unwrap() },
+                "quantity": 2.0,
+                "price": 150000.0,
+                "reserve": 2.0
+            })
+        );
+    }
+
+    #[test]
+    fn update_order_serializes_set_fields_only() {
+        let update = CustomerOrder::update()
+            .applicable(false)
+            .name("00043")
+            .build();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "applicable": false, "name": "00043" })
+        );
+    }
+}
@@ -0,0 +1,819 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    counterparty::{Address, Counterparty},
+    customer_order::CustomerOrder,
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{
+        CreatePosition, CreatePositionBuilder, DiscountPositions, Overhead, Positions, Rate,
+        SlotPositions, ThingPositions, UpdatePositionBuilder,
+    },
+    employee::Employee,
+    ms_ref::MsRef,
+    organization::Organization,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Отгрузка
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{CustomerOrder, Demand, DemandPosition, MoySkladApiClient, Template};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let order = client.get::<CustomerOrder>(uuid::Uuid::nil()).await?;
+///     // отгрузка на основе заказа покупателя
+///     let demand: Demand = client
+///         .create_from_template(&Template::customer_order(&order.meta))
+///         .await?;
+///     let update = Demand::update().description("Отгружено курьером").build();
+///     let demand: Demand = client.update(demand.id, update).await?;
+///     // отгрузка без заказа
+///     let store = order.store.expect("order without store");
+///     let position = client.get_positions::<CustomerOrder>(order.id).await?.remove(0);
+///     let demand = Demand::create(&order.organization.meta, &order.agent.meta, &store.meta)
+///         .position(
+///             DemandPosition::create(&position.assortment.meta, 1.0)
+///                 .price(position.price)
+///                 .build(),
+///         )
+///         .build();
+///     let created: Demand = client.create(demand).await?;
+///     dbg!(created.sum);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Demand {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные контрагента
+    pub agent: MsRef<Counterparty>,
+    /// Метаданные счета контрагента
+    pub agent_account: Option<MetaWrapper>,
+    /// Отметка о проведении
+    pub applicable: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Отгрузки
+    pub code: Option<String>,
+    /// Метаданные договора
+    pub contract: Option<MetaWrapper>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Ссылка на Заказ покупателя, на основании которого создана Отгрузка
+    pub customer_order: Option<MsRef<CustomerOrder>>,
+    /// Момент последнего удаления Отгрузки
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Комментарий Отгрузки
+    pub description: Option<String>,
+    /// Внешний код Отгрузки
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Отгрузки
+    pub id: Uuid,
+    /// Метаданные Отгрузки
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Отгрузки
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Метаданные счета юрлица
+    pub organization_account: Option<MetaWrapper>,
+    /// Накладные расходы
+    pub overhead: Option<Overhead>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Сумма входящих платежей по Отгрузке
+    pub payed_sum: f64,
+    /// Метаданные позиций Отгрузки
+    pub positions: Positions<DemandPosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Метаданные проекта
+    pub project: Option<MetaWrapper>,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Валюта
+    pub rate: Rate,
+    /// Метаданные канала продаж
+    pub sales_channel: Option<MetaWrapper>,
+    /// Общий доступ
+    pub shared: bool,
+    /// Адрес доставки Отгрузки
+    pub shipment_address: Option<String>,
+    /// Адрес доставки Отгрузки с детализацией по отдельным полям
+    pub shipment_address_full: Option<Address>,
+    /// Метаданные статуса Отгрузки
+    pub state: Option<MsRef<State>>,
+    /// Метаданные склада
+    pub store: MsRef<Store>,
+    /// Сумма Отгрузки в копейках
+    pub sum: f64,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Момент последнего обновления Отгрузки
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Учитывается ли НДС
+    pub vat_enabled: bool,
+    /// Включен ли НДС в цену
+    pub vat_included: Option<bool>,
+    /// Сумма НДС
+    pub vat_sum: f64,
+}
+impl Demand {
+    pub fn create(
+        organization_meta: &Meta,
+        agent_meta: &Meta,
+        store_meta: &Meta,
+    ) -> CreateDemandBuilder {
+        CreateDemandBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            agent: MetaWrapper {
+                meta: agent_meta.to_owned(),
+            },
+            store: MetaWrapper {
+                meta: store_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateDemandBuilder {
+        UpdateDemandBuilder::default()
+    }
+}
+impl MsEntity for Demand {
+    fn path() -> String {
+        String::from("entity/demand")
+    }
+}
+impl_has_attributes!(Demand);
+impl HasFiles for Demand {}
+impl HasPositions for Demand {
+    type Position = DemandPosition;
+}
+impl DiscountPositions for Demand {}
+impl SlotPositions for Demand {}
+impl ThingPositions for Demand {}
+/// Позиция Отгрузки
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DemandPosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// Себестоимость (только для услуг)
+    pub cost: Option<f64>,
+    /// Процент скидки или наценки
+    pub discount: f64,
+    /// ID позиции
+    pub id: Uuid,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Накладные расходы
+    pub overhead: Option<f64>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+    /// Ячейка на складе
+    pub slot: Option<MetaWrapper>,
+    /// Серийные номера
+    pub things: Option<Vec<String>>,
+    /// НДС, которым облагается текущая позиция
+    pub vat: i32,
+    /// Включен ли НДС для позиции
+    pub vat_enabled: bool,
+}
+impl DemandPosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<Demand> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<Demand> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDemand {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    store: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer_order: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<Demand>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sales_channel: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipment_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreateDemandBuilder {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    store: MetaWrapper,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    customer_order: Option<MetaWrapper>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    positions: Option<Vec<CreatePosition<Demand>>>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    sales_channel: Option<MetaWrapper>,
+    shared: Option<bool>,
+    shipment_address: Option<String>,
+    state: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl CreateDemandBuilder {
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn customer_order(&mut self, customer_order_meta: &Meta) -> &mut Self {
+        let _ = self.customer_order.insert(MetaWrapper {
+            meta: customer_order_meta.to_owned(),
+        });
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<Demand>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn sales_channel(&mut self, sales_channel_meta: &Meta) -> &mut Self {
+        let _ = self.sales_channel.insert(MetaWrapper {
+            meta: sales_channel_meta.to_owned(),
+        });
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn shipment_address(&mut self, shipment_address: impl Into<String>) -> &mut Self {
+        let _ = self.shipment_address.insert(shipment_address.into());
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> CreateDemand {
+        CreateDemand {
+            organization: self.organization.to_owned(),
+            agent: self.agent.to_owned(),
+            store: self.store.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            customer_order: self.customer_order.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            positions: self.positions.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            sales_channel: self.sales_channel.to_owned(),
+            shared: self.shared,
+            shipment_address: self.shipment_address.to_owned(),
+            state: self.state.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDemand {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    customer_order: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sales_channel: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipment_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateDemandBuilder {
+    agent: Option<MetaWrapper>,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    customer_order: Option<MetaWrapper>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    sales_channel: Option<MetaWrapper>,
+    shared: Option<bool>,
+    shipment_address: Option<String>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl UpdateDemandBuilder {
+    pub fn agent(&mut self, agent_meta: &Meta) -> &mut Self {
+        let _ = self.agent.insert(MetaWrapper {
+            meta: agent_meta.to_owned(),
+        });
+        self
+    }
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn customer_order(&mut self, customer_order_meta: &Meta) -> &mut Self {
+        let _ = self.customer_order.insert(MetaWrapper {
+            meta: customer_order_meta.to_owned(),
+        });
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn sales_channel(&mut self, sales_channel_meta: &Meta) -> &mut Self {
+        let _ = self.sales_channel.insert(MetaWrapper {
+            meta: sales_channel_meta.to_owned(),
+        });
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn shipment_address(&mut self, shipment_address: impl Into<String>) -> &mut Self {
+        let _ = self.shipment_address.insert(shipment_address.into());
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> UpdateDemand {
+        UpdateDemand {
+            agent: self.agent.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            customer_order: self.customer_order.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            sales_channel: self.sales_channel.to_owned(),
+            shared: self.shared,
+            shipment_address: self.shipment_address.to_owned(),
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::models::document::OverheadDistribution;
+
+    use super::*;
+
+    const DEMAND_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000201";
+    const ORDER_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000202";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn meta(path: &str) -> Meta {
+        serde_json::from_value(meta_json(path)).unwrap()
+    }
+
+    fn demand_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("demand/{DEMAND_ID}")),
+            "id": DEMAND_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "owner": { "meta": meta_json("employee/7a2c9b43-6c3e-11ee-0a80-0c5e00000002") },
+            "shared": false,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-02 10:00:00.000",
+            "name": "00017",
+            "externalCode": "ext-17",
+            "moment": "2024-05-02 09:58:00.000",
+            "applicable": true,
+            "rate": { "currency": { "meta": meta_json("currency/7a2c9b43-6c3e-11ee-0a80-0c5e00000004") } },
+            "sum": 150000.0,
+            "store": { "meta": meta_json("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005") },
+            "agent": { "meta": meta_json("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006") },
+            "organization": { "meta": meta_json("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007") },
+            "created": "2024-05-02 09:58:30.000",
+            "printed": true,
+            "published": false,
+            "customerOrder": { "meta": meta_json(&format!("customerorder/{ORDER_ID}")) },
+            "overhead": { "sum": 5000.0, "distribution": "weight" },
+            "positions": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/demand/{DEMAND_ID}/positions"),
+                    "type": "demandposition",
+                    "mediaType": "application/json",
+                    "size": 1
+                },
+                "rows": [{
+                    "meta": meta_json(&format!("demand/{DEMAND_ID}/positions/7a2c9b43-6c3e-11ee-0a80-0c5e00000210")),
+                    "id": "7a2c9b43-6c3e-11ee-0a80-0c5e00000210",
+                    "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+                    "quantity": 1.0,
+                    "price": 150000.0,
+                    "discount": 0.0,
+                    "vat": 20,
+                    "vatEnabled": true,
+                    "assortment": { "meta": meta_json("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009") },
+                    "overhead": 5000.0,
+                    "slot": { "meta": meta_json("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005/slots/7a2c9b43-6c3e-11ee-0a80-0c5e00000011") },
+                    "things": ["SN-001"]
+                }]
+            },
+            "vatEnabled": true,
+            "vatIncluded": true,
+            "vatSum": 25000.0,
+            "payedSum": 0.0
+        })
+    }
+
+    #[test]
+    fn demand_keeps_order_link_and_positions() {
+        let demand: Demand = serde_json::from_value(demand_json()).unwrap();
+        assert_eq!(demand.id.to_string(), DEMAND_ID);
+        assert_eq!(
+            demand
+                .customer_order
+                .as_ref()
+                .and_then(|order| order.id())
+                .map(|id| id.to_string()),
+            Some(ORDER_ID.to_string())
+        );
+        assert_eq!(demand.store.entity_type(), Some("store"));
+        assert_eq!(
+            demand.overhead.as_ref().map(|o| &o.distribution),
+            Some(&OverheadDistribution::Weight)
+        );
+        let position = &demand.positions.rows[0];
+        assert_eq!(
+            position.things.as_deref(),
+            Some(["SN-001".to_string()].as_slice())
+        );
+        assert!(position.slot.is_some());
+        assert_eq!(position.overhead, Some(5000.0));
+        assert!(position.cost.is_none());
+    }
+
+    #[test]
+    fn create_demand_links_order() {
+        let demand = Demand::create(
+            &meta("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007"),
+            &meta("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006"),
+            &meta("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005"),
+        )
+        .customer_order(&meta(&format!("customerorder/{ORDER_ID}")))
+        .position(
+            DemandPosition::create(&meta("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009"), 1.0)
+                .price(150000.0)
+                .slot(&meta(
+                    "store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005/slots/7a2c9b43-6c3e-11ee-0a80-0c5e00000011",
+                ))
+                .thing("SN-001")
+                .build(),
+        )
+        .build();
+        let value = serde_json::to_value(&demand).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                "agent",
+                "customerOrder",
+                "organization",
+                "positions",
+                "store"
+            ]
+        );
+        assert_eq!(value["customerOrder"]["meta"]["type"], "customerorder");
+        let position = &value["positions"][0];
+        assert_eq!(position["things"], json!(["SN-001"]));
+        assert_eq!(position["slot"]["meta"]["type"], "store");
+        assert!(position.get("discount").is_none());
+    }
+
+    #[test]
+    fn update_demand_serializes_set_fields_only() {
+        let update = Demand::update().description("Отгружено курьером").build();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "description": "Отгружено курьером" })
+        );
+    }
+}
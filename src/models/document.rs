@@ -0,0 +1,543 @@
+use std::marker::PhantomData;
+
+use serde::{ser::SerializeMap, Deserialize, Serialize, Serializer};
+
+use crate::api_client::HasPositions;
+
+use super::{assortment::BuyPrice, currency::Currency, ms_ref::MsRef, Meta, MetaWrapper};
+
+/// Позиции документа
+///
+/// Без `expand=positions` содержит только метаданные коллекции, `rows` пустой
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Positions<P> {
+    pub meta: Meta,
+    #[serde(default)]
+    pub rows: Vec<P>,
+}
+/// Валюта документа
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Rate {
+    /// Метаданные валюты
+    pub currency: MsRef<Currency>,
+    /// Курс валюты
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<f64>,
+}
+impl Rate {
+    pub fn new(currency_meta: &Meta) -> Self {
+        Self {
+            currency: MsRef::new(currency_meta.to_owned()),
+            value: None,
+        }
+    }
+}
+/// Накладные расходы документа
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Overhead {
+    /// Сумма накладных расходов в копейках
+    pub sum: f64,
+    /// Распределение накладных расходов
+    pub distribution: OverheadDistribution,
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OverheadDistribution {
+    /// По весу
+    Weight,
+    /// По объему
+    Volume,
+    /// По цене
+    #[default]
+    Price,
+}
+/// Основание для шаблона документа, например `{"customerOrder": {"meta": ...}}`
+///
+/// Используется в [`crate::MoySkladApiClient::template`] для получения
+/// предзаполненного документа на основе другого документа
+#[derive(Debug, Clone, PartialEq)]
+pub struct Template {
+    field: String,
+    base: MetaWrapper,
+}
+impl Template {
+    /// Основание по имени поля документа, например `customerOrder`
+    pub fn new(field: impl Into<String>, base_meta: &Meta) -> Self {
+        Self {
+            field: field.into(),
+            base: MetaWrapper {
+                meta: base_meta.to_owned(),
+            },
+        }
+    }
+    /// На основе Заказа покупателя
+    pub fn customer_order(customer_order_meta: &Meta) -> Self {
+        Self::new("customerOrder", customer_order_meta)
+    }
+    /// На основе Отгрузки
+    pub fn demand(demand_meta: &Meta) -> Self {
+        Self::new("demand", demand_meta)
+    }
+    /// На основе Заказа поставщику
+    pub fn purchase_order(purchase_order_meta: &Meta) -> Self {
+        Self::new("purchaseOrder", purchase_order_meta)
+    }
+    /// На основе Приемки
+    pub fn supply(supply_meta: &Meta) -> Self {
+        Self::new("supply", supply_meta)
+    }
+    /// На основе Инвентаризации, для Оприходования и Списания
+    pub fn inventory(inventory_meta: &Meta) -> Self {
+        Self::new("inventory", inventory_meta)
+    }
+}
+impl Serialize for Template {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(&self.field, &self.base)?;
+        map.end()
+    }
+}
+/// Документ, в позициях которого есть скидка и НДС: `discount`, `vat`, `vatEnabled`
+pub trait DiscountPositions: HasPositions {}
+/// Документ, в позициях которого есть резерв `reserve`
+pub trait ReservePositions: HasPositions {}
+/// Документ, в позициях которого есть ячейка склада `slot`
+pub trait SlotPositions: HasPositions {}
+/// Документ, в позициях которого есть серийные номера `things`
+pub trait ThingPositions: HasPositions {}
+/// Документ, в позициях которого есть причина оприходования/списания `reason`
+pub trait ReasonPositions: HasPositions {}
+/// Документ, в позициях которого есть ячейки перемещения `sourceSlot` и `targetSlot`
+pub trait TransferPositions: HasPositions {}
+/// Позиция для добавления в документ `D`
+///
+/// Сеттеры полей, которые есть не во всех документах, доступны только для документов
+/// с соответствующим маркером, например [`CreatePositionBuilder::reserve`] - для
+/// [`ReservePositions`]:
+///
+/// ```rust,compile_fail
+/// use rust_moysklad::{Meta, SupplyPosition};
+/// // в позициях Приемки нет резерва
+/// SupplyPosition::create(&Meta::default(), 1.0).reserve(1.0);
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePosition<D> {
+    assortment: MetaWrapper,
+    quantity: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reserve: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slot: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    things: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_slot: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_slot: Option<MetaWrapper>,
+    #[serde(skip)]
+    document: PhantomData<D>,
+}
+#[derive(Default)]
+pub struct CreatePositionBuilder<D> {
+    assortment: MetaWrapper,
+    quantity: f64,
+    price: Option<f64>,
+    discount: Option<f64>,
+    vat: Option<i32>,
+    vat_enabled: Option<bool>,
+    reserve: Option<f64>,
+    slot: Option<MetaWrapper>,
+    things: Option<Vec<String>>,
+    reason: Option<String>,
+    source_slot: Option<MetaWrapper>,
+    target_slot: Option<MetaWrapper>,
+    document: PhantomData<D>,
+}
+impl<D: HasPositions> CreatePositionBuilder<D> {
+    pub fn new(assortment_meta: &Meta, quantity: f64) -> Self {
+        Self {
+            assortment: MetaWrapper {
+                meta: assortment_meta.to_owned(),
+            },
+            quantity,
+            price: None,
+            discount: None,
+            vat: None,
+            vat_enabled: None,
+            reserve: None,
+            slot: None,
+            things: None,
+            reason: None,
+            source_slot: None,
+            target_slot: None,
+            document: PhantomData,
+        }
+    }
+    /// Цена товара/услуги в копейках
+    pub fn price(&mut self, price: f64) -> &mut Self {
+        let _ = self.price.insert(price);
+        self
+    }
+    /// Цена по закупочной цене товара
+    pub fn buy_price(&mut self, buy_price: &BuyPrice) -> &mut Self {
+        let _ = self.price.insert(buy_price.value);
+        self
+    }
+    pub fn build(&self) -> CreatePosition<D> {
+        CreatePosition {
+            assortment: self.assortment.to_owned(),
+            quantity: self.quantity,
+            price: self.price,
+            discount: self.discount,
+            vat: self.vat,
+            vat_enabled: self.vat_enabled,
+            reserve: self.reserve,
+            slot: self.slot.to_owned(),
+            things: self.things.to_owned(),
+            reason: self.reason.to_owned(),
+            source_slot: self.source_slot.to_owned(),
+            target_slot: self.target_slot.to_owned(),
+            document: PhantomData,
+        }
+    }
+}
+impl<D: DiscountPositions> CreatePositionBuilder<D> {
+    /// Процент скидки или наценки
+    pub fn discount(&mut self, discount: f64) -> &mut Self {
+        let _ = self.discount.insert(discount);
+        self
+    }
+    /// НДС, которым облагается текущая позиция
+    pub fn vat(&mut self, vat: i32) -> &mut Self {
+        let _ = self.vat.insert(vat);
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+}
+impl<D: ReservePositions> CreatePositionBuilder<D> {
+    /// Резерв данной позиции
+    pub fn reserve(&mut self, reserve: f64) -> &mut Self {
+        let _ = self.reserve.insert(reserve);
+        self
+    }
+}
+impl<D: SlotPositions> CreatePositionBuilder<D> {
+    /// Ячейка на складе
+    pub fn slot(&mut self, slot_meta: &Meta) -> &mut Self {
+        let _ = self.slot.insert(MetaWrapper {
+            meta: slot_meta.to_owned(),
+        });
+        self
+    }
+}
+impl<D: ThingPositions> CreatePositionBuilder<D> {
+    /// Серийный номер
+    pub fn thing(&mut self, thing: impl Into<String>) -> &mut Self {
+        self.things.get_or_insert(vec![]).push(thing.into());
+        self
+    }
+}
+impl<D: ReasonPositions> CreatePositionBuilder<D> {
+    /// Причина оприходования/списания
+    pub fn reason(&mut self, reason: impl Into<String>) -> &mut Self {
+        let _ = self.reason.insert(reason.into());
+        self
+    }
+}
+impl<D: TransferPositions> CreatePositionBuilder<D> {
+    /// Ячейка на складе, с которого совершается перемещение
+    pub fn source_slot(&mut self, source_slot_meta: &Meta) -> &mut Self {
+        let _ = self.source_slot.insert(MetaWrapper {
+            meta: source_slot_meta.to_owned(),
+        });
+        self
+    }
+    /// Ячейка на складе, на который совершается перемещение
+    pub fn target_slot(&mut self, target_slot_meta: &Meta) -> &mut Self {
+        let _ = self.target_slot.insert(MetaWrapper {
+            meta: target_slot_meta.to_owned(),
+        });
+        self
+    }
+}
+/// Изменение позиции документа `D`
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePosition<D> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    assortment: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quantity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reserve: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    slot: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    things: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_slot: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_slot: Option<MetaWrapper>,
+    #[serde(skip)]
+    document: PhantomData<D>,
+}
+#[derive(Default)]
+pub struct UpdatePositionBuilder<D> {
+    assortment: Option<MetaWrapper>,
+    quantity: Option<f64>,
+    price: Option<f64>,
+    discount: Option<f64>,
+    vat: Option<i32>,
+    vat_enabled: Option<bool>,
+    reserve: Option<f64>,
+    slot: Option<MetaWrapper>,
+    things: Option<Vec<String>>,
+    reason: Option<String>,
+    source_slot: Option<MetaWrapper>,
+    target_slot: Option<MetaWrapper>,
+    document: PhantomData<D>,
+}
+impl<D: HasPositions> UpdatePositionBuilder<D> {
+    pub fn new() -> Self {
+        Self {
+            assortment: None,
+            quantity: None,
+            price: None,
+            discount: None,
+            vat: None,
+            vat_enabled: None,
+            reserve: None,
+            slot: None,
+            things: None,
+            reason: None,
+            source_slot: None,
+            target_slot: None,
+            document: PhantomData,
+        }
+    }
+    pub fn assortment(&mut self, assortment_meta: &Meta) -> &mut Self {
+        let _ = self.assortment.insert(MetaWrapper {
+            meta: assortment_meta.to_owned(),
+        });
+        self
+    }
+    pub fn quantity(&mut self, quantity: f64) -> &mut Self {
+        let _ = self.quantity.insert(quantity);
+        self
+    }
+    /// Цена товара/услуги в копейках
+    pub fn price(&mut self, price: f64) -> &mut Self {
+        let _ = self.price.insert(price);
+        self
+    }
+    pub fn build(&self) -> UpdatePosition<D> {
+        UpdatePosition {
+            assortment: self.assortment.to_owned(),
+            quantity: self.quantity,
+            price: self.price,
+            discount: self.discount,
+            vat: self.vat,
+            vat_enabled: self.vat_enabled,
+            reserve: self.reserve,
+            slot: self.slot.to_owned(),
+            things: self.things.to_owned(),
+            reason: self.reason.to_owned(),
+            source_slot: self.source_slot.to_owned(),
+            target_slot: self.target_slot.to_owned(),
+            document: PhantomData,
+        }
+    }
+}
+impl<D: DiscountPositions> UpdatePositionBuilder<D> {
+    /// Процент скидки или наценки
+    pub fn discount(&mut self, discount: f64) -> &mut Self {
+        let _ = self.discount.insert(discount);
+        self
+    }
+    pub fn vat(&mut self, vat: i32) -> &mut Self {
+        let _ = self.vat.insert(vat);
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+}
+impl<D: ReservePositions> UpdatePositionBuilder<D> {
+    pub fn reserve(&mut self, reserve: f64) -> &mut Self {
+        let _ = self.reserve.insert(reserve);
+        self
+    }
+}
+impl<D: SlotPositions> UpdatePositionBuilder<D> {
+    /// Ячейка на складе
+    pub fn slot(&mut self, slot_meta: &Meta) -> &mut Self {
+        let _ = self.slot.insert(MetaWrapper {
+            meta: slot_meta.to_owned(),
+        });
+        self
+    }
+}
+impl<D: ThingPositions> UpdatePositionBuilder<D> {
+    /// Серийный номер
+    pub fn thing(&mut self, thing: impl Into<String>) -> &mut Self {
+        self.things.get_or_insert(vec![]).push(thing.into());
+        self
+    }
+}
+impl<D: ReasonPositions> UpdatePositionBuilder<D> {
+    /// Причина оприходования/списания
+    pub fn reason(&mut self, reason: impl Into<String>) -> &mut Self {
+        let _ = self.reason.insert(reason.into());
+        self
+    }
+}
+impl<D: TransferPositions> UpdatePositionBuilder<D> {
+    /// Ячейка на складе, с которого совершается перемещение
+    pub fn source_slot(&mut self, source_slot_meta: &Meta) -> &mut Self {
+        let _ = self.source_slot.insert(MetaWrapper {
+            meta: source_slot_meta.to_owned(),
+        });
+        self
+    }
+    /// Ячейка на складе, на который совершается перемещение
+    pub fn target_slot(&mut self, target_slot_meta: &Meta) -> &mut Self {
+        let _ = self.target_slot.insert(MetaWrapper {
+            meta: target_slot_meta.to_owned(),
+        });
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::models::customer_order::CustomerOrder;
+
+    use super::*;
+
+    fn meta(path: &str) -> Meta {
+        Meta {
+            href: format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            meta_type: path.split('/').next().map(str::to_string),
+            media_type: "application/json".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn positions_without_expand_have_no_rows() {
+        let positions: Positions<serde_json::Value> = serde_json::from_value(json!({
+            "meta": {
+                "href": "https://api.moysklad.ru/api/remap/1.2/entity/customerorder/1/positions",
+                "type": "customerorderposition",
+                "mediaType": "application/json",
+                "size": 2,
+                "limit": 1000,
+                "offset": 0
+            }
+        }))
+        .unwrap();
+        assert!(positions.rows.is_empty());
+        assert_eq!(positions.meta.size, Some(2));
+    }
+
+    #[test]
+    fn create_position_omits_unset_fields() {
+        let position = CreatePositionBuilder::<CustomerOrder>::new(&meta("product/1"), 2.0).build();
+        let value = serde_json::to_value(&position).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "assortment": { "meta": serde_json::to_value(meta("product/1")).unwrap() },
+                "quantity": 2.0
+            })
+        );
+    }
+
+    #[test]
+    fn create_position_with_marker_fields() {
+        let position = CreatePositionBuilder::<CustomerOrder>::new(&meta("product/1"), 2.0)
+            .price(150000.0)
+            .discount(5.0)
+            .vat(20)
+            .vat_enabled(true)
+            .reserve(2.0)
+            .build();
+        let value = serde_json::to_value(&position).unwrap();
+        assert_eq!(value["price"], json!(150000.0));
+        assert_eq!(value["discount"], json!(5.0));
+        assert_eq!(value["vat"], json!(20));
+        assert_eq!(value["vatEnabled"], json!(true));
+        assert_eq!(value["reserve"], json!(2.0));
+        assert!(value.get("slot").is_none());
+        assert!(value.get("things").is_none());
+    }
+
+    #[test]
+    fn update_position_sends_only_changed_fields() {
+        let update = UpdatePositionBuilder::<CustomerOrder>::new()
+            .quantity(3.0)
+            .reserve(1.0)
+            .build();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "quantity": 3.0, "reserve": 1.0 })
+        );
+        assert_eq!(
+            serde_json::to_value(UpdatePositionBuilder::<CustomerOrder>::new().build()).unwrap(),
+            json!({})
+        );
+    }
+
+    #[test]
+    fn template_is_keyed_by_base_field() {
+        let base = meta("customerorder/1");
+        let value = serde_json::to_value(Template::customer_order(&base)).unwrap();
+        assert_eq!(
+            value,
+            json!({ "customerOrder": { "meta": serde_json::to_value(&base).unwrap() } })
+        );
+        for (template, field) in [
+            (Template::demand(&base), "demand"),
+            (Template::purchase_order(&base), "purchaseOrder"),
+            (Template::supply(&base), "supply"),
+            (Template::inventory(&base), "inventory"),
+        ] {
+            let value = serde_json::to_value(template).unwrap();
+            assert!(value.get(field).is_some(), "{field}");
+        }
+    }
+}
@@ -0,0 +1,540 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::MsEntity;
+
+use super::{deserialize_date_from_str, ms_ref::MsRef, Attribute, Meta, MetaWrapper};
+
+/// Сотрудник
+/// Средствами JSON API можно создавать и обновлять сведения о Сотрудниках, запрашивать списки Сотрудников и сведения по отдельным Сотрудникам. Кодом сущности для Сотрудника в составе JSON API является ключевое слово employee.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{Employee, MoySkladApiClient};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let employee: Employee = client
+///         .create(
+///             Employee::create("Иванов")
+///                 .first_name("Иван")
+///                 .position("Кладовщик")
+///                 .email("ivanov@example.com")
+///                 .build(),
+///         )
+///         .await?;
+///     dbg!(employee.is_cashier(), employee.full_name);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Employee {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Добавлен ли Сотрудник в архив
+    pub archived: bool,
+    /// Дополнительные поля Сотрудника
+    pub attributes: Option<Vec<Attribute>>,
+    /// Массив кассиров, к которым привязан Сотрудник
+    pub cashiers: Option<Vec<Cashier>>,
+    /// Код Сотрудника
+    pub code: Option<String>,
+    /// Момент создания Сотрудника
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Комментарий к Сотруднику
+    pub description: Option<String>,
+    /// Электронная почта сотрудника
+    pub email: Option<String>,
+    /// Внешний код Сотрудника
+    pub external_code: String,
+    /// Имя
+    pub first_name: Option<String>,
+    /// Имя Отчество Фамилия
+    pub full_name: Option<String>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Сотрудника
+    pub id: Uuid,
+    /// Фотография сотрудника
+    pub image: Option<serde_json::Value>,
+    /// ИНН сотрудника (в формате ИНН физического лица)
+    pub inn: Option<String>,
+    /// Фамилия
+    pub last_name: String,
+    /// Метаданные Сотрудника
+    pub meta: Meta,
+    /// Отчество
+    pub middle_name: Option<String>,
+    /// Наименование Сотрудника
+    pub name: String,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Права Сотрудника. Заполняется только в ответе `context/employee`
+    pub permissions: Option<HashMap<String, Permission>>,
+    /// Телефон сотрудника
+    pub phone: Option<String>,
+    /// Должность сотрудника
+    pub position: Option<String>,
+    /// Общий доступ
+    pub shared: bool,
+    /// Краткое ФИО
+    pub short_fio: Option<String>,
+    /// Логин Сотрудника
+    pub uid: Option<String>,
+    /// Момент последнего обновления Сотрудника
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+}
+impl Employee {
+    pub fn create(last_name: impl Into<String>) -> CreateEmployeeBuilder {
+        CreateEmployeeBuilder::new(last_name)
+    }
+    pub fn update() -> UpdateEmployeeBuilder {
+        UpdateEmployeeBuilder::default()
+    }
+    /// Привязан ли Сотрудник хотя бы к одной точке продаж как кассир
+    pub fn is_cashier(&self) -> bool {
+        self.cashiers.as_ref().is_some_and(|c| !c.is_empty())
+    }
+}
+impl MsEntity for Employee {
+    fn path() -> String {
+        String::from("entity/employee")
+    }
+}
+impl_has_attributes!(Employee);
+/// Кассир - привязка Сотрудника к точке продаж
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cashier {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные сотрудника, которого представляет собой кассир
+    pub employee: MsRef<Employee>,
+    /// ID Кассира
+    pub id: Uuid,
+    /// Метаданные Кассира
+    pub meta: Meta,
+    /// Метаданные точки продаж, к которой прикреплен кассир
+    pub retail_store: MetaWrapper,
+}
+/// Права Сотрудника на сущность или отдельная разрешающая опция
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Permission {
+    /// Права на операции с сущностью
+    Entity(EntityPermission),
+    /// Флаг, например доступ к разделу
+    Flag(bool),
+}
+/// Права на операции с сущностью
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityPermission {
+    /// Просматривать
+    pub view: Option<PermissionScope>,
+    /// Создавать
+    pub create: Option<PermissionScope>,
+    /// Редактировать
+    pub update: Option<PermissionScope>,
+    /// Удалять
+    pub delete: Option<PermissionScope>,
+    /// Проводить
+    pub approve: Option<PermissionScope>,
+    /// Печатать
+    pub print: Option<PermissionScope>,
+}
+/// Область действия права
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PermissionScope {
+    /// Нет доступа
+    No,
+    /// Только свои
+    Own,
+    /// Свои и общие
+    OwnShared,
+    /// Свои, общие и своего отдела
+    Department,
+    /// Все
+    All,
+    /// Значение, не поддерживаемое библиотекой
+    #[serde(other)]
+    Other,
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEmployee {
+    last_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    middle_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreateEmployeeBuilder {
+    last_name: String,
+    archived: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    email: Option<String>,
+    external_code: Option<String>,
+    first_name: Option<String>,
+    group: Option<MetaWrapper>,
+    inn: Option<String>,
+    middle_name: Option<String>,
+    owner: Option<MetaWrapper>,
+    phone: Option<String>,
+    position: Option<String>,
+    shared: Option<bool>,
+}
+impl CreateEmployeeBuilder {
+    /// Фамилия Сотрудника
+    pub fn new(last_name: impl Into<String>) -> Self {
+        Self {
+            last_name: last_name.into(),
+            ..Default::default()
+        }
+    }
+    pub fn archived(&mut self, archived: bool) -> &mut Self {
+        let _ = self.archived.insert(archived);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn email(&mut self, email: impl Into<String>) -> &mut Self {
+        let _ = self.email.insert(email.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn first_name(&mut self, first_name: impl Into<String>) -> &mut Self {
+        let _ = self.first_name.insert(first_name.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn inn(&mut self, inn: impl Into<String>) -> &mut Self {
+        let _ = self.inn.insert(inn.into());
+        self
+    }
+    pub fn middle_name(&mut self, middle_name: impl Into<String>) -> &mut Self {
+        let _ = self.middle_name.insert(middle_name.into());
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn phone(&mut self, phone: impl Into<String>) -> &mut Self {
+        let _ = self.phone.insert(phone.into());
+        self
+    }
+    pub fn position(&mut self, position: impl Into<String>) -> &mut Self {
+        let _ = self.position.insert(position.into());
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn build(&self) -> CreateEmployee {
+        CreateEmployee {
+            last_name: self.last_name.to_owned(),
+            archived: self.archived,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            email: self.email.to_owned(),
+            external_code: self.external_code.to_owned(),
+            first_name: self.first_name.to_owned(),
+            group: self.group.to_owned(),
+            inn: self.inn.to_owned(),
+            middle_name: self.middle_name.to_owned(),
+            owner: self.owner.to_owned(),
+            phone: self.phone.to_owned(),
+            position: self.position.to_owned(),
+            shared: self.shared,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEmployee {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    first_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    middle_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    position: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateEmployeeBuilder {
+    archived: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    email: Option<String>,
+    external_code: Option<String>,
+    first_name: Option<String>,
+    group: Option<MetaWrapper>,
+    inn: Option<String>,
+    last_name: Option<String>,
+    middle_name: Option<String>,
+    owner: Option<MetaWrapper>,
+    phone: Option<String>,
+    position: Option<String>,
+    shared: Option<bool>,
+}
+impl UpdateEmployeeBuilder {
+    pub fn archived(&mut self, archived: bool) -> &mut Self {
+        let _ = self.archived.insert(archived);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn email(&mut self, email: impl Into<String>) -> &mut Self {
+        let _ = self.email.insert(email.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn first_name(&mut self, first_name: impl Into<String>) -> &mut Self {
+        let _ = self.first_name.insert(first_name.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn inn(&mut self, inn: impl Into<String>) -> &mut Self {
+        let _ = self.inn.insert(inn.into());
+        self
+    }
+    pub fn last_name(&mut self, last_name: impl Into<String>) -> &mut Self {
+        let _ = self.last_name.insert(last_name.into());
+        self
+    }
+    pub fn middle_name(&mut self, middle_name: impl Into<String>) -> &mut Self {
+        let _ = self.middle_name.insert(middle_name.into());
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn phone(&mut self, phone: impl Into<String>) -> &mut Self {
+        let _ = self.phone.insert(phone.into());
+        self
+    }
+    pub fn position(&mut self, position: impl Into<String>) -> &mut Self {
+        let _ = self.position.insert(position.into());
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn build(&self) -> UpdateEmployee {
+        UpdateEmployee {
+            archived: self.archived,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            email: self.email.to_owned(),
+            external_code: self.external_code.to_owned(),
+            first_name: self.first_name.to_owned(),
+            group: self.group.to_owned(),
+            inn: self.inn.to_owned(),
+            last_name: self.last_name.to_owned(),
+            middle_name: self.middle_name.to_owned(),
+            owner: self.owner.to_owned(),
+            phone: self.phone.to_owned(),
+            position: self.position.to_owned(),
+            shared: self.shared,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const EMPLOYEE_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000002";
+
+    fn context_employee_json() -> Value {
+        json!({
+            "meta": {
+                "href": "https://api.moysklad.ru/api/remap/1.2/context/employee",
+                "metadataHref": "https://api.moysklad.ru/api/remap/1.2/entity/employee/metadata",
+                "type": "employee",
+                "mediaType": "application/json"
+            },
+            "id": EMPLOYEE_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "owner": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/employee/{EMPLOYEE_ID}"),
+                    "type": "employee",
+                    "mediaType": "application/json"
+                }
+            },
+            "shared": true,
+            "group": {
+                "meta": {
+                    "href": "https://api.moysklad.ru/api/remap/1.2/entity/group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003",
+                    "type": "group",
+                    "mediaType": "application/json"
+                }
+            },
+            "updated": "2024-05-01 12:00:00.000",
+            "created": "2023-01-10 09:00:00.000",
+            "name": "Администратор",
+            "externalCode": "admin",
+            "archived": false,
+            "uid": "admin@romashka",
+            "email": "admin@romashka.ru",
+            "lastName": "Администратор",
+            "fullName": "Администратор",
+            "shortFio": "Администратор",
+            "permissions": {
+                "currency": {
+                    "view": "ALL",
+                    "create": "ALL",
+                    "update": "ALL",
+                    "delete": "ALL"
+                },
+                "customerOrder": {
+                    "view": "OWN_SHARED",
+                    "create": "OWN",
+                    "update": "DEPARTMENT",
+                    "delete": "NO",
+                    "approve": "ALL",
+                    "print": "SOME_NEW_SCOPE"
+                },
+                "viewDashboard": true,
+                "importData": false
+            }
+        })
+    }
+
+    #[test]
+    fn context_employee_with_both_permission_shapes() {
+        let employee: Employee = serde_json::from_value(context_employee_json()).unwrap();
+        assert_eq!(employee.uid.as_deref(), Some("admin@romashka"));
+        assert!(!employee.is_cashier());
+        let permissions = employee.permissions.unwrap();
+        assert_eq!(permissions.len(), 4);
+        assert_eq!(
+            permissions["customerOrder"],
+            Permission::Entity(EntityPermission {
+                view: Some(PermissionScope::OwnShared),
+                create: Some(PermissionScope::Own),
+                update: Some(PermissionScope::Department),
+                delete: Some(PermissionScope::No),
+                approve: Some(PermissionScope::All),
+                print: Some(PermissionScope::Other),
+            })
+        );
+        match &permissions["currency"] {
+            Permission::Entity(permission) => {
+                assert_eq!(permission.view, Some(PermissionScope::All));
+                assert!(permission.approve.is_none());
+            }
+            other => panic!("expected entity permission, got {other:?}"),
+        }
+        assert_eq!(permissions["viewDashboard"], Permission::Flag(true));
+        assert_eq!(permissions["importData"], Permission::Flag(false));
+    }
+}
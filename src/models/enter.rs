@@ -0,0 +1,597 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{
+        CreatePosition, CreatePositionBuilder, Overhead, Positions, Rate, ReasonPositions,
+        SlotPositions, ThingPositions, UpdatePositionBuilder,
+    },
+    employee::Employee,
+    inventory::Inventory,
+    ms_ref::MsRef,
+    organization::Organization,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Оприходование
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{Enter, Inventory, MoySkladApiClient, Template};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let inventory = client.get::<Inventory>(uuid::Uuid::nil()).await?;
+///     // оприходование излишков по результатам инвентаризации
+///     let enter: Enter = client
+///         .create_from_template(&Template::inventory(&inventory.meta))
+///         .await?;
+///     dbg!(enter.sum);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Enter {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Отметка о проведении
+    pub applicable: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Оприходования
+    pub code: Option<String>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Момент последнего удаления Оприходования
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Комментарий Оприходования
+    pub description: Option<String>,
+    /// Внешний код Оприходования
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Оприходования
+    pub id: Uuid,
+    /// Ссылка на Инвентаризацию, на основании которой создано Оприходование
+    pub inventory: Option<MsRef<Inventory>>,
+    /// Метаданные Оприходования
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Оприходования
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Накладные расходы
+    pub overhead: Option<Overhead>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Метаданные позиций Оприходования
+    pub positions: Positions<EnterPosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Метаданные проекта
+    pub project: Option<MetaWrapper>,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Валюта
+    pub rate: Rate,
+    /// Общий доступ
+    pub shared: bool,
+    /// Метаданные статуса Оприходования
+    pub state: Option<MsRef<State>>,
+    /// Метаданные склада
+    pub store: MsRef<Store>,
+    /// Сумма Оприходования в копейках
+    pub sum: f64,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Момент последнего обновления Оприходования
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+}
+impl Enter {
+    pub fn create(organization_meta: &Meta, store_meta: &Meta) -> CreateEnterBuilder {
+        CreateEnterBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            store: MetaWrapper {
+                meta: store_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateEnterBuilder {
+        UpdateEnterBuilder::default()
+    }
+}
+impl MsEntity for Enter {
+    fn path() -> String {
+        String::from("entity/enter")
+    }
+}
+impl_has_attributes!(Enter);
+impl HasFiles for Enter {}
+impl HasPositions for Enter {
+    type Position = EnterPosition;
+}
+impl SlotPositions for Enter {}
+impl ThingPositions for Enter {}
+impl ReasonPositions for Enter {}
+/// Позиция Оприходования
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnterPosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// Метаданные страны
+    pub country: Option<MetaWrapper>,
+    /// ID позиции
+    pub id: Uuid,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Накладные расходы, распределенные на позицию
+    pub overhead: Option<f64>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+    /// Причина оприходования данной позиции
+    pub reason: Option<String>,
+    /// Ячейка на складе
+    pub slot: Option<MetaWrapper>,
+    /// Серийные номера
+    pub things: Option<Vec<String>>,
+}
+impl EnterPosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<Enter> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<Enter> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateEnter {
+    organization: MetaWrapper,
+    store: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overhead: Option<Overhead>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<Enter>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct CreateEnterBuilder {
+    organization: MetaWrapper,
+    store: MetaWrapper,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    overhead: Option<Overhead>,
+    owner: Option<MetaWrapper>,
+    positions: Option<Vec<CreatePosition<Enter>>>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+}
+impl CreateEnterBuilder {
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn overhead(&mut self, overhead: Overhead) -> &mut Self {
+        let _ = self.overhead.insert(overhead);
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<Enter>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> CreateEnter {
+        CreateEnter {
+            organization: self.organization.to_owned(),
+            store: self.store.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            overhead: self.overhead.to_owned(),
+            owner: self.owner.to_owned(),
+            positions: self.positions.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEnter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overhead: Option<Overhead>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct UpdateEnterBuilder {
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    overhead: Option<Overhead>,
+    owner: Option<MetaWrapper>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+}
+impl UpdateEnterBuilder {
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn overhead(&mut self, overhead: Overhead) -> &mut Self {
+        let _ = self.overhead.insert(overhead);
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> UpdateEnter {
+        UpdateEnter {
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            overhead: self.overhead.to_owned(),
+            owner: self.owner.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const ENTER_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000401";
+    const INVENTORY_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000404";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn meta(path: &str) -> Meta {
+        serde_json::from_value(meta_json(path)).unwrap()
+    }
+
+    fn enter_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("enter/{ENTER_ID}")),
+            "id": ENTER_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "shared": false,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-06 10:00:00.000",
+            "name": "00004",
+            "externalCode": "ext-4",
+            "moment": "2024-05-06 09:00:00.000",
+            "applicable": true,
+            "rate": { "currency": { "meta": meta_json("currency/7a2c9b43-6c3e-11ee-0a80-0c5e00000004") } },
+            "sum": 50000.0,
+            "store": { "meta": meta_json("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005") },
+            "organization": { "meta": meta_json("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007") },
+            "created": "2024-05-06 09:00:30.000",
+            "printed": false,
+            "published": false,
+            "inventory": { "meta": meta_json(&format!("inventory/{INVENTORY_ID}")) },
+            "positions": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/enter/{ENTER_ID}/positions"),
+                    "type": "enterposition",
+                    "mediaType": "application/json",
+                    "size": 1
+                },
+                "rows": [{
+                    "meta": meta_json(&format!("enter/{ENTER_ID}/positions/7a2c9b43-6c3e-11ee-0a80-0c5e00000410")),
+                    "id": "7a2c9b43-6c3e-11ee-0a80-0c5e00000410",
+                    "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+                    "quantity": 1.0,
+                    "price": 50000.0,
+                    "assortment": { "meta": meta_json("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009") },
+                    "reason": "Излишки по инвентаризации",
+                    "slot": { "meta": meta_json("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005/slots/7a2c9b43-6c3e-11ee-0a80-0c5e00000011") },
+                    "things": ["SN-201"]
+                }]
+            }
+        })
+    }
+
+    #[test]
+    fn enter_keeps_inventory_link() {
+        let enter: Enter = serde_json::from_value(enter_json()).unwrap();
+        assert_eq!(enter.id.to_string(), ENTER_ID);
+        assert_eq!(
+            enter
+                .inventory
+                .as_ref()
+                .and_then(|inventory| inventory.id()),
+            Some(INVENTORY_ID.parse().unwrap())
+        );
+        assert_eq!(
+            enter
+                .inventory
+                .as_ref()
+                .and_then(|inventory| inventory.entity_type()),
+            Some("inventory")
+        );
+        let position = &enter.positions.rows[0];
+        assert_eq!(
+            position.reason.as_deref(),
+            Some("Излишки по инвентаризации")
+        );
+        assert!(position.slot.is_some());
+        assert!(position.overhead.is_none());
+    }
+
+    #[test]
+    fn enter_without_inventory() {
+        let mut value = enter_json();
+        value.as_object_mut().unwrap().remove("inventory");
+        let enter: Enter = serde_json::from_value(value).unwrap();
+        assert!(enter.inventory.is_none());
+    }
+
+    #[test]
+    fn create_enter_skips_unset_fields() {
+        let enter = Enter::create(
+            &meta("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007"),
+            &meta("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005"),
+        )
+        .position(
+            EnterPosition::create(&meta("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009"), 1.0)
+                .reason("Излишки")
+                .thing("SN-201")
+                .build(),
+        )
+        .build();
+        let value = serde_json::to_value(&enter).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(keys, ["organization", "positions", "store"]);
+        assert_eq!(value["positions"][0]["reason"], "Излишки");
+        assert_eq!(value["positions"][0]["things"], json!(["SN-201"]));
+    }
+}
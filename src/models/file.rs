@@ -0,0 +1,54 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::{deserialize_date_from_str, employee::Employee, ms_ref::MsRef, Meta};
+
+/// Файл, прикрепленный к товару, контрагенту или документу
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{MoySkladApiClient, Product, Upload};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let id = uuid::Uuid::nil();
+///     let certificate = Upload::from_path("certificates/carpet.pdf").await?;
+///     let files = client.add_files::<Product>(id, vec![certificate]).await?;
+///     for file in client.get_files::<Product>(id).await? {
+///         let content = client.download_file(&file).await?;
+///         dbg!(&file.filename, content.len());
+///     }
+///     if let Some(old_id) = files.first().and_then(|old| old.id()) {
+///         client.delete_file::<Product>(id, old_id).await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MsFile {
+    /// Метаданные файла, `downloadHref` - ссылка на скачивание
+    pub meta: Meta,
+    /// Название файла
+    pub title: String,
+    /// Имя файла
+    pub filename: String,
+    /// Размер файла в байтах
+    pub size: i64,
+    /// Время загрузки файла на сервер
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Сотрудник, загрузивший файл
+    pub created_by: Option<MsRef<Employee>>,
+    /// Метаданные миниатюры, если файл является изображением
+    pub miniature: Option<Meta>,
+    /// Метаданные уменьшенного изображения, если файл является изображением
+    pub tiny: Option<Meta>,
+}
+impl MsFile {
+    /// ID файла из `meta.href`
+    pub fn id(&self) -> Option<uuid::Uuid> {
+        self.meta.id()
+    }
+}
@@ -0,0 +1,153 @@
+use std::path::Path;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use super::{deserialize_date_from_str, Meta};
+
+/// Изображение товара или модификации
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{ImageSize, MoySkladApiClient, Product, Upload};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let id = uuid::Uuid::nil();
+///     let photo = Upload::from_path("feed/photos/carpet.jpg").await?;
+///     let images = client.add_images::<Product>(id, vec![photo]).await?;
+///     for image in &images {
+///         let tiny = client.download_image(image, ImageSize::Tiny).await?;
+///         dbg!(&image.filename, tiny.len());
+///     }
+///     if let Some(old_id) = images.first().and_then(|old| old.id()) {
+///         client.delete_image::<Product>(id, old_id).await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Image {
+    /// Метаданные изображения, `downloadHref` - ссылка на оригинал
+    pub meta: Meta,
+    /// Название изображения
+    pub title: String,
+    /// Имя файла
+    pub filename: String,
+    /// Размер файла в байтах
+    pub size: i64,
+    /// Время загрузки файла на сервер
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Метаданные миниатюры изображения
+    pub miniature: Meta,
+    /// Метаданные уменьшенного изображения
+    pub tiny: Option<Meta>,
+}
+impl Image {
+    /// ID изображения из `meta.href`
+    pub fn id(&self) -> Option<uuid::Uuid> {
+        self.meta.id()
+    }
+    /// Ссылка на скачивание изображения указанного размера
+    pub fn download_href(&self, size: ImageSize) -> Option<&str> {
+        let meta = match size {
+            ImageSize::Original => Some(&self.meta),
+            ImageSize::Miniature => Some(&self.miniature),
+            ImageSize::Tiny => self.tiny.as_ref(),
+        };
+        meta.and_then(|meta| meta.download_href.as_deref().or(Some(meta.href.as_str())))
+    }
+}
+/// Размер изображения для скачивания
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum ImageSize {
+    /// Оригинал
+    #[default]
+    Original,
+    /// Миниатюра
+    Miniature,
+    /// Уменьшенное изображение
+    Tiny,
+}
+/// Файл для загрузки: имя и содержимое в base64
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Upload {
+    filename: String,
+    content: String,
+}
+impl Upload {
+    /// Файл из памяти, содержимое кодируется в base64
+    pub fn from_bytes(filename: impl Into<String>, bytes: impl AsRef<[u8]>) -> Self {
+        Self {
+            filename: filename.into(),
+            content: STANDARD.encode(bytes),
+        }
+    }
+    /// Прочитать файл с диска, имя файла берется из пути
+    pub async fn from_path(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path).await?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(Self::from_bytes(filename, bytes))
+    }
+    /// Имя файла
+    pub fn filename(&self) -> &str {
+        &self.filename
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn upload_serializes_base64_content() {
+        let upload = Upload::from_bytes("carpet.jpg", b"hello");
+        assert_eq!(upload.filename(), "carpet.jpg");
+        assert_eq!(
+            serde_json::to_value(&upload).unwrap(),
+            json!({ "filename": "carpet.jpg", "content": "aGVsbG8=" })
+        );
+    }
+
+    #[test]
+    fn download_href_falls_back_to_meta_href() {
+        let image: Image = serde_json::from_value(json!({
+            "meta": {
+                "href": "https://api.moysklad.ru/api/remap/1.2/entity/product/1/images/2",
+                "type": "image",
+                "mediaType": "application/json",
+                "downloadHref": "https://api.moysklad.ru/api/remap/1.2/download/2"
+            },
+            "title": "carpet",
+            "filename": "carpet.jpg",
+            "size": 5,
+            "updated": "2024-05-01 12:00:00.000",
+            "miniature": {
+                "href": "https://miniature-prod.moysklad.ru/miniature/1/documentminiature/2",
+                "type": "image",
+                "mediaType": "image/jpeg"
+            }
+        }))
+        .unwrap();
+        assert_eq!(
+            image.download_href(ImageSize::Original),
+            Some("https://api.moysklad.ru/api/remap/1.2/download/2")
+        );
+        assert_eq!(
+            image.download_href(ImageSize::Miniature),
+            Some("https://miniature-prod.moysklad.ru/miniature/1/documentminiature/2")
+        );
+        assert_eq!(image.download_href(ImageSize::Tiny), None);
+    }
+}
@@ -0,0 +1,492 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{CreatePosition, CreatePositionBuilder, Positions, UpdatePositionBuilder},
+    employee::Employee,
+    ms_ref::MsRef,
+    organization::Organization,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Инвентаризация
+///
+/// Расчетные остатки и излишки/недостачи по позициям доступны в `calculatedQuantity` и
+/// `correctionAmount`. По результатам можно создать [`crate::Enter`] и [`crate::Loss`]
+/// через [`crate::Template::inventory`]
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{Inventory, InventoryPosition, MoySkladApiClient, Product};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let template = client.list::<Inventory>(1, 0, None::<String>).await?.rows.remove(0);
+///     let product = client.get::<Product>(uuid::Uuid::nil()).await?;
+///     let inventory = Inventory::create(&template.organization.meta, &template.store.meta)
+///         .position(InventoryPosition::create(&product.meta, 7.0).build())
+///         .build();
+///     let created: Inventory = client.create(inventory).await?;
+///     for position in client.get_positions::<Inventory>(created.id).await? {
+///         dbg!(position.calculated_quantity, position.correction_amount);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Inventory {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Инвентаризации
+    pub code: Option<String>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Момент последнего удаления Инвентаризации
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Комментарий Инвентаризации
+    pub description: Option<String>,
+    /// Внешний код Инвентаризации
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Инвентаризации
+    pub id: Uuid,
+    /// Метаданные Инвентаризации
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Инвентаризации
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Метаданные позиций Инвентаризации
+    pub positions: Positions<InventoryPosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Общий доступ
+    pub shared: bool,
+    /// Метаданные статуса Инвентаризации
+    pub state: Option<MsRef<State>>,
+    /// Метаданные склада
+    pub store: MsRef<Store>,
+    /// Сумма Инвентаризации в копейках
+    pub sum: f64,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Момент последнего обновления Инвентаризации
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+}
+impl Inventory {
+    pub fn create(organization_meta: &Meta, store_meta: &Meta) -> CreateInventoryBuilder {
+        CreateInventoryBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            store: MetaWrapper {
+                meta: store_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateInventoryBuilder {
+        UpdateInventoryBuilder::default()
+    }
+}
+impl MsEntity for Inventory {
+    fn path() -> String {
+        String::from("entity/inventory")
+    }
+}
+impl_has_attributes!(Inventory);
+impl HasFiles for Inventory {}
+impl HasPositions for Inventory {
+    type Position = InventoryPosition;
+}
+/// Позиция Инвентаризации
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InventoryPosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// Расчетный остаток
+    pub calculated_quantity: f64,
+    /// Разница между расчетным остатком и фактическим
+    pub correction_amount: f64,
+    /// Избыток/недостача в копейках
+    pub correction_sum: f64,
+    /// ID позиции
+    pub id: Uuid,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+}
+impl InventoryPosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<Inventory> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<Inventory> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInventory {
+    organization: MetaWrapper,
+    store: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<Inventory>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct CreateInventoryBuilder {
+    organization: MetaWrapper,
+    store: MetaWrapper,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    owner: Option<MetaWrapper>,
+    positions: Option<Vec<CreatePosition<Inventory>>>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+}
+impl CreateInventoryBuilder {
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<Inventory>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> CreateInventory {
+        CreateInventory {
+            organization: self.organization.to_owned(),
+            store: self.store.to_owned(),
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            owner: self.owner.to_owned(),
+            positions: self.positions.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateInventory {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct UpdateInventoryBuilder {
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+}
+impl UpdateInventoryBuilder {
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> UpdateInventory {
+        UpdateInventory {
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            owner: self.owner.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const INVENTORY_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000404";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn meta(path: &str) -> Meta {
+        serde_json::from_value(meta_json(path)).unwrap()
+    }
+
+    fn inventory_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("inventory/{INVENTORY_ID}")),
+            "id": INVENTORY_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "shared": false,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-06 08:00:00.000",
+            "name": "00001",
+            "externalCode": "ext-1",
+            "moment": "2024-05-06 07:00:00.000",
+            "sum": 30000.0,
+            "store": { "meta": meta_json("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005") },
+            "organization": { "meta": meta_json("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007") },
+            "created": "2024-05-06 07:00:30.000",
+            "printed": false,
+            "published": false,
+            "positions": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/inventory/{INVENTORY_ID}/positions"),
+                    "type": "inventoryposition",
+                    "mediaType": "application/json",
+                    "size": 1
+                },
+                "rows": [{
+                    "meta": meta_json(&format!("inventory/{INVENTORY_ID}/positions/7a2c9b43-6c3e-11ee-0a80-0c5e00000440")),
+                    "id": "7a2c9b43-6c3e-11ee-0a80-0c5e00000440",
+                    "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+                    "quantity": 3.0,
+                    "price": 10000.0,
+                    "assortment": { "meta": meta_json("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009") },
+                    "calculatedQuantity": 5.0,
+                    "correctionAmount": -2.0,
+                    "correctionSum": -20000.0
+                }]
+            }
+        })
+    }
+
+    #[test]
+    fn inventory_position_keeps_correction() {
+        let inventory: Inventory = serde_json::from_value(inventory_json()).unwrap();
+        assert_eq!(inventory.id.to_string(), INVENTORY_ID);
+        assert_eq!(inventory.store.entity_type(), Some("store"));
+        let position = &inventory.positions.rows[0];
+        assert_eq!(position.calculated_quantity, 5.0);
+        assert_eq!(position.correction_amount, -2.0);
+        assert_eq!(position.correction_sum, -20000.0);
+    }
+
+    #[test]
+    fn create_inventory_skips_unset_fields() {
+        let inventory = Inventory::create(
+            &meta("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007"),
+            &meta("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005"),
+        )
+        .position(
+            InventoryPosition::create(&meta("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009"), 3.0)
+                .build(),
+        )
+        .build();
+        let value = serde_json::to_value(&inventory).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(keys, ["organization", "positions", "store"]);
+        assert_eq!(
+            value["positions"][0],
+            json!({
+                "assortment": {
+                    "meta": serde_json::to_value(meta("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009")).unwrap()
+                },
+                "quantity": 3.0
+            })
+        );
+    }
+}
@@ -0,0 +1,804 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    counterparty::Counterparty,
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{
+        CreatePosition, CreatePositionBuilder, DiscountPositions, Positions, Rate,
+        UpdatePositionBuilder,
+    },
+    employee::Employee,
+    ms_ref::MsRef,
+    organization::Organization,
+    purchase_order::PurchaseOrder,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    supply::Supply,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Счет поставщика
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{InvoiceIn, MoySkladApiClient, PurchaseOrder, Template};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let order = client.get::<PurchaseOrder>(uuid::Uuid::nil()).await?;
+///     let invoice: InvoiceIn = client
+///         .create_from_template(&Template::purchase_order(&order.meta))
+///         .await?;
+///     let update = InvoiceIn::update().incoming_number("СЧ-17").build();
+///     let invoice: InvoiceIn = client.update(invoice.id, update).await?;
+///     dbg!(invoice.payment_planned_moment);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceIn {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные контрагента
+    pub agent: MsRef<Counterparty>,
+    /// Метаданные счета контрагента
+    pub agent_account: Option<MetaWrapper>,
+    /// Отметка о проведении
+    pub applicable: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Счета поставщика
+    pub code: Option<String>,
+    /// Метаданные договора
+    pub contract: Option<MetaWrapper>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Момент последнего удаления Счета поставщика
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Комментарий Счета поставщика
+    pub description: Option<String>,
+    /// Внешний код Счета поставщика
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Счета поставщика
+    pub id: Uuid,
+    /// Входящая дата
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub incoming_date: Option<NaiveDateTime>,
+    /// Входящий номер
+    pub incoming_number: Option<String>,
+    /// Метаданные Счета поставщика
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Счета поставщика
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Метаданные счета юрлица
+    pub organization_account: Option<MetaWrapper>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Сумма исходящих платежей по Счету поставщика
+    pub payed_sum: f64,
+    /// Планируемая дата оплаты
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub payment_planned_moment: Option<NaiveDateTime>,
+    /// Метаданные позиций Счета поставщика
+    pub positions: Positions<InvoiceInPosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Метаданные проекта
+    pub project: Option<MetaWrapper>,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Ссылка на связанный заказ поставщику
+    pub purchase_order: Option<MsRef<PurchaseOrder>>,
+    /// Валюта
+    pub rate: Rate,
+    /// Общий доступ
+    pub shared: bool,
+    /// Сумма принятого
+    pub shipped_sum: f64,
+    /// Метаданные статуса Счета поставщика
+    pub state: Option<MsRef<State>>,
+    /// Метаданные склада
+    pub store: Option<MsRef<Store>>,
+    /// Сумма Счета поставщика в копейках
+    pub sum: f64,
+    /// Связанные приемки
+    pub supplies: Option<Vec<MsRef<Supply>>>,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Момент последнего обновления Счета поставщика
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Учитывается ли НДС
+    pub vat_enabled: bool,
+    /// Включен ли НДС в цену
+    pub vat_included: Option<bool>,
+    /// Сумма НДС
+    pub vat_sum: f64,
+}
+impl InvoiceIn {
+    pub fn create(organization_meta: &Meta, agent_meta: &Meta) -> CreateInvoiceInBuilder {
+        CreateInvoiceInBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            agent: MetaWrapper {
+                meta: agent_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateInvoiceInBuilder {
+        UpdateInvoiceInBuilder::default()
+    }
+}
+impl MsEntity for InvoiceIn {
+    fn path() -> String {
+        String::from("entity/invoicein")
+    }
+}
+impl_has_attributes!(InvoiceIn);
+impl HasFiles for InvoiceIn {}
+impl HasPositions for InvoiceIn {
+    type Position = InvoiceInPosition;
+}
+impl DiscountPositions for InvoiceIn {}
+/// Позиция Счета поставщика
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvoiceInPosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// Процент скидки или наценки
+    pub discount: f64,
+    /// ID позиции
+    pub id: Uuid,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+    /// НДС, которым облагается текущая позиция
+    pub vat: i32,
+    /// Включен ли НДС для позиции
+    pub vat_enabled: bool,
+}
+impl InvoiceInPosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<InvoiceIn> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<InvoiceIn> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateInvoiceIn {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    incoming_date: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    incoming_number: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    payment_planned_moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<InvoiceIn>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreateInvoiceInBuilder {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    incoming_date: Option<NaiveDateTime>,
+    incoming_number: Option<String>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    payment_planned_moment: Option<NaiveDateTime>,
+    positions: Option<Vec<CreatePosition<InvoiceIn>>>,
+    project: Option<MetaWrapper>,
+    purchase_order: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl CreateInvoiceInBuilder {
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn incoming_date(&mut self, incoming_date: NaiveDateTime) -> &mut Self {
+        let _ = self.incoming_date.insert(incoming_date);
+        self
+    }
+    pub fn incoming_number(&mut self, incoming_number: impl Into<String>) -> &mut Self {
+        let _ = self.incoming_number.insert(incoming_number.into());
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn payment_planned_moment(&mut self, payment_planned_moment: NaiveDateTime) -> &mut Self {
+        let _ = self.payment_planned_moment.insert(payment_planned_moment);
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<InvoiceIn>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn purchase_order(&mut self, purchase_order_meta: &Meta) -> &mut Self {
+        let _ = self.purchase_order.insert(MetaWrapper {
+            meta: purchase_order_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> CreateInvoiceIn {
+        CreateInvoiceIn {
+            organization: self.organization.to_owned(),
+            agent: self.agent.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            incoming_date: self.incoming_date,
+            incoming_number: self.incoming_number.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            payment_planned_moment: self.payment_planned_moment,
+            positions: self.positions.to_owned(),
+            project: self.project.to_owned(),
+            purchase_order: self.purchase_order.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateInvoiceIn {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    incoming_date: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    incoming_number: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    payment_planned_moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateInvoiceInBuilder {
+    agent: Option<MetaWrapper>,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    incoming_date: Option<NaiveDateTime>,
+    incoming_number: Option<String>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    payment_planned_moment: Option<NaiveDateTime>,
+    project: Option<MetaWrapper>,
+    purchase_order: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl UpdateInvoiceInBuilder {
+    pub fn agent(&mut self, agent_meta: &Meta) -> &mut Self {
+        let _ = self.agent.insert(MetaWrapper {
+            meta: agent_meta.to_owned(),
+        });
+        self
+    }
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn incoming_date(&mut self, incoming_date: NaiveDateTime) -> &mut Self {
+        let _ = self.incoming_date.insert(incoming_date);
+        self
+    }
+    pub fn incoming_number(&mut self, incoming_number: impl Into<String>) -> &mut Self {
+        let _ = self.incoming_number.insert(incoming_number.into());
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn payment_planned_moment(&mut self, payment_planned_moment: NaiveDateTime) -> &mut Self {
+        let _ = self.payment_planned_moment.insert(payment_planned_moment);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn purchase_order(&mut self, purchase_order_meta: &Meta) -> &mut Self {
+        let _ = self.purchase_order.insert(MetaWrapper {
+            meta: purchase_order_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> UpdateInvoiceIn {
+        UpdateInvoiceIn {
+            agent: self.agent.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            incoming_date: self.incoming_date,
+            incoming_number: self.incoming_number.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            payment_planned_moment: self.payment_planned_moment,
+            project: self.project.to_owned(),
+            purchase_order: self.purchase_order.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const INVOICE_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000303";
+    const ORDER_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000301";
+    const SUPPLY_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000302";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn meta(path: &str) -> Meta {
+        serde_json::from_value(meta_json(path)).unwrap()
+    }
+
+    fn invoice_in_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("invoicein/{INVOICE_ID}")),
+            "id": INVOICE_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "shared": false,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-03 11:00:00.000",
+            "name": "00005",
+            "externalCode": "ext-5",
+            "moment": "2024-05-03 10:30:00.000",
+            "applicable": true,
+            "rate": { "currency": { "meta": meta_json("currency/7a2c9b43-6c3e-11ee-0a80-0c5e00000004") } },
+            "sum": 200000.0,
+            "agent": { "meta": meta_json("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006") },
+            "organization": { "meta": meta_json("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007") },
+            "created": "2024-05-03 10:30:30.000",
+            "printed": false,
+            "published": false,
+            "incomingNumber": "СЧ-7",
+            "incomingDate": "2024-05-02 00:00:00.000",
+            "paymentPlannedMoment": "2024-05-15 00:00:00.000",
+            "positions": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/invoicein/{INVOICE_ID}/positions"),
+                    "type": "invoiceposition",
+                    "mediaType": "application/json",
+                    "size": 0
+                }
+            },
+            "vatEnabled": true,
+            "vatIncluded": true,
+            "vatSum": 33333.33,
+            "payedSum": 0.0,
+            "shippedSum": 100000.0,
+            "purchaseOrder": { "meta": meta_json(&format!("purchaseorder/{ORDER_ID}")) },
+            "supplies": [{ "meta": meta_json(&format!("supply/{SUPPLY_ID}")) }]
+        })
+    }
+
+    #[test]
+    fn invoice_in_keeps_linked_documents() {
+        let invoice: InvoiceIn = serde_json::from_value(invoice_in_json()).unwrap();
+        assert_eq!(invoice.id.to_string(), INVOICE_ID);
+        assert_eq!(
+            invoice.purchase_order.as_ref().and_then(|order| order.id()),
+            Some(ORDER_ID.parse().unwrap())
+        );
+        assert_eq!(
+            invoice.supplies.as_ref().map(|supplies| supplies[0].id()),
+            Some(Some(SUPPLY_ID.parse().unwrap()))
+        );
+        assert_eq!(
+            invoice.payment_planned_moment,
+            NaiveDate::from_ymd_opt(2024, 5, 15)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+        );
+        assert_eq!(invoice.incoming_number.as_deref(), Some("СЧ-7"));
+        assert!(invoice.store.is_none());
+        assert!(invoice.positions.rows.is_empty());
+    }
+
+    #[test]
+    fn create_invoice_in_links_purchase_order() {
+        let invoice = InvoiceIn::create(
+            &meta("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007"),
+            &meta("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006"),
+        )
+        .purchase_order(&meta(&format!("purchaseorder/{ORDER_ID}")))
+        .payment_planned_moment(
+            NaiveDate::from_ymd_opt(2024, 5, 15)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        )
+        .incoming_number("СЧ-7")
+        .build();
+        let value = serde_json::to_value(&invoice).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                "agent",
+                "incomingNumber",
+                "organization",
+                "paymentPlannedMoment",
+                "purchaseOrder"
+            ]
+        );
+        assert_eq!(value["purchaseOrder"]["meta"]["type"], "purchaseorder");
+        assert_eq!(value["paymentPlannedMoment"], "2024-05-15 00:00:00.000");
+    }
+
+    #[test]
+    fn update_invoice_in_serializes_set_fields_only() {
+        let update = InvoiceIn::update().incoming_number("СЧ-8").build();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "incomingNumber": "СЧ-8" })
+        );
+    }
+}
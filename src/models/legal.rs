@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+use super::{country::Country, ms_ref::MsRef, region::Region};
+
+/// Адрес
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Address {
+    /// Другое
+    pub add_info: Option<String>,
+    /// Почтовый индекс
+    pub postal_code: Option<String>,
+    /// Метаданные страны
+    pub country: Option<MsRef<Country>>,
+    /// Метаданные региона
+    pub region: Option<MsRef<Region>>,
+    /// Город
+    pub city: Option<String>,
+    /// Улица
+    pub street: Option<String>,
+    /// Дом
+    pub house: Option<String>,
+    /// Квартира
+    pub apartment: Option<String>,
+    /// Комментарий
+    pub comment: Option<String>,
+}
+
+/// Тип юрлица
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CompanyType {
+    /// Юридическое лицо
+    Legal,
+    /// Индивидуальный предприниматель
+    Entrepreneur,
+    /// Физическое лицо
+    #[default]
+    Individual,
+}
+
+/// Реквизиты юрлица, общие для Контрагента и Юрлица
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegalDetails {
+    /// Полное наименование юрлица
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_title: Option<String>,
+    /// Юридический адрес юрлица
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_address: Option<String>,
+    /// Юридический адрес юрлица с детализацией по отдельным полям
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_address_full: Option<Address>,
+    /// ИНН
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inn: Option<String>,
+    /// КПП
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kpp: Option<String>,
+    /// ОГРН
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ogrn: Option<String>,
+    /// ОКПО
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub okpo: Option<String>,
+    /// ОГРНИП
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ogrnip: Option<String>,
+    /// Фамилия для юрлица типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для юрлиц типа [Юридическое лицо]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_last_name: Option<String>,
+    /// Имя для юрлица типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для юрлиц типа [Юридическое лицо]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_first_name: Option<String>,
+    /// Отчество для юрлица типа [Индивидуальный предприниматель, Физическое лицо]. Игнорируется для юрлиц типа [Юридическое лицо]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub legal_middle_name: Option<String>,
+    /// Номер свидетельства
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_number: Option<String>,
+    /// Дата свидетельства
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub certificate_date: Option<String>,
+}
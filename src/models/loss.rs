@@ -0,0 +1,562 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{
+        CreatePosition, CreatePositionBuilder, Positions, Rate, ReasonPositions, SlotPositions,
+        ThingPositions, UpdatePositionBuilder,
+    },
+    employee::Employee,
+    inventory::Inventory,
+    ms_ref::MsRef,
+    organization::Organization,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Списание
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{Inventory, Loss, MoySkladApiClient, Template};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let inventory = client.get::<Inventory>(uuid::Uuid::nil()).await?;
+///     // списание недостачи по результатам инвентаризации
+///     let loss: Loss = client
+///         .create_from_template(&Template::inventory(&inventory.meta))
+///         .await?;
+///     dbg!(loss.sum);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Loss {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Отметка о проведении
+    pub applicable: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Списания
+    pub code: Option<String>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Момент последнего удаления Списания
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Комментарий Списания
+    pub description: Option<String>,
+    /// Внешний код Списания
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Списания
+    pub id: Uuid,
+    /// Ссылка на Инвентаризацию, на основании которой создано Списание
+    pub inventory: Option<MsRef<Inventory>>,
+    /// Метаданные Списания
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Списания
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Метаданные позиций Списания
+    pub positions: Positions<LossPosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Метаданные проекта
+    pub project: Option<MetaWrapper>,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Валюта
+    pub rate: Rate,
+    /// Общий доступ
+    pub shared: bool,
+    /// Метаданные статуса Списания
+    pub state: Option<MsRef<State>>,
+    /// Метаданные склада
+    pub store: MsRef<Store>,
+    /// Сумма Списания в копейках
+    pub sum: f64,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Момент последнего обновления Списания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+}
+impl Loss {
+    pub fn create(organization_meta: &Meta, store_meta: &Meta) -> CreateLossBuilder {
+        CreateLossBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            store: MetaWrapper {
+                meta: store_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateLossBuilder {
+        UpdateLossBuilder::default()
+    }
+}
+impl MsEntity for Loss {
+    fn path() -> String {
+        String::from("entity/loss")
+    }
+}
+impl_has_attributes!(Loss);
+impl HasFiles for Loss {}
+impl HasPositions for Loss {
+    type Position = LossPosition;
+}
+impl SlotPositions for Loss {}
+impl ThingPositions for Loss {}
+impl ReasonPositions for Loss {}
+/// Позиция Списания
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LossPosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// ID позиции
+    pub id: Uuid,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+    /// Причина списания данной позиции
+    pub reason: Option<String>,
+    /// Ячейка на складе
+    pub slot: Option<MetaWrapper>,
+    /// Серийные номера
+    pub things: Option<Vec<String>>,
+}
+impl LossPosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<Loss> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<Loss> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateLoss {
+    organization: MetaWrapper,
+    store: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<Loss>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct CreateLossBuilder {
+    organization: MetaWrapper,
+    store: MetaWrapper,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    owner: Option<MetaWrapper>,
+    positions: Option<Vec<CreatePosition<Loss>>>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+}
+impl CreateLossBuilder {
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<Loss>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> CreateLoss {
+        CreateLoss {
+            organization: self.organization.to_owned(),
+            store: self.store.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            owner: self.owner.to_owned(),
+            positions: self.positions.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLoss {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct UpdateLossBuilder {
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+}
+impl UpdateLossBuilder {
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> UpdateLoss {
+        UpdateLoss {
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            owner: self.owner.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const LOSS_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000402";
+    const INVENTORY_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000404";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn meta(path: &str) -> Meta {
+        serde_json::from_value(meta_json(path)).unwrap()
+    }
+
+    fn loss_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("loss/{LOSS_ID}")),
+            "id": LOSS_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "shared": false,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-06 11:00:00.000",
+            "name": "00003",
+            "externalCode": "ext-3",
+            "moment": "2024-05-06 10:30:00.000",
+            "applicable": true,
+            "rate": { "currency": { "meta": meta_json("currency/7a2c9b43-6c3e-11ee-0a80-0c5e00000004") } },
+            "sum": 20000.0,
+            "store": { "meta": meta_json("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005") },
+            "organization": { "meta": meta_json("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007") },
+            "created": "2024-05-06 10:30:30.000",
+            "printed": false,
+            "published": false,
+            "inventory": { "meta": meta_json(&format!("inventory/{INVENTORY_ID}")) },
+            "positions": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/loss/{LOSS_ID}/positions"),
+                    "type": "lossposition",
+                    "mediaType": "application/json",
+                    "size": 1
+                },
+                "rows": [{
+                    "meta": meta_json(&format!("loss/{LOSS_ID}/positions/7a2c9b43-6c3e-11ee-0a80-0c5e00000420")),
+                    "id": "7a2c9b43-6c3e-11ee-0a80-0c5e00000420",
+                    "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+                    "quantity": 2.0,
+                    "price": 10000.0,
+                    "assortment": { "meta": meta_json("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009") },
+                    "reason": "Недостача"
+                }]
+            }
+        })
+    }
+
+    #[test]
+    fn loss_keeps_inventory_link() {
+        let loss: Loss = serde_json::from_value(loss_json()).unwrap();
+        assert_eq!(loss.id.to_string(), LOSS_ID);
+        assert_eq!(
+            loss.inventory.as_ref().and_then(|inventory| inventory.id()),
+            Some(INVENTORY_ID.parse().unwrap())
+        );
+        assert_eq!(
+            loss.inventory
+                .as_ref()
+                .and_then(|inventory| inventory.entity_type()),
+            Some("inventory")
+        );
+        let position = &loss.positions.rows[0];
+        assert_eq!(position.reason.as_deref(), Some("Недостача"));
+        assert!(position.slot.is_none());
+        assert!(position.things.is_none());
+    }
+
+    #[test]
+    fn create_loss_skips_unset_fields() {
+        let loss = Loss::create(
+            &meta("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007"),
+            &meta("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005"),
+        )
+        .position(
+            LossPosition::create(&meta("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009"), 2.0)
+                .reason("Недостача")
+                .slot(&meta(
+                    "store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005/slots/7a2c9b43-6c3e-11ee-0a80-0c5e00000011",
+                ))
+                .build(),
+        )
+        .build();
+        let value = serde_json::to_value(&loss).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(keys, ["organization", "positions", "store"]);
+        let position = &value["positions"][0];
+        assert_eq!(position["reason"], "Недостача");
+        assert_eq!(position["slot"]["meta"]["type"], "store");
+        assert!(position.get("things").is_none());
+    }
+}
@@ -1,19 +1,60 @@
+use std::marker::PhantomData;
+
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
-use serde_with::skip_serializing_none;
 
-use crate::api_client::MsEntity;
+use crate::{api_client::MsEntity, error::MoySkladError};
+
+pub use self::custom_entity::CustomEntity;
+use self::{employee::Employee, image::Upload, ms_ref::MsRef, product::Product};
+
+/// Реализует [`HasAttributes`] для сущности с полем `attributes: Option<Vec<Attribute>>`
+macro_rules! impl_has_attributes {
+    ($entity:ty) => {
+        impl $crate::models::HasAttributes for $entity {
+            fn attribute_list(&self) -> &[$crate::models::Attribute] {
+                self.attributes.as_deref().unwrap_or_default()
+            }
+            fn attribute_list_mut(&mut self) -> &mut Vec<$crate::models::Attribute> {
+                self.attributes.get_or_insert(vec![])
+            }
+        }
+    };
+}
 
 pub mod assortment;
+pub mod audit;
 pub mod characteristic;
 pub mod counterparty;
 pub mod country;
 pub mod currency;
+pub mod custom_entity;
+pub mod customer_order;
+pub mod demand;
+pub mod document;
+pub mod employee;
+pub mod enter;
+pub mod file;
+pub mod image;
+pub mod inventory;
+pub mod invoice_in;
+pub mod legal;
+pub mod loss;
+pub mod move_document;
+pub mod ms_ref;
+pub mod organization;
 pub mod product;
 pub mod product_folder;
+pub mod purchase_order;
+pub mod purchase_return;
 pub mod region;
+pub mod sales_return;
+pub mod state;
+pub mod store;
+pub mod supply;
 pub mod uom;
 pub mod variant;
+pub mod webhook;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -22,6 +63,8 @@ pub struct Meta {
     pub metadata_href: Option<String>,
     #[serde(rename = "type")]
     pub meta_type: Option<String>,
+    /// Не передается в ссылках из тела вебхука
+    #[serde(default)]
     pub media_type: String,
     pub uuid_href: Option<String>,
     pub download_href: Option<String>,
@@ -29,6 +72,15 @@ pub struct Meta {
     pub limit: Option<i32>,
     pub offset: Option<i32>,
 }
+impl Meta {
+    /// ID сущности - последний сегмент `href`
+    pub fn id(&self) -> Option<uuid::Uuid> {
+        let path = self.href.split(['?', '#']).next().unwrap_or_default();
+        path.rsplit('/')
+            .find(|segment| !segment.is_empty())
+            .and_then(|segment| uuid::Uuid::parse_str(segment).ok())
+    }
+}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -40,7 +92,7 @@ pub struct EntityResponse<T> {
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Context {
-    pub employee: MetaWrapper,
+    pub employee: MsRef<Employee>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -82,10 +134,43 @@ where
         None => Ok(None),
     }
 }
-/// Дополнительные поля
+pub fn serialize_option_date_to_str<S>(
+    date: &Option<NaiveDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    match date {
+        Some(date) => serializer.serialize_str(&date.format("%Y-%m-%d %H:%M:%S%.3f").to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+/// Описание дополнительного поля сущности `E`, `{path}/metadata/attributes`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{
+///     Attribute, AttributeMetadata, AttributeType, AttributeValue, Counterparty, MoySkladApiClient,
+/// };
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let field = AttributeMetadata::<Counterparty>::create("Менеджер", AttributeType::String)
+///         .required(false)
+///         .build();
+///     let field: AttributeMetadata<Counterparty> = client.create(field).await?;
+///     let fields = client.get_all::<AttributeMetadata<Counterparty>>().await?;
+///     dbg!(fields.len());
+///     let attribute = Attribute::from_field(&field, AttributeValue::String("Иванов".into()))?;
+///     dbg!(attribute.as_str());
+///     Ok(())
+/// }
+/// ```
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ProductsCustomField {
+pub struct AttributeMetadata<E> {
     pub custom_entity_meta: Option<Meta>,
     pub meta: Meta,
     pub id: uuid::Uuid,
@@ -94,27 +179,141 @@ pub struct ProductsCustomField {
     pub attribute_type: AttributeType,
     pub required: bool,
     pub description: Option<String>,
+    #[serde(skip)]
+    entity: PhantomData<E>,
+}
+/// Дополнительные поля товаров
+pub type ProductsCustomField = AttributeMetadata<Product>;
+impl<E: MsEntity> AttributeMetadata<E> {
+    pub fn create(
+        name: impl Into<String>,
+        attribute_type: AttributeType,
+    ) -> CreateAttributeMetadataBuilder {
+        CreateAttributeMetadataBuilder::new(name, attribute_type)
+    }
+    pub fn update() -> UpdateAttributeMetadataBuilder {
+        UpdateAttributeMetadataBuilder::default()
+    }
 }
-impl MsEntity for ProductsCustomField {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/product/metadata/attributes")
+impl<E: MsEntity> MsEntity for AttributeMetadata<E> {
+    fn path() -> String {
+        format!("{}/metadata/attributes", E::path())
     }
 }
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CustomEntity {
-    pub account_id: uuid::Uuid,
-    pub code: Option<String>,
-    pub description: Option<String>,
-    pub external_code: String,
-    pub id: uuid::Uuid,
-    pub meta: Meta,
-    pub name: String,
-    #[serde(deserialize_with = "deserialize_date_from_str")]
-    pub updated: NaiveDateTime,
-    pub group: MetaWrapper,
-    pub owner: MetaWrapper,
-    pub shared: Option<bool>,
+pub struct CreateAttributeMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    custom_entity_meta: Option<Meta>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show: Option<bool>,
+    #[serde(rename = "type")]
+    attribute_type: AttributeType,
+}
+#[derive(Default)]
+pub struct CreateAttributeMetadataBuilder {
+    custom_entity_meta: Option<Meta>,
+    description: Option<String>,
+    name: String,
+    required: Option<bool>,
+    show: Option<bool>,
+    attribute_type: AttributeType,
+}
+impl CreateAttributeMetadataBuilder {
+    /// Наименование и тип доп. поля
+    pub fn new(name: impl Into<String>, attribute_type: AttributeType) -> Self {
+        Self {
+            name: name.into(),
+            attribute_type,
+            ..Default::default()
+        }
+    }
+    /// Метаданные справочника для доп. поля типа `customentity`
+    pub fn custom_entity_meta(&mut self, custom_entity_meta: &Meta) -> &mut Self {
+        let _ = self
+            .custom_entity_meta
+            .insert(custom_entity_meta.to_owned());
+        self
+    }
+    /// Описание доп. поля
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    /// Является ли доп. поле обязательным
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        let _ = self.required.insert(required);
+        self
+    }
+    /// Показывать ли доп. поле на карточке сущности
+    pub fn show(&mut self, show: bool) -> &mut Self {
+        let _ = self.show.insert(show);
+        self
+    }
+    pub fn build(&self) -> CreateAttributeMetadata {
+        CreateAttributeMetadata {
+            custom_entity_meta: self.custom_entity_meta.to_owned(),
+            description: self.description.to_owned(),
+            name: self.name.to_owned(),
+            required: self.required,
+            show: self.show,
+            attribute_type: self.attribute_type.to_owned(),
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAttributeMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    show: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateAttributeMetadataBuilder {
+    description: Option<String>,
+    name: Option<String>,
+    required: Option<bool>,
+    show: Option<bool>,
+}
+impl UpdateAttributeMetadataBuilder {
+    /// Описание доп. поля
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    /// Наименование доп. поля
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    /// Является ли доп. поле обязательным
+    pub fn required(&mut self, required: bool) -> &mut Self {
+        let _ = self.required.insert(required);
+        self
+    }
+    /// Показывать ли доп. поле на карточке сущности
+    pub fn show(&mut self, show: bool) -> &mut Self {
+        let _ = self.show.insert(show);
+        self
+    }
+    pub fn build(&self) -> UpdateAttributeMetadata {
+        UpdateAttributeMetadata {
+            description: self.description.to_owned(),
+            name: self.name.to_owned(),
+            required: self.required,
+            show: self.show,
+        }
+    }
 }
 // #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 // #[serde(untagged)]
@@ -152,28 +351,236 @@ pub struct CustomEntity {
 //     }
 // }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-#[skip_serializing_none]
+/// Значение дополнительного поля сущности
+///
+/// Вариант значения выбирается по типу поля `type`. Для отправки значения используйте
+/// [`Attribute::from_field`], который проверяет соответствие значения типу поля.
+#[derive(Debug, Clone, PartialEq)]
 pub struct Attribute {
     pub meta: Meta,
     pub id: uuid::Uuid,
     pub name: String,
-    #[serde(rename = "type")]
     pub attribute_type: AttributeType,
     pub value: AttributeValue,
-    pub download: Option<DownloadMeta>,
 }
 impl Attribute {
-    pub fn from_field(field: &ProductsCustomField, value: AttributeValue) -> Self {
-        Self {
+    /// Значение для доп. поля. Возвращает ошибку, если тип значения не совпадает с типом поля
+    pub fn from_field<E>(
+        field: &AttributeMetadata<E>,
+        value: AttributeValue,
+    ) -> crate::error::Result<Self> {
+        if !value.matches(&field.attribute_type) {
+            return Err(MoySkladError::InvalidArgument(format!(
+                "value {value:?} does not match type {:?} of attribute '{}'",
+                field.attribute_type, field.name
+            )));
+        }
+        Ok(Self {
             meta: field.meta.clone(),
             id: field.id,
             name: field.name.clone(),
             attribute_type: field.attribute_type.clone(),
             value,
-            download: None,
+        })
+    }
+    /// Значение полей типа `string`, `text` и `link`
+    pub fn as_str(&self) -> Option<&str> {
+        self.value.as_str()
+    }
+    /// Значение поля типа `long`
+    pub fn as_long(&self) -> Option<i64> {
+        self.value.as_long()
+    }
+    /// Значение поля типа `double`
+    pub fn as_double(&self) -> Option<f64> {
+        self.value.as_double()
+    }
+    /// Значение поля типа `boolean`
+    pub fn as_bool(&self) -> Option<bool> {
+        self.value.as_bool()
+    }
+    /// Значение поля типа `time`
+    pub fn as_time(&self) -> Option<NaiveDateTime> {
+        self.value.as_time()
+    }
+    /// Значение поля типа `customentity`
+    pub fn as_custom_value(&self) -> Option<&CustomValue> {
+        self.value.as_custom_value()
+    }
+    /// Значение поля типа `file`
+    pub fn as_file(&self) -> Option<&FileValue> {
+        self.value.as_file()
+    }
+    /// Метаданные сущности в поле-ссылке, например типа `counterparty`
+    pub fn as_entity(&self) -> Option<&Meta> {
+        self.value.as_entity()
+    }
+}
+impl Serialize for Attribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct Raw<'a> {
+            meta: &'a Meta,
+            id: &'a uuid::Uuid,
+            name: &'a str,
+            #[serde(rename = "type")]
+            attribute_type: &'a AttributeType,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            value: Option<&'a AttributeValue>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            download: Option<&'a DownloadMeta>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            file: Option<&'a Upload>,
+        }
+        // загружаемый файл передается в поле `file` вместо `value`
+        let (value, file) = match &self.value {
+            AttributeValue::Upload(upload) => (None, Some(upload)),
+            value => (Some(value), None),
+        };
+        Raw {
+            meta: &self.meta,
+            id: &self.id,
+            name: &self.name,
+            attribute_type: &self.attribute_type,
+            value,
+            download: self.as_file().and_then(|file| file.download.as_ref()),
+            file,
+        }
+        .serialize(serializer)
+    }
+}
+impl<'de> Deserialize<'de> for Attribute {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Raw {
+            meta: Meta,
+            id: uuid::Uuid,
+            name: String,
+            #[serde(rename = "type")]
+            attribute_type: AttributeType,
+            #[serde(default)]
+            value: serde_json::Value,
+            download: Option<DownloadMeta>,
         }
+        let raw = Raw::deserialize(deserializer)?;
+        let value = AttributeValue::from_json(&raw.attribute_type, raw.value, raw.download)
+            .map_err(|e| serde::de::Error::custom(format!("attribute '{}': {e}", raw.name)))?;
+        Ok(Self {
+            meta: raw.meta,
+            id: raw.id,
+            name: raw.name,
+            attribute_type: raw.attribute_type,
+            value,
+        })
+    }
+}
+/// Сущность с дополнительными полями `attributes`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use anyhow::Result;
+/// use rust_moysklad::{
+///     Attribute, AttributeValue, HasAttributes, MoySkladApiClient, Product, ProductsCustomField,
+/// };
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let fields = client.get_all::<ProductsCustomField>().await?;
+///     let mut products = client.search::<Product>("Краска").await?;
+///     if let (Some(field), Some(product)) = (
+///         fields.iter().find(|f| f.name == "Вес, г"),
+///         products.first_mut(),
+///     ) {
+///         dbg!(product.attribute_value::<i64>("Вес, г"));
+///         product.set_attribute(Attribute::from_field(field, AttributeValue::Long(500))?);
+///         let update = Product::update()
+///             .attributes(product.attribute_list().to_vec())
+///             .build();
+///         let _: Product = client.update(product.id, update).await?;
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait HasAttributes {
+    /// Доп. поля сущности
+    fn attribute_list(&self) -> &[Attribute];
+    /// Изменяемый список доп. полей сущности
+    fn attribute_list_mut(&mut self) -> &mut Vec<Attribute>;
+    /// Доп. поле по id
+    fn attribute(&self, id: uuid::Uuid) -> Option<&Attribute> {
+        self.attribute_list().iter().find(|a| a.id == id)
+    }
+    /// Доп. поле по названию
+    fn attribute_by_name(&self, name: &str) -> Option<&Attribute> {
+        self.attribute_list().iter().find(|a| a.name == name)
+    }
+    /// Значение доп. поля по названию, если оно соответствует запрошенному типу
+    fn attribute_value<T: FromAttributeValue>(&self, name: &str) -> Option<T> {
+        self.attribute_by_name(name)
+            .and_then(|a| T::from_attribute_value(&a.value))
+    }
+    /// Устанавливает доп. поле, заменяя поле с тем же id. Возвращает прежнее значение
+    fn set_attribute(&mut self, attribute: Attribute) -> Option<Attribute> {
+        let attributes = self.attribute_list_mut();
+        match attributes.iter_mut().find(|a| a.id == attribute.id) {
+            Some(existing) => Some(std::mem::replace(existing, attribute)),
+            None => {
+                attributes.push(attribute);
+                None
+            }
+        }
+    }
+    /// Удаляет доп. поле по id
+    fn remove_attribute(&mut self, id: uuid::Uuid) -> Option<Attribute> {
+        let attributes = self.attribute_list_mut();
+        let index = attributes.iter().position(|a| a.id == id)?;
+        Some(attributes.remove(index))
+    }
+}
+/// Типизированное значение доп. поля для [`HasAttributes::attribute_value`]
+pub trait FromAttributeValue: Sized {
+    fn from_attribute_value(value: &AttributeValue) -> Option<Self>;
+}
+impl FromAttributeValue for String {
+    fn from_attribute_value(value: &AttributeValue) -> Option<Self> {
+        value.as_str().map(String::from)
+    }
+}
+impl FromAttributeValue for i64 {
+    fn from_attribute_value(value: &AttributeValue) -> Option<Self> {
+        value.as_long()
+    }
+}
+impl FromAttributeValue for f64 {
+    fn from_attribute_value(value: &AttributeValue) -> Option<Self> {
+        value.as_double()
+    }
+}
+impl FromAttributeValue for bool {
+    fn from_attribute_value(value: &AttributeValue) -> Option<Self> {
+        value.as_bool()
+    }
+}
+impl FromAttributeValue for NaiveDateTime {
+    fn from_attribute_value(value: &AttributeValue) -> Option<Self> {
+        value.as_time()
+    }
+}
+impl FromAttributeValue for CustomValue {
+    fn from_attribute_value(value: &AttributeValue) -> Option<Self> {
+        value.as_custom_value().cloned()
+    }
+}
+impl FromAttributeValue for FileValue {
+    fn from_attribute_value(value: &AttributeValue) -> Option<Self> {
+        value.as_file().cloned()
     }
 }
 // #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -269,18 +676,195 @@ impl From<CustomEntity> for CustomValue {
         }
     }
 }
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(untagged)]
+impl From<&CustomEntity> for CustomValue {
+    fn from(value: &CustomEntity) -> Self {
+        Self {
+            meta: value.meta.to_owned(),
+            name: value.name.to_owned(),
+        }
+    }
+}
+/// Значение дополнительного поля, вариант соответствует [`AttributeType`]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AttributeValue {
+    /// `customentity` - элемент справочника
     Custom(CustomValue),
+    /// `string` - строка
     String(String),
-    #[serde(deserialize_with = "deserialize_date_from_str")]
-    Date(NaiveDateTime),
+    /// `text` - текст
+    Text(String),
+    /// `link` - ссылка
+    Link(String),
+    /// `time` - дата
+    Time(NaiveDateTime),
+    /// `boolean` - флажок
     Bool(bool),
-    Float(f64),
-    Int(i32),
-    #[default]
-    Other,
+    /// `double` - число дробное
+    Double(f64),
+    /// `long` - число целое
+    Long(i64),
+    /// `file` - файл, полученный от API
+    File(FileValue),
+    /// `file` - файл для загрузки, в доп. поле отправляется как `file` вместо `value`
+    Upload(Upload),
+    /// Ссылка на сущность для полей `counterparty`, `employee`, `store` и т.п.
+    Entity(MetaWrapper),
+    /// Значение `null` или значение поля неизвестного типа без преобразования.
+    /// Подходит для поля любого типа, `null` очищает значение
+    Other(serde_json::Value),
+}
+impl AttributeValue {
+    /// Соответствует ли значение типу доп. поля
+    pub fn matches(&self, attribute_type: &AttributeType) -> bool {
+        matches!(
+            (self, attribute_type),
+            (AttributeValue::Custom(_), AttributeType::Customentity)
+                | (AttributeValue::String(_), AttributeType::String)
+                | (AttributeValue::Text(_), AttributeType::Text)
+                | (AttributeValue::Link(_), AttributeType::Link)
+                | (AttributeValue::Time(_), AttributeType::Time)
+                | (AttributeValue::Bool(_), AttributeType::Boolean)
+                | (AttributeValue::Double(_), AttributeType::Double)
+                | (AttributeValue::Long(_), AttributeType::Long)
+                | (AttributeValue::File(_), AttributeType::File)
+                | (AttributeValue::Upload(_), AttributeType::File)
+                | (AttributeValue::Other(_), _)
+        ) || (matches!(self, AttributeValue::Entity(_)) && attribute_type.is_entity())
+    }
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            AttributeValue::String(value)
+            | AttributeValue::Text(value)
+            | AttributeValue::Link(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_long(&self) -> Option<i64> {
+        match self {
+            AttributeValue::Long(value) => Some(*value),
+            _ => None,
+        }
+    }
+    pub fn as_double(&self) -> Option<f64> {
+        match self {
+            AttributeValue::Double(value) => Some(*value),
+            _ => None,
+        }
+    }
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            AttributeValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+    pub fn as_time(&self) -> Option<NaiveDateTime> {
+        match self {
+            AttributeValue::Time(value) => Some(*value),
+            _ => None,
+        }
+    }
+    pub fn as_custom_value(&self) -> Option<&CustomValue> {
+        match self {
+            AttributeValue::Custom(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_file(&self) -> Option<&FileValue> {
+        match self {
+            AttributeValue::File(value) => Some(value),
+            _ => None,
+        }
+    }
+    pub fn as_entity(&self) -> Option<&Meta> {
+        match self {
+            AttributeValue::Entity(value) => Some(&value.meta),
+            _ => None,
+        }
+    }
+    /// Значение из JSON по типу доп. поля
+    fn from_json(
+        attribute_type: &AttributeType,
+        value: serde_json::Value,
+        download: Option<DownloadMeta>,
+    ) -> Result<Self, String> {
+        let mismatch = |value: &serde_json::Value| {
+            format!("unexpected value {value} for type {attribute_type:?}")
+        };
+        if value.is_null() {
+            return Ok(AttributeValue::Other(value));
+        }
+        match attribute_type {
+            AttributeType::String | AttributeType::Text | AttributeType::Link => {
+                let text = value.as_str().ok_or_else(|| mismatch(&value))?.to_string();
+                Ok(match attribute_type {
+                    AttributeType::Text => AttributeValue::Text(text),
+                    AttributeType::Link => AttributeValue::Link(text),
+                    _ => AttributeValue::String(text),
+                })
+            }
+            AttributeType::Long => value
+                .as_i64()
+                .map(AttributeValue::Long)
+                .ok_or_else(|| mismatch(&value)),
+            AttributeType::Double => value
+                .as_f64()
+                .map(AttributeValue::Double)
+                .ok_or_else(|| mismatch(&value)),
+            AttributeType::Boolean => value
+                .as_bool()
+                .map(AttributeValue::Bool)
+                .ok_or_else(|| mismatch(&value)),
+            AttributeType::Time => value
+                .as_str()
+                .and_then(|date| NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S%.3f").ok())
+                .map(AttributeValue::Time)
+                .ok_or_else(|| mismatch(&value)),
+            AttributeType::Customentity => serde_json::from_value::<CustomValue>(value.clone())
+                .map(AttributeValue::Custom)
+                .map_err(|_| mismatch(&value)),
+            AttributeType::File => {
+                let filename = value.as_str().ok_or_else(|| mismatch(&value))?.to_string();
+                Ok(AttributeValue::File(FileValue { filename, download }))
+            }
+            attribute_type if attribute_type.is_entity() => {
+                serde_json::from_value::<MetaWrapper>(value.clone())
+                    .map(AttributeValue::Entity)
+                    .map_err(|_| mismatch(&value))
+            }
+            _ => Ok(AttributeValue::Other(value)),
+        }
+    }
+}
+impl Serialize for AttributeValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            AttributeValue::Custom(value) => value.serialize(serializer),
+            AttributeValue::String(value)
+            | AttributeValue::Text(value)
+            | AttributeValue::Link(value) => serializer.serialize_str(value),
+            AttributeValue::Time(value) => {
+                serializer.serialize_str(&value.format("%Y-%m-%d %H:%M:%S%.3f").to_string())
+            }
+            AttributeValue::Bool(value) => serializer.serialize_bool(*value),
+            AttributeValue::Double(value) => serializer.serialize_f64(*value),
+            AttributeValue::Long(value) => serializer.serialize_i64(*value),
+            AttributeValue::File(value) => serializer.serialize_str(&value.filename),
+            AttributeValue::Upload(value) => value.serialize(serializer),
+            AttributeValue::Entity(value) => value.serialize(serializer),
+            AttributeValue::Other(value) => value.serialize(serializer),
+        }
+    }
+}
+/// Файл в доп. поле
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct FileValue {
+    /// Имя файла
+    pub filename: String,
+    /// Ссылка на скачивание
+    pub download: Option<DownloadMeta>,
 }
 // #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 // #[serde(rename_all = "camelCase")]
@@ -301,6 +885,39 @@ pub enum AttributeType {
     Boolean,
     Text,
     Link,
+    /// Контрагент
+    Counterparty,
+    /// Сотрудник
+    Employee,
+    /// Договор
+    Contract,
+    /// Проект
+    Project,
+    /// Склад
+    Store,
+    /// Товар
+    Product,
+    /// Группа товаров
+    Productfolder,
+    /// Тип, который библиотека не различает. Значение такого поля разбирается как
+    /// [`AttributeValue::Other`]
+    #[serde(untagged)]
+    Other(String),
+}
+impl AttributeType {
+    /// Является ли поле ссылкой на сущность, например `counterparty` или `store`
+    pub fn is_entity(&self) -> bool {
+        matches!(
+            self,
+            AttributeType::Counterparty
+                | AttributeType::Employee
+                | AttributeType::Contract
+                | AttributeType::Project
+                | AttributeType::Store
+                | AttributeType::Product
+                | AttributeType::Productfolder
+        )
+    }
 }
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -310,3 +927,408 @@ pub struct PriceType {
     pub name: String,
     pub external_code: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const ATTRIBUTE_ID: &str = "0b1f6c1e-6c3e-11ee-0a80-0c5e00000011";
+
+    fn attribute_json(attribute_type: &str, value: Value) -> Value {
+        json!({
+            "meta": {
+                "href": format!(
+                    "https://api.moysklad.ru/api/remap/1.2/entity/product/metadata/attributes/{ATTRIBUTE_ID}"
+                ),
+                "type": "attributemetadata",
+                "mediaType": "application/json"
+            },
+            "id": ATTRIBUTE_ID,
+            "name": "Поле",
+            "type": attribute_type,
+            "value": value
+        })
+    }
+
+    /// Разбирает доп. поле, сериализует обратно и проверяет, что значение не изменилось
+    fn round_trip(raw: Value) -> Attribute {
+        let attribute: Attribute = serde_json::from_value(raw.clone()).unwrap();
+        let serialized = serde_json::to_value(&attribute).unwrap();
+        assert_eq!(serialized["value"], raw["value"]);
+        assert_eq!(serialized["type"], raw["type"]);
+        let again: Attribute = serde_json::from_value(serialized).unwrap();
+        assert_eq!(again, attribute);
+        attribute
+    }
+
+    fn field(attribute_type: AttributeType) -> ProductsCustomField {
+        let raw = attribute_json("string", Value::Null);
+        AttributeMetadata {
+            custom_entity_meta: None,
+            meta: serde_json::from_value(raw["meta"].clone()).unwrap(),
+            id: uuid::Uuid::parse_str(ATTRIBUTE_ID).unwrap(),
+            name: "Поле".to_string(),
+            attribute_type,
+            required: false,
+            description: None,
+            entity: PhantomData,
+        }
+    }
+
+    #[test]
+    fn string_round_trip() {
+        let attribute = round_trip(attribute_json("string", json!("Иванов")));
+        assert_eq!(attribute.value, AttributeValue::String("Иванов".into()));
+        assert_eq!(attribute.as_str(), Some("Иванов"));
+    }
+
+    #[test]
+    fn text_and_link_are_distinct() {
+        let text = round_trip(attribute_json("text", json!("длинный текст")));
+        let link = round_trip(attribute_json("link", json!("https://example.com")));
+        assert_eq!(text.value, AttributeValue::Text("длинный текст".into()));
+        assert_eq!(
+            link.value,
+            AttributeValue::Link("https://example.com".into())
+        );
+        assert_eq!(link.as_str(), Some("https://example.com"));
+        assert_ne!(text.attribute_type, link.attribute_type);
+    }
+
+    #[test]
+    fn long_above_i32_round_trip() {
+        let big = 5_000_000_000_i64;
+        let attribute = round_trip(attribute_json("long", json!(big)));
+        assert_eq!(attribute.as_long(), Some(big));
+        assert_eq!(attribute.as_double(), None);
+    }
+
+    #[test]
+    fn double_round_trip() {
+        let attribute = round_trip(attribute_json("double", json!(12.5)));
+        assert_eq!(attribute.as_double(), Some(12.5));
+    }
+
+    #[test]
+    fn boolean_round_trip() {
+        let attribute = round_trip(attribute_json("boolean", json!(true)));
+        assert_eq!(attribute.as_bool(), Some(true));
+    }
+
+    #[test]
+    fn time_round_trip() {
+        let attribute = round_trip(attribute_json("time", json!("2024-05-01 09:30:00.000")));
+        let expected = NaiveDate::from_ymd_opt(2024, 5, 1)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        assert_eq!(attribute.as_time(), Some(expected));
+    }
+
+    #[test]
+    fn customentity_round_trip() {
+        let value = json!({
+            "meta": {
+                "href": "https://api.moysklad.ru/api/remap/1.2/entity/customentity/1/2",
+                "type": "customentity",
+                "mediaType": "application/json"
+            },
+            "name": "Бренд"
+        });
+        let attribute: Attribute =
+            serde_json::from_value(attribute_json("customentity", value)).unwrap();
+        assert_eq!(
+            attribute.as_custom_value().map(|v| v.name.as_str()),
+            Some("Бренд")
+        );
+        let serialized = serde_json::to_value(&attribute).unwrap();
+        assert_eq!(
+            serde_json::from_value::<Attribute>(serialized).unwrap(),
+            attribute
+        );
+    }
+
+    #[test]
+    fn file_keeps_download() {
+        let mut raw = attribute_json("file", json!("certificate.pdf"));
+        raw["download"] = json!({
+            "href": "https://api.moysklad.ru/api/remap/1.2/download/1",
+            "mediaType": "application/octet-stream"
+        });
+        let attribute = round_trip(raw.clone());
+        let file = attribute.as_file().unwrap();
+        assert_eq!(file.filename, "certificate.pdf");
+        assert_eq!(
+            file.download.as_ref().map(|d| d.href.as_str()),
+            Some("https://api.moysklad.ru/api/remap/1.2/download/1")
+        );
+        let serialized = serde_json::to_value(&attribute).unwrap();
+        assert_eq!(serialized["download"], raw["download"]);
+    }
+
+    #[test]
+    fn value_not_matching_type_is_an_error() {
+        assert!(serde_json::from_value::<Attribute>(attribute_json("long", json!("5"))).is_err());
+        assert!(serde_json::from_value::<Attribute>(attribute_json("long", json!(1.5))).is_err());
+        assert!(serde_json::from_value::<Attribute>(attribute_json("boolean", json!(1))).is_err());
+        assert!(
+            serde_json::from_value::<Attribute>(attribute_json("time", json!("вчера"))).is_err()
+        );
+        assert!(serde_json::from_value::<Attribute>(attribute_json(
+            "customentity",
+            json!("Бренд")
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn entity_reference_round_trip() {
+        let value = json!({
+            "meta": {
+                "href": "https://api.moysklad.ru/api/remap/1.2/entity/counterparty/1",
+                "type": "counterparty",
+                "mediaType": "application/json"
+            }
+        });
+        let attribute: Attribute =
+            serde_json::from_value(attribute_json("counterparty", value)).unwrap();
+        let serialized = serde_json::to_value(&attribute).unwrap();
+        assert_eq!(
+            serde_json::from_value::<Attribute>(serialized).unwrap(),
+            attribute
+        );
+        assert_eq!(attribute.attribute_type, AttributeType::Counterparty);
+        assert_eq!(
+            attribute
+                .as_entity()
+                .and_then(|meta| meta.meta_type.as_deref()),
+            Some("counterparty")
+        );
+        for attribute_type in [
+            "employee",
+            "contract",
+            "project",
+            "store",
+            "product",
+            "productfolder",
+        ] {
+            let value =
+                json!({ "meta": { "href": "https://example.com/1", "type": attribute_type } });
+            let attribute: Attribute =
+                serde_json::from_value(attribute_json(attribute_type, value)).unwrap();
+            assert!(attribute.attribute_type.is_entity());
+            assert!(attribute.as_entity().is_some());
+        }
+        assert!(
+            serde_json::from_value::<Attribute>(attribute_json("store", json!("Основной")))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn unknown_type_keeps_raw_value() {
+        let attribute = round_trip(attribute_json("organization", json!({ "name": "ООО" })));
+        assert_eq!(
+            attribute.attribute_type,
+            AttributeType::Other("organization".into())
+        );
+        assert_eq!(
+            attribute.value,
+            AttributeValue::Other(json!({ "name": "ООО" }))
+        );
+    }
+
+    #[test]
+    fn null_value_is_other() {
+        for attribute_type in ["string", "long", "customentity", "counterparty", "file"] {
+            let attribute = round_trip(attribute_json(attribute_type, Value::Null));
+            assert_eq!(attribute.value, AttributeValue::Other(Value::Null));
+        }
+        let mut missing = attribute_json("string", Value::Null);
+        missing.as_object_mut().unwrap().remove("value");
+        let attribute: Attribute = serde_json::from_value(missing).unwrap();
+        assert_eq!(attribute.value, AttributeValue::Other(Value::Null));
+    }
+
+    #[test]
+    fn metadata_with_reference_and_unknown_types() {
+        let fields: Vec<AttributeMetadata<Product>> = serde_json::from_value(json!([
+            {
+                "meta": attribute_json("string", Value::Null)["meta"],
+                "id": ATTRIBUTE_ID,
+                "name": "Поставщик",
+                "type": "counterparty",
+                "required": false
+            },
+            {
+                "meta": attribute_json("string", Value::Null)["meta"],
+                "id": ATTRIBUTE_ID,
+                "name": "Новое поле",
+                "type": "somethingnew",
+                "required": true
+            }
+        ]))
+        .unwrap();
+        assert_eq!(fields[0].attribute_type, AttributeType::Counterparty);
+        assert_eq!(
+            fields[1].attribute_type,
+            AttributeType::Other("somethingnew".into())
+        );
+        assert_eq!(
+            serde_json::to_value(&fields[1].attribute_type).unwrap(),
+            json!("somethingnew")
+        );
+    }
+
+    #[test]
+    fn from_field_checks_entity_reference() {
+        let store = AttributeValue::Entity(MetaWrapper {
+            meta: Meta {
+                href: "https://api.moysklad.ru/api/remap/1.2/entity/store/1".into(),
+                ..Default::default()
+            },
+        });
+        assert!(Attribute::from_field(&field(AttributeType::Store), store.clone()).is_ok());
+        assert!(Attribute::from_field(&field(AttributeType::String), store).is_err());
+        assert!(Attribute::from_field(
+            &field(AttributeType::Long),
+            AttributeValue::Other(Value::Null)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn upload_is_sent_as_file() {
+        let upload = Upload::from_bytes("certificate.pdf", b"%PDF");
+        let attribute =
+            Attribute::from_field(&field(AttributeType::File), AttributeValue::Upload(upload))
+                .unwrap();
+        let serialized = serde_json::to_value(&attribute).unwrap();
+        assert!(serialized.get("value").is_none());
+        assert_eq!(
+            serialized["file"],
+            json!({ "filename": "certificate.pdf", "content": "JVBERg==" })
+        );
+    }
+
+    #[test]
+    fn from_field_accepts_matching_value() {
+        let attribute =
+            Attribute::from_field(&field(AttributeType::Long), AttributeValue::Long(1 << 40))
+                .unwrap();
+        assert_eq!(attribute.as_long(), Some(1 << 40));
+        assert_eq!(attribute.attribute_type, AttributeType::Long);
+        assert_eq!(attribute.id.to_string(), ATTRIBUTE_ID);
+    }
+
+    #[test]
+    fn from_field_rejects_mismatched_value() {
+        let result = Attribute::from_field(
+            &field(AttributeType::Long),
+            AttributeValue::String("5".into()),
+        );
+        assert!(matches!(result, Err(MoySkladError::InvalidArgument(_))));
+        let result = Attribute::from_field(
+            &field(AttributeType::Link),
+            AttributeValue::Text("x".into()),
+        );
+        assert!(matches!(result, Err(MoySkladError::InvalidArgument(_))));
+    }
+
+    fn attribute(id: u128, name: &str, value: AttributeValue) -> Attribute {
+        let attribute_type = match &value {
+            AttributeValue::Long(_) => AttributeType::Long,
+            AttributeValue::Bool(_) => AttributeType::Boolean,
+            _ => AttributeType::String,
+        };
+        Attribute {
+            meta: Meta::default(),
+            id: uuid::Uuid::from_u128(id),
+            name: name.to_string(),
+            attribute_type,
+            value,
+        }
+    }
+
+    fn product() -> Product {
+        Product {
+            attributes: Some(vec![
+                attribute(1, "Вес, г", AttributeValue::Long(5_000_000_000)),
+                attribute(2, "Цвет", AttributeValue::String("серый".into())),
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn lookup_by_id_and_name() {
+        let product = product();
+        assert_eq!(
+            product
+                .attribute(uuid::Uuid::from_u128(2))
+                .map(|a| a.name.as_str()),
+            Some("Цвет")
+        );
+        assert_eq!(
+            product.attribute_by_name("Вес, г").map(|a| a.id),
+            Some(uuid::Uuid::from_u128(1))
+        );
+        assert!(product.attribute(uuid::Uuid::from_u128(3)).is_none());
+        assert!(product.attribute_by_name("Размер").is_none());
+    }
+
+    #[test]
+    fn typed_attribute_value() {
+        let product = product();
+        assert_eq!(
+            product.attribute_value::<i64>("Вес, г"),
+            Some(5_000_000_000)
+        );
+        assert_eq!(
+            product.attribute_value::<String>("Цвет"),
+            Some("серый".to_string())
+        );
+        // тип не совпадает
+        assert_eq!(product.attribute_value::<i64>("Цвет"), None);
+        assert_eq!(product.attribute_value::<bool>("Вес, г"), None);
+        assert_eq!(product.attribute_value::<i64>("Размер"), None);
+    }
+
+    #[test]
+    fn set_attribute_replaces_by_id() {
+        let mut product = product();
+        let previous = product.set_attribute(attribute(1, "Вес, г", AttributeValue::Long(250)));
+        assert_eq!(previous.and_then(|a| a.as_long()), Some(5_000_000_000));
+        assert_eq!(product.attribute_list().len(), 2);
+        assert_eq!(product.attribute_value::<i64>("Вес, г"), Some(250));
+    }
+
+    #[test]
+    fn set_attribute_inserts_new() {
+        let mut product = product();
+        let previous = product.set_attribute(attribute(3, "Хит", AttributeValue::Bool(true)));
+        assert!(previous.is_none());
+        assert_eq!(product.attribute_list().len(), 3);
+        assert_eq!(product.attribute_value::<bool>("Хит"), Some(true));
+    }
+
+    #[test]
+    fn set_attribute_on_entity_without_attributes() {
+        let mut product = Product::default();
+        assert!(product.attribute_list().is_empty());
+        product.set_attribute(attribute(1, "Вес, г", AttributeValue::Long(1)));
+        assert_eq!(product.attributes.as_ref().map(Vec::len), Some(1));
+    }
+
+    #[test]
+    fn remove_attribute_by_id() {
+        let mut product = product();
+        let removed = product.remove_attribute(uuid::Uuid::from_u128(2));
+        assert_eq!(removed.map(|a| a.name), Some("Цвет".to_string()));
+        assert_eq!(product.attribute_list().len(), 1);
+        assert!(product.remove_attribute(uuid::Uuid::from_u128(2)).is_none());
+    }
+}
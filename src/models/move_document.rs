@@ -0,0 +1,627 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{
+        CreatePosition, CreatePositionBuilder, Overhead, Positions, Rate, ThingPositions,
+        TransferPositions, UpdatePositionBuilder,
+    },
+    employee::Employee,
+    ms_ref::MsRef,
+    organization::Organization,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Перемещение
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{Move, MovePosition, MoySkladApiClient, Product};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let template = client.list::<Move>(1, 0, None::<String>).await?.rows.remove(0);
+///     let product = client.get::<Product>(uuid::Uuid::nil()).await?;
+///     let document = Move::create(
+///         &template.organization.meta,
+///         &template.source_store.meta,
+///         &template.target_store.meta,
+///     )
+///     .position(MovePosition::create(&product.meta, 5.0).build())
+///     .build();
+///     let created: Move = client.create(document).await?;
+///     dbg!(created.positions.meta.size);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Move {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Отметка о проведении
+    pub applicable: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Перемещения
+    pub code: Option<String>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Момент последнего удаления Перемещения
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Комментарий Перемещения
+    pub description: Option<String>,
+    /// Внешний код Перемещения
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Перемещения
+    pub id: Uuid,
+    /// Метаданные Перемещения
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Перемещения
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Накладные расходы
+    pub overhead: Option<Overhead>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Метаданные позиций Перемещения
+    pub positions: Positions<MovePosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Метаданные проекта
+    pub project: Option<MetaWrapper>,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Валюта
+    pub rate: Rate,
+    /// Общий доступ
+    pub shared: bool,
+    /// Метаданные склада, с которого совершается перемещение
+    pub source_store: MsRef<Store>,
+    /// Метаданные статуса Перемещения
+    pub state: Option<MsRef<State>>,
+    /// Сумма Перемещения в копейках
+    pub sum: f64,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Метаданные склада, на который совершается перемещение
+    pub target_store: MsRef<Store>,
+    /// Момент последнего обновления Перемещения
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+}
+impl Move {
+    pub fn create(
+        organization_meta: &Meta,
+        source_store_meta: &Meta,
+        target_store_meta: &Meta,
+    ) -> CreateMoveBuilder {
+        CreateMoveBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            source_store: MetaWrapper {
+                meta: source_store_meta.to_owned(),
+            },
+            target_store: MetaWrapper {
+                meta: target_store_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateMoveBuilder {
+        UpdateMoveBuilder::default()
+    }
+}
+impl MsEntity for Move {
+    fn path() -> String {
+        String::from("entity/move")
+    }
+}
+impl_has_attributes!(Move);
+impl HasFiles for Move {}
+impl HasPositions for Move {
+    type Position = MovePosition;
+}
+impl ThingPositions for Move {}
+impl TransferPositions for Move {}
+/// Позиция Перемещения
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MovePosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// ID позиции
+    pub id: Uuid,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Накладные расходы, распределенные на позицию
+    pub overhead: Option<f64>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+    /// Ячейка на складе, с которого совершается перемещение
+    pub source_slot: Option<MetaWrapper>,
+    /// Ячейка на складе, на который совершается перемещение
+    pub target_slot: Option<MetaWrapper>,
+    /// Серийные номера
+    pub things: Option<Vec<String>>,
+}
+impl MovePosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<Move> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<Move> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMove {
+    organization: MetaWrapper,
+    source_store: MetaWrapper,
+    target_store: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overhead: Option<Overhead>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<Move>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct CreateMoveBuilder {
+    organization: MetaWrapper,
+    source_store: MetaWrapper,
+    target_store: MetaWrapper,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    overhead: Option<Overhead>,
+    owner: Option<MetaWrapper>,
+    positions: Option<Vec<CreatePosition<Move>>>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+}
+impl CreateMoveBuilder {
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn overhead(&mut self, overhead: Overhead) -> &mut Self {
+        let _ = self.overhead.insert(overhead);
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<Move>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> CreateMove {
+        CreateMove {
+            organization: self.organization.to_owned(),
+            source_store: self.source_store.to_owned(),
+            target_store: self.target_store.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            overhead: self.overhead.to_owned(),
+            owner: self.owner.to_owned(),
+            positions: self.positions.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMove {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overhead: Option<Overhead>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source_store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_store: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct UpdateMoveBuilder {
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    overhead: Option<Overhead>,
+    owner: Option<MetaWrapper>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    source_store: Option<MetaWrapper>,
+    state: Option<MetaWrapper>,
+    target_store: Option<MetaWrapper>,
+}
+impl UpdateMoveBuilder {
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn overhead(&mut self, overhead: Overhead) -> &mut Self {
+        let _ = self.overhead.insert(overhead);
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn source_store(&mut self, source_store_meta: &Meta) -> &mut Self {
+        let _ = self.source_store.insert(MetaWrapper {
+            meta: source_store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn target_store(&mut self, target_store_meta: &Meta) -> &mut Self {
+        let _ = self.target_store.insert(MetaWrapper {
+            meta: target_store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> UpdateMove {
+        UpdateMove {
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            overhead: self.overhead.to_owned(),
+            owner: self.owner.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            source_store: self.source_store.to_owned(),
+            state: self.state.to_owned(),
+            target_store: self.target_store.to_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const MOVE_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000403";
+    const SOURCE_STORE_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000005";
+    const TARGET_STORE_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000015";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn meta(path: &str) -> Meta {
+        serde_json::from_value(meta_json(path)).unwrap()
+    }
+
+    fn move_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("move/{MOVE_ID}")),
+            "id": MOVE_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "shared": false,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-07 10:00:00.000",
+            "name": "00006",
+            "externalCode": "ext-6",
+            "moment": "2024-05-07 09:00:00.000",
+            "applicable": true,
+            "rate": { "currency": { "meta": meta_json("currency/7a2c9b43-6c3e-11ee-0a80-0c5e00000004") } },
+            "sum": 100000.0,
+            "sourceStore": { "meta": meta_json(&format!("store/{SOURCE_STORE_ID}")) },
+            "targetStore": { "meta": meta_json(&format!("store/{TARGET_STORE_ID}")) },
+            "organization": { "meta": meta_json("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007") },
+            "created": "2024-05-07 09:00:30.000",
+            "printed": false,
+            "published": false,
+            "positions": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/move/{MOVE_ID}/positions"),
+                    "type": "moveposition",
+                    "mediaType": "application/json",
+                    "size": 1
+                },
+                "rows": [{
+                    "meta": meta_json(&format!("move/{MOVE_ID}/positions/7a2c9b43-6c3e-11ee-0a80-0c5e00000430")),
+                    "id": "7a2c9b43-6c3e-11ee-0a80-0c5e00000430",
+                    "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+                    "quantity": 1.0,
+                    "price": 100000.0,
+                    "assortment": { "meta": meta_json("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009") },
+                    "sourceSlot": { "meta": meta_json(&format!("store/{SOURCE_STORE_ID}/slots/7a2c9b43-6c3e-11ee-0a80-0c5e00000011")) },
+                    "targetSlot": { "meta": meta_json(&format!("store/{TARGET_STORE_ID}/slots/7a2c9b43-6c3e-11ee-0a80-0c5e00000021")) }
+                }]
+            }
+        })
+    }
+
+    #[test]
+    fn move_keeps_both_stores_and_slots() {
+        let document: Move = serde_json::from_value(move_json()).unwrap();
+        assert_eq!(document.id.to_string(), MOVE_ID);
+        assert_eq!(
+            document.source_store.id(),
+            Some(SOURCE_STORE_ID.parse().unwrap())
+        );
+        assert_eq!(
+            document.target_store.id(),
+            Some(TARGET_STORE_ID.parse().unwrap())
+        );
+        let position = &document.positions.rows[0];
+        assert!(position.source_slot.is_some());
+        assert!(position.target_slot.is_some());
+        assert!(position.things.is_none());
+    }
+
+    #[test]
+    fn create_move_with_transfer_slots() {
+        let document = Move::create(
+            &meta("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007"),
+            &meta(&format!("store/{SOURCE_STORE_ID}")),
+            &meta(&format!("store/{TARGET_STORE_ID}")),
+        )
+        .position(
+            MovePosition::create(&meta("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009"), 1.0)
+                .source_slot(&meta(&format!(
+                    "store/{SOURCE_STORE_ID}/slots/7a2c9b43-6c3e-11ee-0a80-0c5e00000011"
+                )))
+                .target_slot(&meta(&format!(
+                    "store/{TARGET_STORE_ID}/slots/7a2c9b43-6c3e-11ee-0a80-0c5e00000021"
+                )))
+                .build(),
+        )
+        .build();
+        let value = serde_json::to_value(&document).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            ["organization", "positions", "sourceStore", "targetStore"]
+        );
+        let position = &value["positions"][0];
+        assert!(position["sourceSlot"]["meta"]["href"]
+            .as_str()
+            .unwrap()
+            .contains(SOURCE_STORE_ID));
+        assert!(position["targetSlot"]["meta"]["href"]
+            .as_str()
+            .unwrap()
+            .contains(TARGET_STORE_ID));
+        assert!(position.get("slot").is_none());
+    }
+
+    #[test]
+    fn update_move_serializes_set_fields_only() {
+        let update = Move::update()
+            .target_store(&meta(&format!("store/{TARGET_STORE_ID}")))
+            .build();
+        let value = serde_json::to_value(&update).unwrap();
+        assert_eq!(value.as_object().unwrap().len(), 1);
+        assert_eq!(value["targetStore"]["meta"]["type"], "store");
+    }
+}
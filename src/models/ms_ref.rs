@@ -0,0 +1,216 @@
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Meta, MetaWrapper};
+
+/// Типизированная ссылка на сущность
+///
+/// В ответе API ссылка приходит как `{"meta": {...}}`, а при использовании `expand` -
+/// как полный объект сущности. Раскрытая сущность доступна через [`MsRef::entity`],
+/// загрузить ее отдельно можно методом [`crate::MoySkladApiClient::resolve`].
+/// Частично раскрытый объект, который не разбирается как `T`, остается ссылкой с `meta`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{MoySkladApiClient, Product, Query};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let product = client.get::<Product>(uuid::Uuid::nil()).await?;
+///     if let Some(folder) = &product.product_folder {
+///         dbg!(folder.id(), folder.entity_type());
+///         let folder = client.resolve(folder).await?;
+///         dbg!(folder.name);
+///     }
+///     let product = client
+///         .get_with::<Product>(product.id, Query::new().expand("productFolder"))
+///         .await?;
+///     dbg!(product.product_folder.and_then(|f| f.into_entity()));
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MsRef<T> {
+    pub meta: Meta,
+    entity: Option<Box<T>>,
+}
+impl<T> MsRef<T> {
+    pub fn new(meta: Meta) -> Self {
+        Self { meta, entity: None }
+    }
+    /// ID сущности из `meta.href`
+    pub fn id(&self) -> Option<uuid::Uuid> {
+        self.meta.id()
+    }
+    /// Тип сущности из `meta.type`, например `product`
+    pub fn entity_type(&self) -> Option<&str> {
+        self.meta.meta_type.as_deref()
+    }
+    /// Раскрытая сущность, если в запросе был указан `expand`
+    pub fn entity(&self) -> Option<&T> {
+        self.entity.as_deref()
+    }
+    pub fn into_entity(self) -> Option<T> {
+        self.entity.map(|e| *e)
+    }
+}
+impl<T> Default for MsRef<T> {
+    fn default() -> Self {
+        Self::new(Meta::default())
+    }
+}
+impl<T> From<Meta> for MsRef<T> {
+    fn from(meta: Meta) -> Self {
+        Self::new(meta)
+    }
+}
+impl<T> From<MetaWrapper> for MsRef<T> {
+    fn from(value: MetaWrapper) -> Self {
+        Self::new(value.meta)
+    }
+}
+impl<T> From<MsRef<T>> for MetaWrapper {
+    fn from(value: MsRef<T>) -> Self {
+        MetaWrapper { meta: value.meta }
+    }
+}
+impl<T> Serialize for MsRef<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct Ref<'a> {
+            meta: &'a Meta,
+        }
+        Ref { meta: &self.meta }.serialize(serializer)
+    }
+}
+impl<'de, T> Deserialize<'de> for MsRef<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let expanded = value
+            .as_object()
+            .is_some_and(|object| object.keys().any(|key| key != "meta"));
+        let MetaWrapper { meta } =
+            MetaWrapper::deserialize(&value).map_err(serde::de::Error::custom)?;
+        // частично раскрытый объект (например, с `fields`) оставляем ссылкой
+        let entity = if expanded {
+            T::deserialize(value).ok().map(Box::new)
+        } else {
+            None
+        };
+        Ok(Self { meta, entity })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Deserialize)]
+    struct Folder {
+        meta: Meta,
+        name: String,
+        path_name: String,
+    }
+
+    const ID: &str = "9f4a8a52-6c3e-11ee-0a80-0c5e00123456";
+
+    fn meta() -> serde_json::Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/productfolder/{ID}"),
+            "type": "productfolder",
+            "mediaType": "application/json"
+        })
+    }
+
+    #[test]
+    fn collapsed_ref_has_only_meta() {
+        let reference: MsRef<Folder> = serde_json::from_value(json!({ "meta": meta() })).unwrap();
+        assert_eq!(reference.id(), uuid::Uuid::parse_str(ID).ok());
+        assert_eq!(reference.entity_type(), Some("productfolder"));
+        assert!(reference.entity().is_none());
+    }
+
+    #[test]
+    fn expanded_ref_has_entity() {
+        let reference: MsRef<Folder> = serde_json::from_value(json!({
+            "meta": meta(),
+            "name": "Ковры",
+            "path_name": "Напольные покрытия"
+        }))
+        .unwrap();
+        assert_eq!(reference.entity().map(|f| f.name.as_str()), Some("Ковры"));
+        assert_eq!(
+            reference.into_entity().unwrap().meta.meta_type.as_deref(),
+            Some("productfolder")
+        );
+    }
+
+    #[test]
+    fn partial_ref_falls_back_to_meta() {
+        let reference: MsRef<Folder> = serde_json::from_value(json!({
+            "meta": meta(),
+            "name": "Ковры"
+        }))
+        .unwrap();
+        assert_eq!(reference.id(), uuid::Uuid::parse_str(ID).ok());
+        assert!(reference.entity().is_none());
+    }
+
+    #[test]
+    fn ref_without_meta_is_an_error() {
+        assert!(serde_json::from_value::<MsRef<Folder>>(json!({ "name": "Ковры" })).is_err());
+    }
+
+    #[test]
+    fn ref_serializes_as_meta_only() {
+        let reference: MsRef<Folder> = serde_json::from_value(json!({
+            "meta": meta(),
+            "name": "Ковры",
+            "path_name": ""
+        }))
+        .unwrap();
+        let value = serde_json::to_value(&reference).unwrap();
+        assert_eq!(value.as_object().map(|o| o.len()), Some(1));
+        assert_eq!(value["meta"]["type"], "productfolder");
+    }
+
+    fn meta_with_href(href: String) -> Meta {
+        Meta {
+            href,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn meta_id_ignores_query_and_fragment() {
+        let id = uuid::Uuid::parse_str(ID).ok();
+        let base = "https://api.moysklad.ru/api/remap/1.2/entity/product";
+        assert_eq!(meta_with_href(format!("{base}/{ID}")).id(), id);
+        assert_eq!(meta_with_href(format!("{base}/{ID}/")).id(), id);
+        assert_eq!(meta_with_href(format!("{base}/{ID}?expand=uom")).id(), id);
+        assert_eq!(
+            meta_with_href(format!("https://online.moysklad.ru/app/#good/edit?id={ID}")).id(),
+            None
+        );
+        assert_eq!(meta_with_href(format!("{base}/{ID}#fragment")).id(), id);
+    }
+
+    #[test]
+    fn meta_id_requires_uuid_tail() {
+        let base = "https://api.moysklad.ru/api/remap/1.2/entity/product";
+        assert_eq!(meta_with_href(base.to_string()).id(), None);
+        assert_eq!(meta_with_href(format!("{base}/metadata")).id(), None);
+        assert_eq!(meta_with_href(String::new()).id(), None);
+    }
+}
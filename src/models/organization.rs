@@ -0,0 +1,784 @@
+use std::marker::PhantomData;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{MsEntity, MsNested};
+
+use super::{
+    counterparty::Counterparty,
+    deserialize_date_from_str,
+    employee::Employee,
+    legal::{Address, CompanyType, LegalDetails},
+    ms_ref::MsRef,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Юрлицо
+/// Средствами JSON API можно создавать и обновлять сведения о юрлицах, запрашивать списки юрлиц и сведения по отдельным юрлицам. Счетами юрлица можно управлять как в составе отдельного юрлица, так и отдельно - с помощью специальных ресурсов для управления счетами юрлица. Кодом сущности для юрлица в составе JSON API является ключевое слово organization.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{MoySkladApiClient, Organization, OrganizationAccount};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let organization: Organization = client
+///         .create(
+///             Organization::create("ООО Ромашка")
+///                 .company_type(rust_moysklad::CompanyType::Legal)
+///                 .inn("7736207543")
+///                 .kpp("773601001")
+///                 .build(),
+///         )
+///         .await?;
+///     let account = OrganizationAccount::create("40702810900000012345")
+///         .bank_name("АО Банк")
+///         .bic("044525000")
+///         .correspondent_account("30101810400000000225")
+///         .is_default(true)
+///         .build();
+///     let account: OrganizationAccount = client.create_nested(organization.id, account).await?;
+///     dbg!(account.is_default);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Organization {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные счетов юрлица
+    pub accounts: MetaWrapper,
+    /// Фактический адрес юрлица
+    pub actual_address: Option<String>,
+    /// Фактический адрес юрлица с детализацией по отдельным полям
+    pub actual_address_full: Option<Address>,
+    /// Добавлено ли юрлицо в архив
+    pub archived: bool,
+    /// Массив метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Бонусные баллы по активной бонусной программе
+    pub bonus_points: Option<i32>,
+    /// Метаданные активной бонусной программы
+    pub bonus_program: Option<MetaWrapper>,
+    /// Главный бухгалтер
+    pub chief_accountant: Option<String>,
+    /// Код юрлица
+    pub code: Option<String>,
+    /// Тип юрлица
+    pub company_type: CompanyType,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Комментарий к юрлицу
+    pub description: Option<String>,
+    /// Руководитель
+    pub director: Option<String>,
+    /// Адрес электронной почты
+    pub email: Option<String>,
+    /// Внешний код юрлица
+    pub external_code: String,
+    /// Номер факса
+    pub fax: Option<String>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID юрлица
+    pub id: Uuid,
+    /// Метаданные юрлица
+    pub meta: Meta,
+    /// Наименование юрлица
+    pub name: String,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Является ли данное юрлицо плательщиком НДС
+    pub payer_vat: Option<bool>,
+    /// Номер городского телефона
+    pub phone: Option<String>,
+    /// Общий доступ
+    pub shared: bool,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Момент последнего обновления юрлица
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Реквизиты юрлица
+    #[serde(flatten)]
+    pub legal: LegalDetails,
+}
+impl Organization {
+    pub fn create(name: impl Into<String>) -> CreateOrganizationBuilder {
+        CreateOrganizationBuilder::new(name)
+    }
+    pub fn update() -> UpdateOrganizationBuilder {
+        UpdateOrganizationBuilder::default()
+    }
+}
+impl MsEntity for Organization {
+    fn path() -> String {
+        String::from("entity/organization")
+    }
+}
+impl_has_attributes!(Organization);
+/// Расчетный счет юрлица или контрагента
+/// Тип параметра указывает владельца счета: [`OrganizationAccount`] или [`CounterpartyAccount`]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BankAccount<P> {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Номер счета
+    pub account_number: String,
+    /// Адрес банка
+    pub bank_location: Option<String>,
+    /// Наименование банка
+    pub bank_name: Option<String>,
+    /// БИК
+    pub bic: Option<String>,
+    /// Корр счет
+    pub correspondent_account: Option<String>,
+    /// ID счета
+    pub id: Uuid,
+    /// Является ли счет основным счетом
+    pub is_default: bool,
+    /// Метаданные счета
+    pub meta: Meta,
+    /// Момент последнего обновления
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    #[serde(skip)]
+    owner: PhantomData<P>,
+}
+/// Счет юрлица
+pub type OrganizationAccount = BankAccount<Organization>;
+/// Счет контрагента
+pub type CounterpartyAccount = BankAccount<Counterparty>;
+impl<P> BankAccount<P> {
+    pub fn create(account_number: impl Into<String>) -> CreateBankAccountBuilder {
+        CreateBankAccountBuilder {
+            account_number: account_number.into(),
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateBankAccountBuilder {
+        UpdateBankAccountBuilder::default()
+    }
+}
+impl MsNested for OrganizationAccount {
+    type Parent = Organization;
+    fn nested_path() -> String {
+        String::from("accounts")
+    }
+}
+impl MsNested for CounterpartyAccount {
+    type Parent = Counterparty;
+    fn nested_path() -> String {
+        String::from("accounts")
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateOrganization {
+    #[serde(flatten)]
+    legal: LegalDetails,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual_address_full: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chief_accountant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    company_type: Option<CompanyType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    director: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fax: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payer_vat: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreateOrganizationBuilder {
+    legal: LegalDetails,
+    name: String,
+    actual_address: Option<String>,
+    actual_address_full: Option<Address>,
+    archived: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    chief_accountant: Option<String>,
+    code: Option<String>,
+    company_type: Option<CompanyType>,
+    description: Option<String>,
+    director: Option<String>,
+    email: Option<String>,
+    external_code: Option<String>,
+    fax: Option<String>,
+    group: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    payer_vat: Option<bool>,
+    phone: Option<String>,
+    shared: Option<bool>,
+}
+impl CreateOrganizationBuilder {
+    /// Наименование юрлица
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+    pub fn actual_address(&mut self, actual_address: impl Into<String>) -> &mut Self {
+        let _ = self.actual_address.insert(actual_address.into());
+        self
+    }
+    pub fn actual_address_full(&mut self, actual_address_full: Address) -> &mut Self {
+        let _ = self.actual_address_full.insert(actual_address_full);
+        self
+    }
+    pub fn archived(&mut self, archived: bool) -> &mut Self {
+        let _ = self.archived.insert(archived);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn chief_accountant(&mut self, chief_accountant: impl Into<String>) -> &mut Self {
+        let _ = self.chief_accountant.insert(chief_accountant.into());
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn company_type(&mut self, company_type: CompanyType) -> &mut Self {
+        let _ = self.company_type.insert(company_type);
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn director(&mut self, director: impl Into<String>) -> &mut Self {
+        let _ = self.director.insert(director.into());
+        self
+    }
+    pub fn email(&mut self, email: impl Into<String>) -> &mut Self {
+        let _ = self.email.insert(email.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn fax(&mut self, fax: impl Into<String>) -> &mut Self {
+        let _ = self.fax.insert(fax.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn payer_vat(&mut self, payer_vat: bool) -> &mut Self {
+        let _ = self.payer_vat.insert(payer_vat);
+        self
+    }
+    pub fn phone(&mut self, phone: impl Into<String>) -> &mut Self {
+        let _ = self.phone.insert(phone.into());
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    /// Реквизиты юрлица
+    pub fn legal_details(&mut self, legal: LegalDetails) -> &mut Self {
+        self.legal = legal;
+        self
+    }
+    pub fn inn(&mut self, inn: impl Into<String>) -> &mut Self {
+        let _ = self.legal.inn.insert(inn.into());
+        self
+    }
+    pub fn kpp(&mut self, kpp: impl Into<String>) -> &mut Self {
+        let _ = self.legal.kpp.insert(kpp.into());
+        self
+    }
+    pub fn legal_title(&mut self, legal_title: impl Into<String>) -> &mut Self {
+        let _ = self.legal.legal_title.insert(legal_title.into());
+        self
+    }
+    pub fn legal_address_full(&mut self, legal_address_full: Address) -> &mut Self {
+        let _ = self.legal.legal_address_full.insert(legal_address_full);
+        self
+    }
+    pub fn build(&self) -> CreateOrganization {
+        CreateOrganization {
+            legal: self.legal.to_owned(),
+            name: self.name.to_owned(),
+            actual_address: self.actual_address.to_owned(),
+            actual_address_full: self.actual_address_full.to_owned(),
+            archived: self.archived,
+            attributes: self.attributes.to_owned(),
+            chief_accountant: self.chief_accountant.to_owned(),
+            code: self.code.to_owned(),
+            company_type: self.company_type.to_owned(),
+            description: self.description.to_owned(),
+            director: self.director.to_owned(),
+            email: self.email.to_owned(),
+            external_code: self.external_code.to_owned(),
+            fax: self.fax.to_owned(),
+            group: self.group.to_owned(),
+            owner: self.owner.to_owned(),
+            payer_vat: self.payer_vat,
+            phone: self.phone.to_owned(),
+            shared: self.shared,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOrganization {
+    #[serde(flatten)]
+    legal: LegalDetails,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actual_address_full: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chief_accountant: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    company_type: Option<CompanyType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    director: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fax: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    payer_vat: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateOrganizationBuilder {
+    legal: LegalDetails,
+    actual_address: Option<String>,
+    actual_address_full: Option<Address>,
+    archived: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    chief_accountant: Option<String>,
+    code: Option<String>,
+    company_type: Option<CompanyType>,
+    description: Option<String>,
+    director: Option<String>,
+    email: Option<String>,
+    external_code: Option<String>,
+    fax: Option<String>,
+    group: Option<MetaWrapper>,
+    name: Option<String>,
+    owner: Option<MetaWrapper>,
+    payer_vat: Option<bool>,
+    phone: Option<String>,
+    shared: Option<bool>,
+}
+impl UpdateOrganizationBuilder {
+    pub fn actual_address(&mut self, actual_address: impl Into<String>) -> &mut Self {
+        let _ = self.actual_address.insert(actual_address.into());
+        self
+    }
+    pub fn actual_address_full(&mut self, actual_address_full: Address) -> &mut Self {
+        let _ = self.actual_address_full.insert(actual_address_full);
+        self
+    }
+    pub fn archived(&mut self, archived: bool) -> &mut Self {
+        let _ = self.archived.insert(archived);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn chief_accountant(&mut self, chief_accountant: impl Into<String>) -> &mut Self {
+        let _ = self.chief_accountant.insert(chief_accountant.into());
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn company_type(&mut self, company_type: CompanyType) -> &mut Self {
+        let _ = self.company_type.insert(company_type);
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn director(&mut self, director: impl Into<String>) -> &mut Self {
+        let _ = self.director.insert(director.into());
+        self
+    }
+    pub fn email(&mut self, email: impl Into<String>) -> &mut Self {
+        let _ = self.email.insert(email.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn fax(&mut self, fax: impl Into<String>) -> &mut Self {
+        let _ = self.fax.insert(fax.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn payer_vat(&mut self, payer_vat: bool) -> &mut Self {
+        let _ = self.payer_vat.insert(payer_vat);
+        self
+    }
+    pub fn phone(&mut self, phone: impl Into<String>) -> &mut Self {
+        let _ = self.phone.insert(phone.into());
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    /// Реквизиты юрлица
+    pub fn legal_details(&mut self, legal: LegalDetails) -> &mut Self {
+        self.legal = legal;
+        self
+    }
+    pub fn inn(&mut self, inn: impl Into<String>) -> &mut Self {
+        let _ = self.legal.inn.insert(inn.into());
+        self
+    }
+    pub fn kpp(&mut self, kpp: impl Into<String>) -> &mut Self {
+        let _ = self.legal.kpp.insert(kpp.into());
+        self
+    }
+    pub fn legal_title(&mut self, legal_title: impl Into<String>) -> &mut Self {
+        let _ = self.legal.legal_title.insert(legal_title.into());
+        self
+    }
+    pub fn legal_address_full(&mut self, legal_address_full: Address) -> &mut Self {
+        let _ = self.legal.legal_address_full.insert(legal_address_full);
+        self
+    }
+    pub fn build(&self) -> UpdateOrganization {
+        UpdateOrganization {
+            legal: self.legal.to_owned(),
+            actual_address: self.actual_address.to_owned(),
+            actual_address_full: self.actual_address_full.to_owned(),
+            archived: self.archived,
+            attributes: self.attributes.to_owned(),
+            chief_accountant: self.chief_accountant.to_owned(),
+            code: self.code.to_owned(),
+            company_type: self.company_type.to_owned(),
+            description: self.description.to_owned(),
+            director: self.director.to_owned(),
+            email: self.email.to_owned(),
+            external_code: self.external_code.to_owned(),
+            fax: self.fax.to_owned(),
+            group: self.group.to_owned(),
+            name: self.name.to_owned(),
+            owner: self.owner.to_owned(),
+            payer_vat: self.payer_vat,
+            phone: self.phone.to_owned(),
+            shared: self.shared,
+        }
+    }
+}
+/// Счет для создания
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBankAccount {
+    account_number: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    correspondent_account: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_default: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreateBankAccountBuilder {
+    account_number: String,
+    bank_location: Option<String>,
+    bank_name: Option<String>,
+    bic: Option<String>,
+    correspondent_account: Option<String>,
+    is_default: Option<bool>,
+}
+impl CreateBankAccountBuilder {
+    pub fn bank_location(&mut self, bank_location: impl Into<String>) -> &mut Self {
+        let _ = self.bank_location.insert(bank_location.into());
+        self
+    }
+    pub fn bank_name(&mut self, bank_name: impl Into<String>) -> &mut Self {
+        let _ = self.bank_name.insert(bank_name.into());
+        self
+    }
+    pub fn bic(&mut self, bic: impl Into<String>) -> &mut Self {
+        let _ = self.bic.insert(bic.into());
+        self
+    }
+    pub fn correspondent_account(&mut self, correspondent_account: impl Into<String>) -> &mut Self {
+        let _ = self
+            .correspondent_account
+            .insert(correspondent_account.into());
+        self
+    }
+    pub fn is_default(&mut self, is_default: bool) -> &mut Self {
+        let _ = self.is_default.insert(is_default);
+        self
+    }
+    pub fn build(&self) -> CreateBankAccount {
+        CreateBankAccount {
+            account_number: self.account_number.to_owned(),
+            bank_location: self.bank_location.to_owned(),
+            bank_name: self.bank_name.to_owned(),
+            bic: self.bic.to_owned(),
+            correspondent_account: self.correspondent_account.to_owned(),
+            is_default: self.is_default,
+        }
+    }
+}
+/// Счет для изменения
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBankAccount {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    account_number: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank_location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bank_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bic: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    correspondent_account: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_default: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateBankAccountBuilder {
+    account_number: Option<String>,
+    bank_location: Option<String>,
+    bank_name: Option<String>,
+    bic: Option<String>,
+    correspondent_account: Option<String>,
+    is_default: Option<bool>,
+}
+impl UpdateBankAccountBuilder {
+    pub fn account_number(&mut self, account_number: impl Into<String>) -> &mut Self {
+        let _ = self.account_number.insert(account_number.into());
+        self
+    }
+    pub fn bank_location(&mut self, bank_location: impl Into<String>) -> &mut Self {
+        let _ = self.bank_location.insert(bank_location.into());
+        self
+    }
+    pub fn bank_name(&mut self, bank_name: impl Into<String>) -> &mut Self {
+        let _ = self.bank_name.insert(bank_name.into());
+        self
+    }
+    pub fn bic(&mut self, bic: impl Into<String>) -> &mut Self {
+        let _ = self.bic.insert(bic.into());
+        self
+    }
+    pub fn correspondent_account(&mut self, correspondent_account: impl Into<String>) -> &mut Self {
+        let _ = self
+            .correspondent_account
+            .insert(correspondent_account.into());
+        self
+    }
+    pub fn is_default(&mut self, is_default: bool) -> &mut Self {
+        let _ = self.is_default.insert(is_default);
+        self
+    }
+    pub fn build(&self) -> UpdateBankAccount {
+        UpdateBankAccount {
+            account_number: self.account_number.to_owned(),
+            bank_location: self.bank_location.to_owned(),
+            bank_name: self.bank_name.to_owned(),
+            bic: self.bic.to_owned(),
+            correspondent_account: self.correspondent_account.to_owned(),
+            is_default: self.is_default,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const ORGANIZATION_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000007";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn organization_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("organization/{ORGANIZATION_ID}")),
+            "id": ORGANIZATION_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "shared": true,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-01 12:00:00.000",
+            "created": "2023-01-10 09:00:00.000",
+            "name": "ООО Ромашка",
+            "externalCode": "org-1",
+            "archived": false,
+            "companyType": "legal",
+            "legalTitle": "Общество с ограниченной ответственностью \"Ромашка\"",
+            "legalAddress": "г. Москва, ул. Ленина, д. 1",
+            "inn": "7700000000",
+            "kpp": "770001001",
+            "ogrn": "1027700000000",
+            "accounts": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/organization/{ORGANIZATION_ID}/accounts"),
+                    "type": "account",
+                    "mediaType": "application/json",
+                    "size": 1
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn organization_collects_flattened_legal_details() {
+        let organization: Organization = serde_json::from_value(organization_json()).unwrap();
+        assert_eq!(organization.company_type, CompanyType::Legal);
+        assert_eq!(organization.legal.inn.as_deref(), Some("7700000000"));
+        assert_eq!(organization.legal.kpp.as_deref(), Some("770001001"));
+        assert_eq!(organization.legal.ogrn.as_deref(), Some("1027700000000"));
+        assert_eq!(
+            organization.legal.legal_address.as_deref(),
+            Some("г. Москва, ул. Ленина, д. 1")
+        );
+        assert!(organization.legal.ogrnip.is_none());
+        assert!(organization.legal.certificate_date.is_none());
+    }
+
+    #[test]
+    fn create_organization_flattens_set_legal_details_only() {
+        let organization = Organization::create("ООО Ромашка")
+            .inn("7700000000")
+            .kpp("770001001")
+            .build();
+        assert_eq!(
+            serde_json::to_value(&organization).unwrap(),
+            json!({
+                "name": "ООО Ромашка",
+                "inn": "7700000000",
+                "kpp": "770001001"
+            })
+        );
+    }
+
+    #[test]
+    fn update_organization_flattens_legal_details() {
+        let update = Organization::update()
+            .legal_details(LegalDetails {
+                ogrn: Some("1027700000000".to_string()),
+                ..Default::default()
+            })
+            .build();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "ogrn": "1027700000000" })
+        );
+    }
+
+    #[test]
+    fn bank_account_builders() {
+        let account = OrganizationAccount::create("40702810000000000001")
+            .bic("044525225")
+            .build();
+        assert_eq!(
+            serde_json::to_value(&account).unwrap(),
+            json!({ "accountNumber": "40702810000000000001", "bic": "044525225" })
+        );
+        let update = OrganizationAccount::update().is_default(true).build();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "isDefault": true })
+        );
+    }
+}
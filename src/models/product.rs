@@ -1,5 +1,5 @@
 use crate::{
-    api_client::MsEntity,
+    api_client::{HasFiles, HasImages, MsEntity},
     models::{Meta, MetaWrapper},
     TaxSystem,
 };
@@ -9,14 +9,22 @@ use serde_with::skip_serializing_none;
 
 use super::{
     assortment::{Barcode, BuyPrice, MinPrice, SalePrice},
-    deserialize_option_date_from_str, Attribute,
+    counterparty::Counterparty,
+    country::Country,
+    deserialize_option_date_from_str,
+    employee::Employee,
+    image::Upload,
+    ms_ref::MsRef,
+    product_folder::ProductFolder,
+    uom::Uom,
+    Attribute,
 };
 /// Товары
 /// Средствами JSON API можно создавать и обновлять сведения о Товарах, запрашивать списки Товаров и сведения по отдельным Товарам. Кодом сущности для Товара в составе JSON API является ключевое слово product.
 ///
 /// # Exmaple
 ///
-/// ```rust
+/// ```rust,no_run
 /// use anyhow::Result;
 /// use rust_moysklad::{
 ///     Attribute, AttributeValue, Country, Currency, CustomValue, MoySkladApiClient, Product,
@@ -64,7 +72,7 @@ use super::{
 ///                 let brand = Attribute::from_field(
 ///                     brand_field,
 ///                     AttributeValue::Custom(CustomValue::from(aw.clone())),
-///                 );
+///                 )?;
 ///                 p_to_create.attribute(brand);
 ///             }
 ///         }
@@ -106,7 +114,7 @@ pub struct Product {
     pub barcodes: Option<Vec<Barcode>>,
     pub buy_price: Option<BuyPrice>,
     pub code: Option<String>,
-    pub country: Option<MetaWrapper>,
+    pub country: Option<MsRef<Country>>,
     pub description: Option<String>,
     pub discount_prohibited: Option<bool>,
     pub effective_vat: Option<i64>,
@@ -121,20 +129,20 @@ pub struct Product {
     pub min_price: Option<MinPrice>,
     pub minimum_balance: Option<i32>,
     pub name: Option<String>,
-    pub owner: Option<MetaWrapper>,
+    pub owner: Option<MsRef<Employee>>,
     pub packs: Option<Vec<Pack>>,
     pub partial_disposal: Option<bool>,
     pub path_name: Option<String>,
     pub payment_item_type: Option<String>,
-    pub product_folder: Option<MetaWrapper>,
+    pub product_folder: Option<MsRef<ProductFolder>>,
     pub sale_prices: Vec<SalePrice>,
     pub shared: Option<bool>,
-    pub supplier: Option<MetaWrapper>,
+    pub supplier: Option<MsRef<Counterparty>>,
     pub tax_system: Option<TaxSystem>,
     pub things: Option<Vec<String>>,
     pub tnved: Option<String>,
     pub tracking_type: Option<String>,
-    pub uom: Option<MetaWrapper>,
+    pub uom: Option<MsRef<Uom>>,
     #[serde(deserialize_with = "deserialize_option_date_from_str")]
     pub updated: Option<NaiveDateTime>,
     pub use_parent_vat: Option<bool>,
@@ -159,10 +167,13 @@ impl Product {
     }
 }
 impl MsEntity for Product {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/product")
+    fn path() -> String {
+        String::from("entity/product")
     }
 }
+impl_has_attributes!(Product);
+impl HasFiles for Product {}
+impl HasImages for Product {}
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Pack {
@@ -194,11 +205,11 @@ pub struct CreateProduct {
     #[serde(skip_serializing_if = "Option::is_none")]
     external_code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    files: Option<MetaWrapper>,
+    files: Option<Vec<Upload>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     group: Option<MetaWrapper>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    images: Option<MetaWrapper>,
+    images: Option<Vec<Upload>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     is_serial_trackable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -260,9 +271,9 @@ pub struct CreateProductBuilder {
     description: Option<String>,
     discount_prohibited: Option<bool>,
     external_code: Option<String>,
-    files: Option<MetaWrapper>,
+    files: Option<Vec<Upload>>,
     group: Option<MetaWrapper>,
-    images: Option<MetaWrapper>,
+    images: Option<Vec<Upload>>,
     is_serial_trackable: Option<bool>,
     min_price: Option<MinPrice>,
     minimum_balance: Option<i32>,
@@ -346,16 +357,18 @@ impl CreateProductBuilder {
         let _ = self.external_code.insert(external_code.to_string());
         self
     }
-    pub fn files(&mut self, files_meta: Meta) -> &mut Self {
-        let _ = self.files.insert(MetaWrapper { meta: files_meta });
+    /// Файл для загрузки, не более 100 файлов
+    pub fn file(&mut self, file: Upload) -> &mut Self {
+        self.files.get_or_insert(vec![]).push(file);
         self
     }
     pub fn group(&mut self, group_meta: Meta) -> &mut Self {
         let _ = self.group.insert(MetaWrapper { meta: group_meta });
         self
     }
-    pub fn images(&mut self, images_meta: Meta) -> &mut Self {
-        let _ = self.images.insert(MetaWrapper { meta: images_meta });
+    /// Изображение для загрузки, не более 10 изображений
+    pub fn image(&mut self, image: Upload) -> &mut Self {
+        self.images.get_or_insert(vec![]).push(image);
         self
     }
     pub fn is_serial_trackable(&mut self, is_serial_trackable: bool) -> &mut Self {
@@ -537,11 +550,11 @@ pub struct UpdateProduct {
     #[serde(skip_serializing_if = "Option::is_none")]
     external_code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    files: Option<MetaWrapper>,
+    files: Option<Vec<Upload>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     group: Option<MetaWrapper>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    images: Option<MetaWrapper>,
+    images: Option<Vec<Upload>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     is_serial_trackable: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -598,9 +611,9 @@ pub struct UpdateProductBuilder {
     description: Option<String>,
     discount_prohibited: Option<bool>,
     external_code: Option<String>,
-    files: Option<MetaWrapper>,
+    files: Option<Vec<Upload>>,
     group: Option<MetaWrapper>,
-    images: Option<MetaWrapper>,
+    images: Option<Vec<Upload>>,
     is_serial_trackable: Option<bool>,
     meta: Option<Meta>,
     min_price: Option<MinPrice>,
@@ -636,6 +649,14 @@ impl UpdateProductBuilder {
         self.attributes.get_or_insert(vec![]).push(attribute);
         self
     }
+    /// Весь набор доп. полей, например из [`crate::HasAttributes::attribute_list`]
+    pub fn attributes<I>(&mut self, attributes: I) -> &mut Self
+    where
+        I: IntoIterator<Item = Attribute>,
+    {
+        let _ = self.attributes.insert(attributes.into_iter().collect());
+        self
+    }
     pub fn barcode<T>(&mut self, barcode: T) -> &mut Self
     where
         T: ToString,
@@ -683,16 +704,26 @@ impl UpdateProductBuilder {
         let _ = self.external_code.insert(external_code.to_string());
         self
     }
-    pub fn files(&mut self, files_meta: Meta) -> &mut Self {
-        let _ = self.files.insert(MetaWrapper { meta: files_meta });
+    /// Файл для загрузки, не более 100 файлов
+    ///
+    /// При обновлении переданный список заменяет все файлы Товара: файлы, которых нет в
+    /// списке, будут удалены. Чтобы добавить файлы к уже загруженным, используйте
+    /// [`crate::MoySkladApiClient::add_files`]
+    pub fn file(&mut self, file: Upload) -> &mut Self {
+        self.files.get_or_insert(vec![]).push(file);
         self
     }
     pub fn group(&mut self, group_meta: Meta) -> &mut Self {
         let _ = self.group.insert(MetaWrapper { meta: group_meta });
         self
     }
-    pub fn images(&mut self, images_meta: Meta) -> &mut Self {
-        let _ = self.images.insert(MetaWrapper { meta: images_meta });
+    /// Изображение для загрузки, не более 10 изображений
+    ///
+    /// При обновлении переданный список заменяет все изображения Товара: изображения,
+    /// которых нет в списке, будут удалены. Чтобы добавить изображения к уже загруженным,
+    /// используйте [`crate::MoySkladApiClient::add_images`]
+    pub fn image(&mut self, image: Upload) -> &mut Self {
+        self.images.get_or_insert(vec![]).push(image);
         self
     }
     pub fn is_serial_trackable(&mut self, is_serial_trackable: bool) -> &mut Self {
@@ -4,7 +4,9 @@ use uuid::Uuid;
 
 use crate::api_client::MsEntity;
 
-use super::{deserialize_date_from_str, Meta, MetaWrapper, TaxSystem};
+use super::{
+    deserialize_date_from_str, employee::Employee, ms_ref::MsRef, Meta, MetaWrapper, TaxSystem,
+};
 /// Группы товаров
 /// Средствами JSON API можно создавать и обновлять сведения о Группах товаров, запрашивать списки Групп товаров и сведения по отдельным Группам товаров. Кодом сущности для Группы товаров в составе JSON API является ключевое слово productfolder.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -33,11 +35,11 @@ pub struct ProductFolder {
     /// Наименование Группы товаров
     pub name: String,
     /// Метаданные владельца (Сотрудника)
-    pub owner: MetaWrapper,
+    pub owner: MsRef<Employee>,
     /// Наименование Группы товаров, в которую входит данная Группа товаров
     pub path_name: String,
     /// Ссылка на Группу товаров, в которую входит данная Группа товаров, в формате Метаданных
-    pub product_folder: Option<MetaWrapper>,
+    pub product_folder: Option<MsRef<ProductFolder>>,
     /// Общий доступ
     pub shared: bool,
     /// Код системы налогообложения
@@ -53,8 +55,8 @@ pub struct ProductFolder {
     pub vat_enabled: Option<bool>,
 }
 impl MsEntity for ProductFolder {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/productfolder")
+    fn path() -> String {
+        String::from("entity/productfolder")
     }
 }
 impl ProductFolder {
@@ -63,7 +65,7 @@ impl ProductFolder {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::{MoySkladApiClient, ProductFolder, TaxSystem};
     /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -99,7 +101,7 @@ impl ProductFolder {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,no_run
     /// use anyhow::Result;
     /// use rust_moysklad::{MoySkladApiClient, ProductFolder, TaxSystem};
     /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -113,6 +115,7 @@ impl ProductFolder {
     ///         .with(tracing_subscriber::fmt::layer())
     ///         .init();
     ///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+    ///     # let id = uuid::Uuid::nil();
     ///     let update = ProductFolder::update().external_code("96").build();
     ///     let updated: ProductFolder = client.update(id, update).await?;
     ///     dbg!(&updated);
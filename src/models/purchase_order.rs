@@ -0,0 +1,783 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    counterparty::Counterparty,
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{
+        CreatePosition, CreatePositionBuilder, DiscountPositions, Positions, Rate,
+        UpdatePositionBuilder,
+    },
+    employee::Employee,
+    invoice_in::InvoiceIn,
+    ms_ref::MsRef,
+    organization::Organization,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    supply::Supply,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Заказ поставщику
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{MoySkladApiClient, Product, PurchaseOrder, PurchaseOrderPosition};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let product = client.get::<Product>(uuid::Uuid::nil()).await?;
+///     let supplier = product.supplier.as_ref().expect("product without supplier");
+///     let orders = client.list::<PurchaseOrder>(1, 0, None::<String>).await?;
+///     let organization = &orders.rows.first().expect("no orders").organization;
+///     let mut position = PurchaseOrderPosition::create(&product.meta, 10.0);
+///     if let Some(buy_price) = &product.buy_price {
+///         position.buy_price(buy_price);
+///     }
+///     let order = PurchaseOrder::create(&organization.meta, &supplier.meta)
+///         .position(position.build())
+///         .build();
+///     let created: PurchaseOrder = client.create(order).await?;
+///     dbg!(created.sum);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseOrder {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные контрагента
+    pub agent: MsRef<Counterparty>,
+    /// Метаданные счета контрагента
+    pub agent_account: Option<MetaWrapper>,
+    /// Отметка о проведении
+    pub applicable: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Заказа поставщику
+    pub code: Option<String>,
+    /// Метаданные договора
+    pub contract: Option<MetaWrapper>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Момент последнего удаления Заказа поставщику
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Планируемая дата приемки
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub delivery_planned_moment: Option<NaiveDateTime>,
+    /// Комментарий Заказа поставщику
+    pub description: Option<String>,
+    /// Внешний код Заказа поставщику
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Заказа поставщику
+    pub id: Uuid,
+    /// Сумма счетов поставщику
+    pub invoiced_sum: f64,
+    /// Связанные счета поставщиков
+    pub invoices_in: Option<Vec<MsRef<InvoiceIn>>>,
+    /// Метаданные Заказа поставщику
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Заказа поставщику
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Метаданные счета юрлица
+    pub organization_account: Option<MetaWrapper>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Сумма исходящих платежей по Заказу
+    pub payed_sum: f64,
+    /// Метаданные позиций Заказа поставщику
+    pub positions: Positions<PurchaseOrderPosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Метаданные проекта
+    pub project: Option<MetaWrapper>,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Валюта
+    pub rate: Rate,
+    /// Общий доступ
+    pub shared: bool,
+    /// Сумма принятого
+    pub shipped_sum: f64,
+    /// Метаданные статуса Заказа поставщику
+    pub state: Option<MsRef<State>>,
+    /// Метаданные склада
+    pub store: Option<MsRef<Store>>,
+    /// Сумма Заказа поставщику в копейках
+    pub sum: f64,
+    /// Связанные приемки
+    pub supplies: Option<Vec<MsRef<Supply>>>,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Момент последнего обновления Заказа поставщику
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Учитывается ли НДС
+    pub vat_enabled: bool,
+    /// Включен ли НДС в цену
+    pub vat_included: Option<bool>,
+    /// Сумма НДС
+    pub vat_sum: f64,
+    /// Сумма товаров в пути
+    pub wait_sum: f64,
+}
+impl PurchaseOrder {
+    pub fn create(organization_meta: &Meta, agent_meta: &Meta) -> CreatePurchaseOrderBuilder {
+        CreatePurchaseOrderBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            agent: MetaWrapper {
+                meta: agent_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdatePurchaseOrderBuilder {
+        UpdatePurchaseOrderBuilder::default()
+    }
+}
+impl MsEntity for PurchaseOrder {
+    fn path() -> String {
+        String::from("entity/purchaseorder")
+    }
+}
+impl_has_attributes!(PurchaseOrder);
+impl HasFiles for PurchaseOrder {}
+impl HasPositions for PurchaseOrder {
+    type Position = PurchaseOrderPosition;
+}
+impl DiscountPositions for PurchaseOrder {}
+/// Позиция Заказа поставщику
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseOrderPosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// Процент скидки или наценки
+    pub discount: f64,
+    /// ID позиции
+    pub id: Uuid,
+    /// Количество товара в пути
+    pub in_transit: Option<f64>,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+    /// Принято
+    pub shipped: f64,
+    /// НДС, которым облагается текущая позиция
+    pub vat: i32,
+    /// Включен ли НДС для позиции
+    pub vat_enabled: bool,
+    /// Ожидается ли позиция
+    pub wait: Option<bool>,
+}
+impl PurchaseOrderPosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<PurchaseOrder> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<PurchaseOrder> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePurchaseOrder {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    delivery_planned_moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<PurchaseOrder>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreatePurchaseOrderBuilder {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    delivery_planned_moment: Option<NaiveDateTime>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    positions: Option<Vec<CreatePosition<PurchaseOrder>>>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl CreatePurchaseOrderBuilder {
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn delivery_planned_moment(&mut self, delivery_planned_moment: NaiveDateTime) -> &mut Self {
+        let _ = self.delivery_planned_moment.insert(delivery_planned_moment);
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<PurchaseOrder>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> CreatePurchaseOrder {
+        CreatePurchaseOrder {
+            organization: self.organization.to_owned(),
+            agent: self.agent.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            delivery_planned_moment: self.delivery_planned_moment,
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            positions: self.positions.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePurchaseOrder {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    delivery_planned_moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdatePurchaseOrderBuilder {
+    agent: Option<MetaWrapper>,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    delivery_planned_moment: Option<NaiveDateTime>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl UpdatePurchaseOrderBuilder {
+    pub fn agent(&mut self, agent_meta: &Meta) -> &mut Self {
+        let _ = self.agent.insert(MetaWrapper {
+            meta: agent_meta.to_owned(),
+        });
+        self
+    }
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn delivery_planned_moment(&mut self, delivery_planned_moment: NaiveDateTime) -> &mut Self {
+        let _ = self.delivery_planned_moment.insert(delivery_planned_moment);
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> UpdatePurchaseOrder {
+        UpdatePurchaseOrder {
+            agent: self.agent.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            delivery_planned_moment: self.delivery_planned_moment,
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const ORDER_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000301";
+    const SUPPLY_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000302";
+    const INVOICE_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000303";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn meta(path: &str) -> Meta {
+        serde_json::from_value(meta_json(path)).unwrap()
+    }
+
+    fn purchase_order_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("purchaseorder/{ORDER_ID}")),
+            "id": ORDER_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "shared": false,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-03 10:00:00.000",
+            "name": "00008",
+            "externalCode": "ext-8",
+            "moment": "2024-05-03 09:00:00.000",
+            "applicable": true,
+            "rate": { "currency": { "meta": meta_json("currency/7a2c9b43-6c3e-11ee-0a80-0c5e00000004") } },
+            "sum": 200000.0,
+            "agent": { "meta": meta_json("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006") },
+            "organization": { "meta": meta_json("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007") },
+            "created": "2024-05-03 09:00:30.000",
+            "printed": false,
+            "published": false,
+            "deliveryPlannedMoment": "2024-05-10 12:00:00.000",
+            "positions": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/purchaseorder/{ORDER_ID}/positions"),
+                    "type": "purchaseorderposition",
+                    "mediaType": "application/json",
+                    "size": 1
+                },
+                "rows": [{
+                    "meta": meta_json(&format!("purchaseorder/{ORDER_ID}/positions/7a2c9b43-6c3e-11ee-0a80-0c5e00000310")),
+                    "id": "7a2c9b43-6c3e-11ee-0a80-0c5e00000310",
+                    "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+                    "quantity": 2.0,
+                    "price": 100000.0,
+                    "discount": 0.0,
+                    "vat": 20,
+                    "vatEnabled": true,
+                    "assortment": { "meta": meta_json("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009") },
+                    "shipped": 1.0,
+                    "inTransit": 1.0,
+                    "wait": true
+                }]
+            },
+            "vatEnabled": true,
+            "vatIncluded": true,
+            "vatSum": 33333.33,
+            "payedSum": 0.0,
+            "invoicedSum": 200000.0,
+            "shippedSum": 100000.0,
+            "waitSum": 100000.0,
+            "supplies": [{ "meta": meta_json(&format!("supply/{SUPPLY_ID}")) }],
+            "invoicesIn": [{ "meta": meta_json(&format!("invoicein/{INVOICE_ID}")) }]
+        })
+    }
+
+    #[test]
+    fn purchase_order_keeps_linked_documents() {
+        let order: PurchaseOrder = serde_json::from_value(purchase_order_json()).unwrap();
+        assert_eq!(order.id.to_string(), ORDER_ID);
+        assert_eq!(
+            order
+                .supplies
+                .as_deref()
+                .map(|supplies| supplies.iter().map(|s| s.id()).collect::<Vec<_>>()),
+            Some(vec![Some(SUPPLY_ID.parse().unwrap())])
+        );
+        assert_eq!(
+            order.invoices_in.as_ref().map(|invoices| invoices[0].id()),
+            Some(Some(INVOICE_ID.parse().unwrap()))
+        );
+        assert!(order.store.is_none());
+        assert_eq!(order.wait_sum, 100000.0);
+        assert_eq!(
+            order.delivery_planned_moment,
+            NaiveDate::from_ymd_opt(2024, 5, 10)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+        );
+        let position = &order.positions.rows[0];
+        assert_eq!(position.shipped, 1.0);
+        assert_eq!(position.in_transit, Some(1.0));
+        assert_eq!(position.wait, Some(true));
+    }
+
+    #[test]
+    fn create_purchase_order_skips_unset_fields() {
+        let order = PurchaseOrder::create(
+            &meta("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007"),
+            &meta("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006"),
+        )
+        .delivery_planned_moment(
+            NaiveDate::from_ymd_opt(2024, 5, 10)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+        )
+        .position(
+            PurchaseOrderPosition::create(
+                &meta("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009"),
+                2.0,
+            )
+            .price(100000.0)
+            .build(),
+        )
+        .build();
+        let value = serde_json::to_value(&order).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                "agent",
+                "deliveryPlannedMoment",
+                "organization",
+                "positions"
+            ]
+        );
+        assert_eq!(value["deliveryPlannedMoment"], "2024-05-10 12:00:00.000");
+        assert_eq!(value["positions"][0]["price"], 100000.0);
+    }
+
+    #[test]
+    fn update_purchase_order_serializes_set_fields_only() {
+        let update = PurchaseOrder::update()
+            .store(&meta("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005"))
+            .build();
+        let value = serde_json::to_value(&update).unwrap();
+        assert_eq!(value.as_object().unwrap().len(), 1);
+        assert_eq!(value["store"]["meta"]["type"], "store");
+    }
+}
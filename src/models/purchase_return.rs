@@ -0,0 +1,745 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    counterparty::Counterparty,
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{
+        CreatePosition, CreatePositionBuilder, DiscountPositions, Positions, Rate, SlotPositions,
+        ThingPositions, UpdatePositionBuilder,
+    },
+    employee::Employee,
+    ms_ref::MsRef,
+    organization::Organization,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    supply::Supply,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Возврат поставщику
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{MoySkladApiClient, PurchaseReturn, Supply, Template};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let supply = client.get::<Supply>(uuid::Uuid::nil()).await?;
+///     let purchase_return: PurchaseReturn = client
+///         .create_from_template(&Template::supply(&supply.meta))
+///         .await?;
+///     dbg!(purchase_return.supply.and_then(|s| s.id()));
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseReturn {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные контрагента
+    pub agent: MsRef<Counterparty>,
+    /// Метаданные счета контрагента
+    pub agent_account: Option<MetaWrapper>,
+    /// Отметка о проведении
+    pub applicable: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Возврата поставщику
+    pub code: Option<String>,
+    /// Метаданные договора
+    pub contract: Option<MetaWrapper>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Момент последнего удаления Возврата поставщику
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Комментарий Возврата поставщику
+    pub description: Option<String>,
+    /// Внешний код Возврата поставщику
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Возврата поставщику
+    pub id: Uuid,
+    /// Метаданные Возврата поставщику
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Возврата поставщику
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Метаданные счета юрлица
+    pub organization_account: Option<MetaWrapper>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Сумма входящих платежей по Возврату поставщику
+    pub payed_sum: f64,
+    /// Метаданные позиций Возврата поставщику
+    pub positions: Positions<PurchaseReturnPosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Метаданные проекта
+    pub project: Option<MetaWrapper>,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Валюта
+    pub rate: Rate,
+    /// Общий доступ
+    pub shared: bool,
+    /// Метаданные статуса Возврата поставщику
+    pub state: Option<MsRef<State>>,
+    /// Метаданные склада
+    pub store: MsRef<Store>,
+    /// Сумма Возврата поставщику в копейках
+    pub sum: f64,
+    /// Ссылка на Приемку, по которой произошел возврат
+    pub supply: Option<MsRef<Supply>>,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Момент последнего обновления Возврата поставщику
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Учитывается ли НДС
+    pub vat_enabled: bool,
+    /// Включен ли НДС в цену
+    pub vat_included: Option<bool>,
+    /// Сумма НДС
+    pub vat_sum: f64,
+}
+impl PurchaseReturn {
+    pub fn create(
+        organization_meta: &Meta,
+        agent_meta: &Meta,
+        store_meta: &Meta,
+    ) -> CreatePurchaseReturnBuilder {
+        CreatePurchaseReturnBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            agent: MetaWrapper {
+                meta: agent_meta.to_owned(),
+            },
+            store: MetaWrapper {
+                meta: store_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdatePurchaseReturnBuilder {
+        UpdatePurchaseReturnBuilder::default()
+    }
+}
+impl MsEntity for PurchaseReturn {
+    fn path() -> String {
+        String::from("entity/purchasereturn")
+    }
+}
+impl_has_attributes!(PurchaseReturn);
+impl HasFiles for PurchaseReturn {}
+impl HasPositions for PurchaseReturn {
+    type Position = PurchaseReturnPosition;
+}
+impl DiscountPositions for PurchaseReturn {}
+impl SlotPositions for PurchaseReturn {}
+impl ThingPositions for PurchaseReturn {}
+/// Позиция Возврата поставщику
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurchaseReturnPosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// Процент скидки или наценки
+    pub discount: f64,
+    /// ID позиции
+    pub id: Uuid,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+    /// Ячейка на складе
+    pub slot: Option<MetaWrapper>,
+    /// Серийные номера
+    pub things: Option<Vec<String>>,
+    /// НДС, которым облагается текущая позиция
+    pub vat: i32,
+    /// Включен ли НДС для позиции
+    pub vat_enabled: bool,
+}
+impl PurchaseReturnPosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<PurchaseReturn> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<PurchaseReturn> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePurchaseReturn {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    store: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<PurchaseReturn>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supply: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreatePurchaseReturnBuilder {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    store: MetaWrapper,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    positions: Option<Vec<CreatePosition<PurchaseReturn>>>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    supply: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl CreatePurchaseReturnBuilder {
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<PurchaseReturn>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn supply(&mut self, supply_meta: &Meta) -> &mut Self {
+        let _ = self.supply.insert(MetaWrapper {
+            meta: supply_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> CreatePurchaseReturn {
+        CreatePurchaseReturn {
+            organization: self.organization.to_owned(),
+            agent: self.agent.to_owned(),
+            store: self.store.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            positions: self.positions.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            supply: self.supply.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePurchaseReturn {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    supply: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdatePurchaseReturnBuilder {
+    agent: Option<MetaWrapper>,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    organization_account: Option<MetaWrapper>,
+    owner: Option<MetaWrapper>,
+    project: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+    supply: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl UpdatePurchaseReturnBuilder {
+    pub fn agent(&mut self, agent_meta: &Meta) -> &mut Self {
+        let _ = self.agent.insert(MetaWrapper {
+            meta: agent_meta.to_owned(),
+        });
+        self
+    }
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn supply(&mut self, supply_meta: &Meta) -> &mut Self {
+        let _ = self.supply.insert(MetaWrapper {
+            meta: supply_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> UpdatePurchaseReturn {
+        UpdatePurchaseReturn {
+            agent: self.agent.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            owner: self.owner.to_owned(),
+            project: self.project.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+            supply: self.supply.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    const RETURN_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000304";
+    const SUPPLY_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000302";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn meta(path: &str) -> Meta {
+        serde_json::from_value(meta_json(path)).unwrap()
+    }
+
+    fn purchase_return_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("purchasereturn/{RETURN_ID}")),
+            "id": RETURN_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "shared": false,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-05 10:00:00.000",
+            "name": "00002",
+            "externalCode": "ext-2",
+            "moment": "2024-05-05 09:00:00.000",
+            "applicable": true,
+            "rate": { "currency": { "meta": meta_json("currency/7a2c9b43-6c3e-11ee-0a80-0c5e00000004") } },
+            "sum": 100000.0,
+            "store": { "meta": meta_json("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005") },
+            "agent": { "meta": meta_json("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006") },
+            "organization": { "meta": meta_json("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007") },
+            "created": "2024-05-05 09:00:30.000",
+            "printed": false,
+            "published": false,
+            "supply": { "meta": meta_json(&format!("supply/{SUPPLY_ID}")) },
+            "positions": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/purchasereturn/{RETURN_ID}/positions"),
+                    "type": "purchasereturnposition",
+                    "mediaType": "application/json",
+                    "size": 1
+                },
+                "rows": [{
+                    "meta": meta_json(&format!("purchasereturn/{RETURN_ID}/positions/7a2c9b43-6c3e-11ee-0a80-0c5e00000340")),
+                    "id": "7a2c9b43-6c3e-11ee-0a80-0c5e00000340",
+                    "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+                    "quantity": 1.0,
+                    "price": 100000.0,
+                    "discount": 0.0,
+                    "vat": 20,
+                    "vatEnabled": true,
+                    "assortment": { "meta": meta_json("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009") },
+                    "things": ["SN-101"]
+                }]
+            },
+            "vatEnabled": true,
+            "vatIncluded": true,
+            "vatSum": 16666.67,
+            "payedSum": 0.0
+        })
+    }
+
+    #[test]
+    fn purchase_return_keeps_supply_link() {
+        let purchase_return: PurchaseReturn =
+            serde_json::from_value(purchase_return_json()).unwrap();
+        assert_eq!(purchase_return.id.to_string(), RETURN_ID);
+        assert_eq!(
+            purchase_return
+                .supply
+                .as_ref()
+                .and_then(|supply| supply.id()),
+            Some(SUPPLY_ID.parse().unwrap())
+        );
+        assert_eq!(purchase_return.store.entity_type(), Some("store"));
+        let position = &purchase_return.positions.rows[0];
+        assert_eq!(
+            position.things.as_deref(),
+            Some(["SN-101".to_string()].as_slice())
+        );
+        assert!(position.slot.is_none());
+    }
+
+    #[test]
+    fn create_purchase_return_links_supply() {
+        let purchase_return = PurchaseReturn::create(
+            &meta("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007"),
+            &meta("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006"),
+            &meta("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005"),
+        )
+        .supply(&meta(&format!("supply/{SUPPLY_ID}")))
+        .applicable(false)
+        .position(
+            PurchaseReturnPosition::create(
+                &meta("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009"),
+                1.0,
+            )
+            .thing("SN-101")
+            .build(),
+        )
+        .build();
+        let value = serde_json::to_value(&purchase_return).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                "agent",
+                "applicable",
+                "organization",
+                "positions",
+                "store",
+                "supply"
+            ]
+        );
+        assert_eq!(value["supply"]["meta"]["type"], "supply");
+        assert_eq!(value["applicable"], false);
+        assert_eq!(value["positions"][0]["things"], json!(["SN-101"]));
+    }
+
+    #[test]
+    fn update_purchase_return_serializes_set_fields_only() {
+        let update = PurchaseReturn::update()
+            .supply(&meta(&format!("supply/{SUPPLY_ID}")))
+            .build();
+        let value = serde_json::to_value(&update).unwrap();
+        assert_eq!(value.as_object().unwrap().len(), 1);
+        assert_eq!(value["supply"]["meta"]["type"], "supply");
+    }
+}
@@ -19,7 +19,7 @@ pub struct Region {
     pub version: Option<i32>,
 }
 impl MsEntity for Region {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/region")
+    fn path() -> String {
+        String::from("entity/region")
     }
 }
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+use super::Meta;
+
+/// Статус сущности (контрагента, документа), настраивается в метаданных сущности
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct State {
+    pub account_id: Option<uuid::Uuid>,
+    /// Цвет статуса
+    pub color: Option<i64>,
+    /// Тип сущности, к которой относится статус
+    pub entity_type: Option<String>,
+    pub id: uuid::Uuid,
+    pub meta: Meta,
+    pub name: String,
+    /// Тип статуса
+    pub state_type: Option<StateType>,
+}
+/// Тип статуса
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StateType {
+    /// Обычный
+    #[default]
+    Regular,
+    /// Финальный положительный
+    Successful,
+    /// Финальный отрицательный
+    Unsuccessful,
+}
@@ -0,0 +1,573 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{MsEntity, MsNested};
+
+use super::{
+    deserialize_date_from_str, employee::Employee, legal::Address, ms_ref::MsRef, Attribute, Meta,
+    MetaWrapper,
+};
+
+/// Склады
+/// Средствами JSON API можно создавать и обновлять сведения о Складах, запрашивать списки Складов и сведения по отдельным Складам. Кодом сущности для Склада в составе JSON API является ключевое слово store.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Store {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Адрес склада
+    pub address: Option<String>,
+    /// Адрес с детализацией по отдельным полям
+    pub address_full: Option<Address>,
+    /// Добавлен ли Склад в архив
+    pub archived: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Склада
+    pub code: Option<String>,
+    /// Комментарий к Складу
+    pub description: Option<String>,
+    /// Внешний код Склада
+    pub external_code: String,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Склада
+    pub id: Uuid,
+    /// Метаданные Склада
+    pub meta: Meta,
+    /// Наименование Склада
+    pub name: String,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Метаданные родительского склада (Группы)
+    pub parent: Option<MsRef<Store>>,
+    /// Группа Склада
+    pub path_name: String,
+    /// Общий доступ
+    pub shared: bool,
+    /// Ячейки склада
+    pub slots: Option<MetaWrapper>,
+    /// Момент последнего обновления Склада
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Зоны склада
+    pub zones: Option<MetaWrapper>,
+}
+impl MsEntity for Store {
+    fn path() -> String {
+        String::from("entity/store")
+    }
+}
+impl_has_attributes!(Store);
+impl Store {
+    /// Создать Склад
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use rust_moysklad::{MoySkladApiClient, Store, StoreSlot, StoreZone};
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+    ///     let store = Store::create("Основной склад")
+    ///         .address("Москва, ул. Складская, 1")
+    ///         .code("MAIN")
+    ///         .build();
+    ///     let store: Store = client.create(store).await?;
+    ///     let zone: StoreZone = client
+    ///         .create_nested(store.id, StoreZone::create("Стеллажи").build())
+    ///         .await?;
+    ///     let slot = StoreSlot::create("A-01-01")
+    ///         .barcode("2000000000015")
+    ///         .zone(&zone.meta)
+    ///         .build();
+    ///     let slot: StoreSlot = client.create_nested(store.id, slot).await?;
+    ///     let slots = client.get_all_nested::<StoreSlot>(store.id).await?;
+    ///     dbg!(slots.len());
+    ///     client.delete_nested::<StoreSlot>(store.id, slot.id).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn create(name: impl Into<String>) -> CreateStoreBuilder {
+        CreateStoreBuilder::new(name)
+    }
+    /// Изменить Склад
+    pub fn update() -> UpdateStoreBuilder {
+        UpdateStoreBuilder::default()
+    }
+}
+/// Зона склада
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreZone {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Внешний код Зоны
+    pub external_code: String,
+    /// ID Зоны
+    pub id: Uuid,
+    /// Метаданные Зоны
+    pub meta: Meta,
+    /// Наименование Зоны
+    pub name: String,
+    /// Момент последнего обновления Зоны
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+}
+impl MsNested for StoreZone {
+    type Parent = Store;
+    fn nested_path() -> String {
+        String::from("zones")
+    }
+}
+impl StoreZone {
+    pub fn create(name: impl Into<String>) -> CreateStoreZoneBuilder {
+        CreateStoreZoneBuilder {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateStoreZoneBuilder {
+        UpdateStoreZoneBuilder::default()
+    }
+}
+/// Ячейка склада
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreSlot {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Штрихкод ячейки
+    pub barcode: Option<String>,
+    /// Внешний код Ячейки
+    pub external_code: String,
+    /// ID Ячейки
+    pub id: Uuid,
+    /// Метаданные Ячейки
+    pub meta: Meta,
+    /// Наименование Ячейки
+    pub name: String,
+    /// Момент последнего обновления Ячейки
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Зона ячейки
+    pub zone: Option<MsRef<StoreZone>>,
+}
+impl MsNested for StoreSlot {
+    type Parent = Store;
+    fn nested_path() -> String {
+        String::from("slots")
+    }
+}
+impl StoreSlot {
+    pub fn create(name: impl Into<String>) -> CreateStoreSlotBuilder {
+        CreateStoreSlotBuilder {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateStoreSlotBuilder {
+        UpdateStoreSlotBuilder::default()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStore {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address_full: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreateStoreBuilder {
+    address: Option<String>,
+    address_full: Option<Address>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    name: String,
+    owner: Option<MetaWrapper>,
+    parent: Option<MetaWrapper>,
+    shared: Option<bool>,
+}
+impl CreateStoreBuilder {
+    /// Наименование Склада
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+    /// Адрес склада
+    pub fn address(&mut self, address: impl Into<String>) -> &mut Self {
+        let _ = self.address.insert(address.into());
+        self
+    }
+    /// Адрес с детализацией по отдельным полям
+    pub fn address_full(&mut self, address_full: Address) -> &mut Self {
+        let _ = self.address_full.insert(address_full);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    /// Код Склада
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    /// Комментарий к Складу
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    /// Внешний код Склада
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    /// Отдел сотрудника
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    /// Владелец (Сотрудник)
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    /// Родительский склад (Группа)
+    pub fn parent(&mut self, parent_meta: &Meta) -> &mut Self {
+        let _ = self.parent.insert(MetaWrapper {
+            meta: parent_meta.to_owned(),
+        });
+        self
+    }
+    /// Общий доступ
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn build(&self) -> CreateStore {
+        CreateStore {
+            address: self.address.to_owned(),
+            address_full: self.address_full.to_owned(),
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            name: self.name.to_owned(),
+            owner: self.owner.to_owned(),
+            parent: self.parent.to_owned(),
+            shared: self.shared,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateStore {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address_full: Option<Address>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateStoreBuilder {
+    address: Option<String>,
+    address_full: Option<Address>,
+    archived: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    name: Option<String>,
+    owner: Option<MetaWrapper>,
+    parent: Option<MetaWrapper>,
+    shared: Option<bool>,
+}
+impl UpdateStoreBuilder {
+    /// Адрес склада
+    pub fn address(&mut self, address: impl Into<String>) -> &mut Self {
+        let _ = self.address.insert(address.into());
+        self
+    }
+    /// Адрес с детализацией по отдельным полям
+    pub fn address_full(&mut self, address_full: Address) -> &mut Self {
+        let _ = self.address_full.insert(address_full);
+        self
+    }
+    /// Добавлен ли Склад в архив
+    pub fn archived(&mut self, archived: bool) -> &mut Self {
+        let _ = self.archived.insert(archived);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    /// Код Склада
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    /// Комментарий к Складу
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    /// Внешний код Склада
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    /// Отдел сотрудника
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    /// Наименование Склада
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    /// Владелец (Сотрудник)
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    /// Родительский склад (Группа)
+    pub fn parent(&mut self, parent_meta: &Meta) -> &mut Self {
+        let _ = self.parent.insert(MetaWrapper {
+            meta: parent_meta.to_owned(),
+        });
+        self
+    }
+    /// Общий доступ
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn build(&self) -> UpdateStore {
+        UpdateStore {
+            address: self.address.to_owned(),
+            address_full: self.address_full.to_owned(),
+            archived: self.archived,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            name: self.name.to_owned(),
+            owner: self.owner.to_owned(),
+            parent: self.parent.to_owned(),
+            shared: self.shared,
+        }
+    }
+}
+/// Зона склада для создания
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStoreZone {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    name: String,
+}
+#[derive(Default)]
+pub struct CreateStoreZoneBuilder {
+    external_code: Option<String>,
+    name: String,
+}
+impl CreateStoreZoneBuilder {
+    /// Внешний код Зоны
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn build(&self) -> CreateStoreZone {
+        CreateStoreZone {
+            external_code: self.external_code.to_owned(),
+            name: self.name.to_owned(),
+        }
+    }
+}
+/// Зона склада для изменения
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateStoreZone {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+#[derive(Default)]
+pub struct UpdateStoreZoneBuilder {
+    external_code: Option<String>,
+    name: Option<String>,
+}
+impl UpdateStoreZoneBuilder {
+    /// Внешний код Зоны
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    /// Наименование Зоны
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn build(&self) -> UpdateStoreZone {
+        UpdateStoreZone {
+            external_code: self.external_code.to_owned(),
+            name: self.name.to_owned(),
+        }
+    }
+}
+/// Ячейка склада для создания
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateStoreSlot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    barcode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zone: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct CreateStoreSlotBuilder {
+    barcode: Option<String>,
+    external_code: Option<String>,
+    name: String,
+    zone: Option<MetaWrapper>,
+}
+impl CreateStoreSlotBuilder {
+    /// Штрихкод ячейки
+    pub fn barcode(&mut self, barcode: impl Into<String>) -> &mut Self {
+        let _ = self.barcode.insert(barcode.into());
+        self
+    }
+    /// Внешний код Ячейки
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    /// Зона ячейки
+    pub fn zone(&mut self, zone_meta: &Meta) -> &mut Self {
+        let _ = self.zone.insert(MetaWrapper {
+            meta: zone_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> CreateStoreSlot {
+        CreateStoreSlot {
+            barcode: self.barcode.to_owned(),
+            external_code: self.external_code.to_owned(),
+            name: self.name.to_owned(),
+            zone: self.zone.to_owned(),
+        }
+    }
+}
+/// Ячейка склада для изменения
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateStoreSlot {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    barcode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    zone: Option<MetaWrapper>,
+}
+#[derive(Default)]
+pub struct UpdateStoreSlotBuilder {
+    barcode: Option<String>,
+    external_code: Option<String>,
+    name: Option<String>,
+    zone: Option<MetaWrapper>,
+}
+impl UpdateStoreSlotBuilder {
+    /// Штрихкод ячейки
+    pub fn barcode(&mut self, barcode: impl Into<String>) -> &mut Self {
+        let _ = self.barcode.insert(barcode.into());
+        self
+    }
+    /// Внешний код Ячейки
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    /// Наименование Ячейки
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    /// Зона ячейки
+    pub fn zone(&mut self, zone_meta: &Meta) -> &mut Self {
+        let _ = self.zone.insert(MetaWrapper {
+            meta: zone_meta.to_owned(),
+        });
+        self
+    }
+    pub fn build(&self) -> UpdateStoreSlot {
+        UpdateStoreSlot {
+            barcode: self.barcode.to_owned(),
+            external_code: self.external_code.to_owned(),
+            name: self.name.to_owned(),
+            zone: self.zone.to_owned(),
+        }
+    }
+}
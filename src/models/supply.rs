@@ -0,0 +1,861 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::api_client::{HasFiles, HasPositions, MsEntity};
+
+use super::{
+    assortment::Assortment,
+    counterparty::Counterparty,
+    deserialize_date_from_str, deserialize_option_date_from_str,
+    document::{
+        CreatePosition, CreatePositionBuilder, DiscountPositions, Overhead, Positions, Rate,
+        SlotPositions, ThingPositions, UpdatePositionBuilder,
+    },
+    employee::Employee,
+    invoice_in::InvoiceIn,
+    ms_ref::MsRef,
+    organization::Organization,
+    purchase_order::PurchaseOrder,
+    purchase_return::PurchaseReturn,
+    serialize_option_date_to_str,
+    state::State,
+    store::Store,
+    Attribute, Meta, MetaWrapper,
+};
+
+/// Приемка
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{
+///     MoySkladApiClient, Overhead, OverheadDistribution, PurchaseOrder, Supply, Template,
+/// };
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let order = client.get::<PurchaseOrder>(uuid::Uuid::nil()).await?;
+///     // приемка на основе заказа поставщику
+///     let supply: Supply = client
+///         .create_from_template(&Template::purchase_order(&order.meta))
+///         .await?;
+///     // накладные расходы распределяются по позициям пропорционально весу
+///     let update = Supply::update()
+///         .incoming_number("ВХ-42")
+///         .overhead(Overhead {
+///             sum: 150000.0,
+///             distribution: OverheadDistribution::Weight,
+///         })
+///         .build();
+///     let supply: Supply = client.update(supply.id, update).await?;
+///     for position in client.get_positions::<Supply>(supply.id).await? {
+///         dbg!(position.overhead);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Supply {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные контрагента
+    pub agent: MsRef<Counterparty>,
+    /// Метаданные счета контрагента
+    pub agent_account: Option<MetaWrapper>,
+    /// Отметка о проведении
+    pub applicable: bool,
+    /// Коллекция метаданных доп. полей
+    pub attributes: Option<Vec<Attribute>>,
+    /// Код Приемки
+    pub code: Option<String>,
+    /// Метаданные договора
+    pub contract: Option<MetaWrapper>,
+    /// Дата создания
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub created: NaiveDateTime,
+    /// Момент последнего удаления Приемки
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub deleted: Option<NaiveDateTime>,
+    /// Комментарий Приемки
+    pub description: Option<String>,
+    /// Внешний код Приемки
+    pub external_code: String,
+    /// Метаданные массива Файлов
+    pub files: Option<MetaWrapper>,
+    /// Отдел сотрудника
+    pub group: MetaWrapper,
+    /// ID Приемки
+    pub id: Uuid,
+    /// Входящая дата
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub incoming_date: Option<NaiveDateTime>,
+    /// Входящий номер
+    pub incoming_number: Option<String>,
+    /// Связанные счета поставщиков
+    pub invoices_in: Option<Vec<MsRef<InvoiceIn>>>,
+    /// Метаданные Приемки
+    pub meta: Meta,
+    /// Дата документа
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub moment: NaiveDateTime,
+    /// Наименование Приемки
+    pub name: String,
+    /// Метаданные юрлица
+    pub organization: MsRef<Organization>,
+    /// Метаданные счета юрлица
+    pub organization_account: Option<MetaWrapper>,
+    /// Накладные расходы
+    pub overhead: Option<Overhead>,
+    /// Владелец (Сотрудник)
+    pub owner: Option<MsRef<Employee>>,
+    /// Сумма исходящих платежей по Приемке
+    pub payed_sum: f64,
+    /// Метаданные позиций Приемки
+    pub positions: Positions<SupplyPosition>,
+    /// Напечатан ли документ
+    pub printed: bool,
+    /// Метаданные проекта
+    pub project: Option<MetaWrapper>,
+    /// Опубликован ли документ
+    pub published: bool,
+    /// Ссылка на связанный заказ поставщику
+    pub purchase_order: Option<MsRef<PurchaseOrder>>,
+    /// Валюта
+    pub rate: Rate,
+    /// Связанные возвраты поставщикам
+    pub returns: Option<Vec<MsRef<PurchaseReturn>>>,
+    /// Общий доступ
+    pub shared: bool,
+    /// Метаданные статуса Приемки
+    pub state: Option<MsRef<State>>,
+    /// Метаданные склада
+    pub store: MsRef<Store>,
+    /// Сумма Приемки в копейках
+    pub sum: f64,
+    /// ID синхронизации
+    pub sync_id: Option<Uuid>,
+    /// Момент последнего обновления Приемки
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub updated: NaiveDateTime,
+    /// Учитывается ли НДС
+    pub vat_enabled: bool,
+    /// Включен ли НДС в цену
+    pub vat_included: Option<bool>,
+    /// Сумма НДС
+    pub vat_sum: f64,
+}
+impl Supply {
+    pub fn create(
+        organization_meta: &Meta,
+        agent_meta: &Meta,
+        store_meta: &Meta,
+    ) -> CreateSupplyBuilder {
+        CreateSupplyBuilder {
+            organization: MetaWrapper {
+                meta: organization_meta.to_owned(),
+            },
+            agent: MetaWrapper {
+                meta: agent_meta.to_owned(),
+            },
+            store: MetaWrapper {
+                meta: store_meta.to_owned(),
+            },
+            ..Default::default()
+        }
+    }
+    pub fn update() -> UpdateSupplyBuilder {
+        UpdateSupplyBuilder::default()
+    }
+}
+impl MsEntity for Supply {
+    fn path() -> String {
+        String::from("entity/supply")
+    }
+}
+impl_has_attributes!(Supply);
+impl HasFiles for Supply {}
+impl HasPositions for Supply {
+    type Position = SupplyPosition;
+}
+impl DiscountPositions for Supply {}
+impl SlotPositions for Supply {}
+impl ThingPositions for Supply {}
+/// Позиция Приемки
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupplyPosition {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные товара/услуги/серии/модификации/комплекта
+    pub assortment: MsRef<Assortment>,
+    /// Метаданные страны
+    pub country: Option<MetaWrapper>,
+    /// Процент скидки или наценки
+    pub discount: f64,
+    /// ID позиции
+    pub id: Uuid,
+    /// Метаданные позиции
+    pub meta: Option<Meta>,
+    /// Накладные расходы, распределенные на позицию
+    pub overhead: Option<f64>,
+    /// Цена товара/услуги в копейках
+    pub price: f64,
+    /// Количество товаров/услуг данного вида в позиции
+    pub quantity: f64,
+    /// Ячейка на складе
+    pub slot: Option<MetaWrapper>,
+    /// Серийные номера
+    pub things: Option<Vec<String>>,
+    /// НДС, которым облагается текущая позиция
+    pub vat: i32,
+    /// Включен ли НДС для позиции
+    pub vat_enabled: bool,
+}
+impl SupplyPosition {
+    pub fn create(assortment_meta: &Meta, quantity: f64) -> CreatePositionBuilder<Supply> {
+        CreatePositionBuilder::new(assortment_meta, quantity)
+    }
+    pub fn update() -> UpdatePositionBuilder<Supply> {
+        UpdatePositionBuilder::new()
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSupply {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    store: MetaWrapper,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    incoming_date: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    incoming_number: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overhead: Option<Overhead>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    positions: Option<Vec<CreatePosition<Supply>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct CreateSupplyBuilder {
+    organization: MetaWrapper,
+    agent: MetaWrapper,
+    store: MetaWrapper,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    incoming_date: Option<NaiveDateTime>,
+    incoming_number: Option<String>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization_account: Option<MetaWrapper>,
+    overhead: Option<Overhead>,
+    owner: Option<MetaWrapper>,
+    positions: Option<Vec<CreatePosition<Supply>>>,
+    project: Option<MetaWrapper>,
+    purchase_order: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl CreateSupplyBuilder {
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn incoming_date(&mut self, incoming_date: NaiveDateTime) -> &mut Self {
+        let _ = self.incoming_date.insert(incoming_date);
+        self
+    }
+    pub fn incoming_number(&mut self, incoming_number: impl Into<String>) -> &mut Self {
+        let _ = self.incoming_number.insert(incoming_number.into());
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn overhead(&mut self, overhead: Overhead) -> &mut Self {
+        let _ = self.overhead.insert(overhead);
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn position(&mut self, position: CreatePosition<Supply>) -> &mut Self {
+        self.positions.get_or_insert(vec![]).push(position);
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn purchase_order(&mut self, purchase_order_meta: &Meta) -> &mut Self {
+        let _ = self.purchase_order.insert(MetaWrapper {
+            meta: purchase_order_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> CreateSupply {
+        CreateSupply {
+            organization: self.organization.to_owned(),
+            agent: self.agent.to_owned(),
+            store: self.store.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            incoming_date: self.incoming_date,
+            incoming_number: self.incoming_number.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            overhead: self.overhead.to_owned(),
+            owner: self.owner.to_owned(),
+            positions: self.positions.to_owned(),
+            project: self.project.to_owned(),
+            purchase_order: self.purchase_order.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSupply {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    agent_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    applicable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    attributes: Option<Vec<Attribute>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contract: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    group: Option<MetaWrapper>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    incoming_date: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    incoming_number: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_option_date_to_str"
+    )]
+    moment: Option<NaiveDateTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization_account: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    overhead: Option<Overhead>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    owner: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    purchase_order: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate: Option<Rate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    store: Option<MetaWrapper>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vat_included: Option<bool>,
+}
+#[derive(Default)]
+pub struct UpdateSupplyBuilder {
+    agent: Option<MetaWrapper>,
+    agent_account: Option<MetaWrapper>,
+    applicable: Option<bool>,
+    attributes: Option<Vec<Attribute>>,
+    code: Option<String>,
+    contract: Option<MetaWrapper>,
+    description: Option<String>,
+    external_code: Option<String>,
+    group: Option<MetaWrapper>,
+    incoming_date: Option<NaiveDateTime>,
+    incoming_number: Option<String>,
+    moment: Option<NaiveDateTime>,
+    name: Option<String>,
+    organization: Option<MetaWrapper>,
+    organization_account: Option<MetaWrapper>,
+    overhead: Option<Overhead>,
+    owner: Option<MetaWrapper>,
+    project: Option<MetaWrapper>,
+    purchase_order: Option<MetaWrapper>,
+    rate: Option<Rate>,
+    shared: Option<bool>,
+    state: Option<MetaWrapper>,
+    store: Option<MetaWrapper>,
+    vat_enabled: Option<bool>,
+    vat_included: Option<bool>,
+}
+impl UpdateSupplyBuilder {
+    pub fn agent(&mut self, agent_meta: &Meta) -> &mut Self {
+        let _ = self.agent.insert(MetaWrapper {
+            meta: agent_meta.to_owned(),
+        });
+        self
+    }
+    pub fn agent_account(&mut self, agent_account_meta: &Meta) -> &mut Self {
+        let _ = self.agent_account.insert(MetaWrapper {
+            meta: agent_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn applicable(&mut self, applicable: bool) -> &mut Self {
+        let _ = self.applicable.insert(applicable);
+        self
+    }
+    pub fn attribute(&mut self, attribute: Attribute) -> &mut Self {
+        self.attributes.get_or_insert(vec![]).push(attribute);
+        self
+    }
+    pub fn code(&mut self, code: impl Into<String>) -> &mut Self {
+        let _ = self.code.insert(code.into());
+        self
+    }
+    pub fn contract(&mut self, contract_meta: &Meta) -> &mut Self {
+        let _ = self.contract.insert(MetaWrapper {
+            meta: contract_meta.to_owned(),
+        });
+        self
+    }
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        let _ = self.description.insert(description.into());
+        self
+    }
+    pub fn external_code(&mut self, external_code: impl Into<String>) -> &mut Self {
+        let _ = self.external_code.insert(external_code.into());
+        self
+    }
+    pub fn group(&mut self, group_meta: &Meta) -> &mut Self {
+        let _ = self.group.insert(MetaWrapper {
+            meta: group_meta.to_owned(),
+        });
+        self
+    }
+    pub fn incoming_date(&mut self, incoming_date: NaiveDateTime) -> &mut Self {
+        let _ = self.incoming_date.insert(incoming_date);
+        self
+    }
+    pub fn incoming_number(&mut self, incoming_number: impl Into<String>) -> &mut Self {
+        let _ = self.incoming_number.insert(incoming_number.into());
+        self
+    }
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        let _ = self.moment.insert(moment);
+        self
+    }
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        let _ = self.name.insert(name.into());
+        self
+    }
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        let _ = self.organization.insert(MetaWrapper {
+            meta: organization_meta.to_owned(),
+        });
+        self
+    }
+    pub fn organization_account(&mut self, organization_account_meta: &Meta) -> &mut Self {
+        let _ = self.organization_account.insert(MetaWrapper {
+            meta: organization_account_meta.to_owned(),
+        });
+        self
+    }
+    pub fn overhead(&mut self, overhead: Overhead) -> &mut Self {
+        let _ = self.overhead.insert(overhead);
+        self
+    }
+    pub fn owner(&mut self, owner_meta: &Meta) -> &mut Self {
+        let _ = self.owner.insert(MetaWrapper {
+            meta: owner_meta.to_owned(),
+        });
+        self
+    }
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        let _ = self.project.insert(MetaWrapper {
+            meta: project_meta.to_owned(),
+        });
+        self
+    }
+    pub fn purchase_order(&mut self, purchase_order_meta: &Meta) -> &mut Self {
+        let _ = self.purchase_order.insert(MetaWrapper {
+            meta: purchase_order_meta.to_owned(),
+        });
+        self
+    }
+    pub fn rate(&mut self, rate: Rate) -> &mut Self {
+        let _ = self.rate.insert(rate);
+        self
+    }
+    pub fn shared(&mut self, shared: bool) -> &mut Self {
+        let _ = self.shared.insert(shared);
+        self
+    }
+    pub fn state(&mut self, state_meta: &Meta) -> &mut Self {
+        let _ = self.state.insert(MetaWrapper {
+            meta: state_meta.to_owned(),
+        });
+        self
+    }
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        let _ = self.store.insert(MetaWrapper {
+            meta: store_meta.to_owned(),
+        });
+        self
+    }
+    pub fn vat_enabled(&mut self, vat_enabled: bool) -> &mut Self {
+        let _ = self.vat_enabled.insert(vat_enabled);
+        self
+    }
+    pub fn vat_included(&mut self, vat_included: bool) -> &mut Self {
+        let _ = self.vat_included.insert(vat_included);
+        self
+    }
+    pub fn build(&self) -> UpdateSupply {
+        UpdateSupply {
+            agent: self.agent.to_owned(),
+            agent_account: self.agent_account.to_owned(),
+            applicable: self.applicable,
+            attributes: self.attributes.to_owned(),
+            code: self.code.to_owned(),
+            contract: self.contract.to_owned(),
+            description: self.description.to_owned(),
+            external_code: self.external_code.to_owned(),
+            group: self.group.to_owned(),
+            incoming_date: self.incoming_date,
+            incoming_number: self.incoming_number.to_owned(),
+            moment: self.moment,
+            name: self.name.to_owned(),
+            organization: self.organization.to_owned(),
+            organization_account: self.organization_account.to_owned(),
+            overhead: self.overhead.to_owned(),
+            owner: self.owner.to_owned(),
+            project: self.project.to_owned(),
+            purchase_order: self.purchase_order.to_owned(),
+            rate: self.rate.to_owned(),
+            shared: self.shared,
+            state: self.state.to_owned(),
+            store: self.store.to_owned(),
+            vat_enabled: self.vat_enabled,
+            vat_included: self.vat_included,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::{json, Value};
+
+    use crate::models::document::OverheadDistribution;
+
+    use super::*;
+
+    const SUPPLY_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000302";
+    const ORDER_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000301";
+    const INVOICE_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000303";
+    const RETURN_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000304";
+
+    fn meta_json(path: &str) -> Value {
+        json!({
+            "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/{path}"),
+            "type": path.split('/').next(),
+            "mediaType": "application/json"
+        })
+    }
+
+    fn meta(path: &str) -> Meta {
+        serde_json::from_value(meta_json(path)).unwrap()
+    }
+
+    fn supply_json() -> Value {
+        json!({
+            "meta": meta_json(&format!("supply/{SUPPLY_ID}")),
+            "id": SUPPLY_ID,
+            "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+            "shared": false,
+            "group": { "meta": meta_json("group/7a2c9b43-6c3e-11ee-0a80-0c5e00000003") },
+            "updated": "2024-05-04 10:00:00.000",
+            "name": "00011",
+            "externalCode": "ext-11",
+            "moment": "2024-05-04 09:00:00.000",
+            "applicable": true,
+            "rate": { "currency": { "meta": meta_json("currency/7a2c9b43-6c3e-11ee-0a80-0c5e00000004") } },
+            "sum": 100000.0,
+            "store": { "meta": meta_json("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005") },
+            "agent": { "meta": meta_json("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006") },
+            "organization": { "meta": meta_json("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007") },
+            "created": "2024-05-04 09:00:30.000",
+            "printed": false,
+            "published": false,
+            "incomingNumber": "ВХ-42",
+            "incomingDate": "2024-05-01 00:00:00.000",
+            "overhead": { "sum": 3000.0, "distribution": "price" },
+            "positions": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/supply/{SUPPLY_ID}/positions"),
+                    "type": "supplyposition",
+                    "mediaType": "application/json",
+                    "size": 1
+                },
+                "rows": [{
+                    "meta": meta_json(&format!("supply/{SUPPLY_ID}/positions/7a2c9b43-6c3e-11ee-0a80-0c5e00000320")),
+                    "id": "7a2c9b43-6c3e-11ee-0a80-0c5e00000320",
+                    "accountId": "7a2c9b43-6c3e-11ee-0a80-0c5e00000001",
+                    "quantity": 1.0,
+                    "price": 100000.0,
+                    "discount": 0.0,
+                    "vat": 20,
+                    "vatEnabled": true,
+                    "assortment": { "meta": meta_json("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009") },
+                    "overhead": 3000.0,
+                    "country": { "meta": meta_json("country/7a2c9b43-6c3e-11ee-0a80-0c5e00000012") },
+                    "slot": { "meta": meta_json("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005/slots/7a2c9b43-6c3e-11ee-0a80-0c5e00000011") },
+                    "things": ["SN-101"]
+                }]
+            },
+            "vatEnabled": true,
+            "vatIncluded": true,
+            "vatSum": 16666.67,
+            "payedSum": 0.0,
+            "purchaseOrder": { "meta": meta_json(&format!("purchaseorder/{ORDER_ID}")) },
+            "invoicesIn": [{ "meta": meta_json(&format!("invoicein/{INVOICE_ID}")) }],
+            "returns": [{ "meta": meta_json(&format!("purchasereturn/{RETURN_ID}")) }]
+        })
+    }
+
+    #[test]
+    fn supply_keeps_linked_documents() {
+        let supply: Supply = serde_json::from_value(supply_json()).unwrap();
+        assert_eq!(supply.id.to_string(), SUPPLY_ID);
+        assert_eq!(
+            supply.purchase_order.as_ref().and_then(|order| order.id()),
+            Some(ORDER_ID.parse().unwrap())
+        );
+        assert_eq!(
+            supply.invoices_in.as_ref().map(|invoices| invoices[0].id()),
+            Some(Some(INVOICE_ID.parse().unwrap()))
+        );
+        assert_eq!(
+            supply.returns.as_ref().map(|returns| returns[0].id()),
+            Some(Some(RETURN_ID.parse().unwrap()))
+        );
+        assert_eq!(supply.incoming_number.as_deref(), Some("ВХ-42"));
+        assert_eq!(
+            supply.incoming_date,
+            NaiveDate::from_ymd_opt(2024, 5, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+        );
+        assert_eq!(
+            supply.overhead.as_ref().map(|o| &o.distribution),
+            Some(&OverheadDistribution::Price)
+        );
+        let position = &supply.positions.rows[0];
+        assert!(position.country.is_some());
+        assert!(position.slot.is_some());
+        assert_eq!(
+            position.things.as_deref(),
+            Some(["SN-101".to_string()].as_slice())
+        );
+    }
+
+    #[test]
+    fn create_supply_links_purchase_order() {
+        let supply = Supply::create(
+            &meta("organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007"),
+            &meta("counterparty/7a2c9b43-6c3e-11ee-0a80-0c5e00000006"),
+            &meta("store/7a2c9b43-6c3e-11ee-0a80-0c5e00000005"),
+        )
+        .purchase_order(&meta(&format!("purchaseorder/{ORDER_ID}")))
+        .incoming_number("ВХ-42")
+        .incoming_date(
+            NaiveDate::from_ymd_opt(2024, 5, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        )
+        .position(
+            SupplyPosition::create(&meta("product/7a2c9b43-6c3e-11ee-0a80-0c5e00000009"), 1.0)
+                .thing("SN-101")
+                .build(),
+        )
+        .build();
+        let value = serde_json::to_value(&supply).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                "agent",
+                "incomingDate",
+                "incomingNumber",
+                "organization",
+                "positions",
+                "purchaseOrder",
+                "store"
+            ]
+        );
+        assert_eq!(value["purchaseOrder"]["meta"]["type"], "purchaseorder");
+        assert_eq!(value["incomingDate"], "2024-05-01 00:00:00.000");
+        assert_eq!(value["positions"][0]["things"], json!(["SN-101"]));
+    }
+
+    #[test]
+    fn update_supply_serializes_set_fields_only() {
+        let update = Supply::update().incoming_number("ВХ-43").build();
+        assert_eq!(
+            serde_json::to_value(&update).unwrap(),
+            json!({ "incomingNumber": "ВХ-43" })
+        );
+    }
+}
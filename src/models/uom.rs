@@ -3,12 +3,12 @@ use serde::{Deserialize, Serialize};
 
 use crate::api_client::MsEntity;
 
-use super::{deserialize_date_from_str, Meta, MetaWrapper};
+use super::{deserialize_date_from_str, employee::Employee, ms_ref::MsRef, Meta, MetaWrapper};
 /// Единица измерения
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
 /// use anyhow::Result;
 /// use rust_moysklad::{MoySkladApiClient, Uom};
 /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -58,7 +58,7 @@ pub struct Uom {
     /// Наименование Единицы измерения
     pub name: String,
     /// Владелец (Сотрудник)
-    pub owner: Option<MetaWrapper>,
+    pub owner: Option<MsRef<Employee>>,
     /// Общий доступ
     pub shared: Option<bool>,
     /// Момент последнего обновления Единицы измерения
@@ -66,7 +66,7 @@ pub struct Uom {
     pub updated: NaiveDateTime,
 }
 impl MsEntity for Uom {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/uom")
+    fn path() -> String {
+        String::from("entity/uom")
     }
 }
@@ -1,20 +1,22 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 
-use crate::api_client::MsEntity;
+use crate::api_client::{HasFiles, HasImages, MsEntity};
 
 use super::{
     assortment::{Barcode, BuyPrice, MinPrice, SalePrice},
     characteristic::Characteristic,
     deserialize_option_date_from_str,
-    product::{CreateSalePrice, Pack},
+    image::Upload,
+    ms_ref::MsRef,
+    product::{CreateSalePrice, Pack, Product},
     Meta, MetaWrapper,
 };
 /// Модификация
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,no_run
 /// use anyhow::Result;
 /// use rust_moysklad::{Characteristic, Currency, MoySkladApiClient, Product, Variant};
 /// use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
@@ -80,13 +82,14 @@ pub struct Variant {
     pub description: Option<String>,
     pub discount_prohibited: bool,
     pub external_code: String,
+    pub files: Option<MetaWrapper>,
     pub id: uuid::Uuid,
     pub images: Option<MetaWrapper>,
     pub meta: Meta,
     pub min_price: Option<MinPrice>,
     pub name: String,
     pub packs: Option<Vec<Pack>>,
-    pub product: MetaWrapper,
+    pub product: MsRef<Product>,
     pub sale_prices: Vec<SalePrice>,
     pub things: Option<Vec<String>>,
     #[serde(deserialize_with = "deserialize_option_date_from_str")]
@@ -105,10 +108,12 @@ impl Variant {
     }
 }
 impl MsEntity for Variant {
-    fn url() -> String {
-        String::from("https://api.moysklad.ru/api/remap/1.2/entity/variant")
+    fn path() -> String {
+        String::from("entity/variant")
     }
 }
+impl HasFiles for Variant {}
+impl HasImages for Variant {}
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateVariant {
@@ -126,7 +131,9 @@ pub struct CreateVariant {
     #[serde(skip_serializing_if = "Option::is_none")]
     external_code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    images: Option<MetaWrapper>,
+    files: Option<Vec<Upload>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    images: Option<Vec<Upload>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     min_price: Option<MinPrice>,
     name: Option<String>,
@@ -144,7 +151,8 @@ pub struct CreateVariantBuilder {
     description: Option<String>,
     discount_prohibited: Option<bool>,
     external_code: Option<String>,
-    images: Option<MetaWrapper>,
+    files: Option<Vec<Upload>>,
+    images: Option<Vec<Upload>>,
     min_price: Option<MinPrice>,
     name: Option<String>,
     packs: Option<Vec<Pack>>,
@@ -197,6 +205,16 @@ impl CreateVariantBuilder {
         let _ = self.external_code.insert(external_code.to_string());
         self
     }
+    /// Файл для загрузки, не более 100 файлов
+    pub fn file(&mut self, file: Upload) -> &mut Self {
+        self.files.get_or_insert(vec![]).push(file);
+        self
+    }
+    /// Изображение для загрузки, не более 10 изображений
+    pub fn image(&mut self, image: Upload) -> &mut Self {
+        self.images.get_or_insert(vec![]).push(image);
+        self
+    }
     pub fn min_price(&mut self, value: f64, currency: Meta) -> &mut Self {
         let _ = self.min_price.insert(MinPrice {
             value,
@@ -239,6 +257,7 @@ impl CreateVariantBuilder {
             description: self.description.to_owned(),
             discount_prohibited: self.discount_prohibited,
             external_code: self.external_code.to_owned(),
+            files: self.files.to_owned(),
             images: self.images.to_owned(),
             min_price: self.min_price.to_owned(),
             name: self.name.to_owned(),
@@ -266,9 +285,11 @@ pub struct UpdateVariant {
     #[serde(skip_serializing_if = "Option::is_none")]
     external_code: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<Upload>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     id: Option<uuid::Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    images: Option<MetaWrapper>,
+    images: Option<Vec<Upload>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     meta: Option<Meta>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -291,8 +312,9 @@ pub struct UpdateVariantBuilder {
     description: Option<String>,
     discount_prohibited: Option<bool>,
     external_code: Option<String>,
+    files: Option<Vec<Upload>>,
     id: Option<uuid::Uuid>,
-    images: Option<MetaWrapper>,
+    images: Option<Vec<Upload>>,
     meta: Option<Meta>,
     min_price: Option<MinPrice>,
     name: Option<String>,
@@ -354,6 +376,24 @@ impl UpdateVariantBuilder {
         let _ = self.meta.insert(meta);
         self
     }
+    /// Файл для загрузки, не более 100 файлов
+    ///
+    /// При обновлении переданный список заменяет все файлы Модификации: файлы, которых нет в
+    /// списке, будут удалены. Чтобы добавить файлы к уже загруженным, используйте
+    /// [`crate::MoySkladApiClient::add_files`]
+    pub fn file(&mut self, file: Upload) -> &mut Self {
+        self.files.get_or_insert(vec![]).push(file);
+        self
+    }
+    /// Изображение для загрузки, не более 10 изображений
+    ///
+    /// При обновлении переданный список заменяет все изображения Модификации: изображения,
+    /// которых нет в списке, будут удалены. Чтобы добавить изображения к уже загруженным,
+    /// используйте [`crate::MoySkladApiClient::add_images`]
+    pub fn image(&mut self, image: Upload) -> &mut Self {
+        self.images.get_or_insert(vec![]).push(image);
+        self
+    }
     pub fn min_price(&mut self, value: f64, currency: Meta) -> &mut Self {
         let _ = self.min_price.insert(MinPrice {
             value,
@@ -400,6 +440,7 @@ impl UpdateVariantBuilder {
             description: self.description.to_owned(),
             discount_prohibited: self.discount_prohibited,
             external_code: self.external_code.to_owned(),
+            files: self.files.to_owned(),
             images: self.images.to_owned(),
             min_price: self.min_price.to_owned(),
             name: self.name.to_owned(),
@@ -0,0 +1,363 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{api_client::MsEntity, reports::stock::StockType};
+
+use super::{Meta, MetaWrapper};
+
+/// Вебхук
+/// Средствами JSON API можно создавать и обновлять сведения о вебхуках, запрашивать списки вебхуков и сведения по отдельным вебхукам. Кодом сущности для вебхука в составе JSON API является ключевое слово webhook.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{CustomerOrder, DiffType, MoySkladApiClient, Webhook, WebhookAction};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let webhook = Webhook::create_for::<CustomerOrder>(
+///         "https://example.com/moysklad/webhook",
+///         WebhookAction::Update,
+///     )
+///     .diff_type(DiffType::Fields)
+///     .build();
+///     let webhook: Webhook = client.create(webhook).await?;
+///     let disabled = Webhook::update().enabled(false).build();
+///     client.update::<Webhook, _>(webhook.id, disabled).await?;
+///     client.delete::<Webhook>(webhook.id).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Webhook {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Действие, которое отслеживается вебхуком
+    pub action: WebhookAction,
+    /// Метаданные приложения, создавшего вебхук
+    pub author_application: Option<MetaWrapper>,
+    /// Режим отображения изменения сущности. Только для действия UPDATE
+    pub diff_type: Option<DiffType>,
+    /// Включен ли вебхук
+    pub enabled: bool,
+    /// Тип сущности, к которой привязан вебхук, например `product`
+    pub entity_type: String,
+    /// ID вебхука
+    pub id: Uuid,
+    /// Метаданные вебхука
+    pub meta: Meta,
+    /// HTTP метод, с которым будет происходить запрос
+    pub method: Option<String>,
+    /// URL, по которому будет происходить запрос
+    pub url: String,
+}
+impl Webhook {
+    pub fn create(
+        url: impl Into<String>,
+        action: WebhookAction,
+        entity_type: impl Into<String>,
+    ) -> CreateWebhookBuilder {
+        CreateWebhookBuilder::new(url, action, entity_type)
+    }
+    /// Вебхук на изменения сущности `E`
+    pub fn create_for<E: MsEntity>(
+        url: impl Into<String>,
+        action: WebhookAction,
+    ) -> CreateWebhookBuilder {
+        CreateWebhookBuilder::new(url, action, E::entity_type())
+    }
+    pub fn update() -> UpdateWebhookBuilder {
+        UpdateWebhookBuilder::default()
+    }
+}
+impl MsEntity for Webhook {
+    fn path() -> String {
+        String::from("entity/webhook")
+    }
+}
+/// Действие, которое отслеживается вебхуком
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum WebhookAction {
+    /// Создание
+    #[default]
+    Create,
+    /// Изменение
+    Update,
+    /// Удаление
+    Delete,
+    /// Обработка асинхронной задачи
+    Processed,
+}
+/// Режим отображения изменения сущности
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum DiffType {
+    /// Без списка измененных полей
+    #[default]
+    None,
+    /// Со списком измененных полей в `updatedFields`
+    Fields,
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhook {
+    action: WebhookAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff_type: Option<DiffType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    entity_type: String,
+    url: String,
+}
+#[derive(Default)]
+pub struct CreateWebhookBuilder {
+    action: WebhookAction,
+    diff_type: Option<DiffType>,
+    enabled: Option<bool>,
+    entity_type: String,
+    url: String,
+}
+impl CreateWebhookBuilder {
+    pub fn new(
+        url: impl Into<String>,
+        action: WebhookAction,
+        entity_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            action,
+            entity_type: entity_type.into(),
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+    /// Режим отображения изменения сущности
+    pub fn diff_type(&mut self, diff_type: DiffType) -> &mut Self {
+        let _ = self.diff_type.insert(diff_type);
+        self
+    }
+    /// Включен ли вебхук
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        let _ = self.enabled.insert(enabled);
+        self
+    }
+    pub fn build(&self) -> CreateWebhook {
+        CreateWebhook {
+            action: self.action,
+            diff_type: self.diff_type,
+            enabled: self.enabled,
+            entity_type: self.entity_type.to_owned(),
+            url: self.url.to_owned(),
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWebhook {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    action: Option<WebhookAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    diff_type: Option<DiffType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+#[derive(Default)]
+pub struct UpdateWebhookBuilder {
+    action: Option<WebhookAction>,
+    diff_type: Option<DiffType>,
+    enabled: Option<bool>,
+    url: Option<String>,
+}
+impl UpdateWebhookBuilder {
+    /// Действие, которое отслеживается вебхуком
+    pub fn action(&mut self, action: WebhookAction) -> &mut Self {
+        let _ = self.action.insert(action);
+        self
+    }
+    /// Режим отображения изменения сущности
+    pub fn diff_type(&mut self, diff_type: DiffType) -> &mut Self {
+        let _ = self.diff_type.insert(diff_type);
+        self
+    }
+    /// Включен ли вебхук
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        let _ = self.enabled.insert(enabled);
+        self
+    }
+    /// URL, по которому будет происходить запрос
+    pub fn url(&mut self, url: impl Into<String>) -> &mut Self {
+        let _ = self.url.insert(url.into());
+        self
+    }
+    pub fn build(&self) -> UpdateWebhook {
+        UpdateWebhook {
+            action: self.action,
+            diff_type: self.diff_type,
+            enabled: self.enabled,
+            url: self.url.to_owned(),
+        }
+    }
+}
+/// Вебхук на изменение остатков
+/// Вебхук присылает ссылку `reportUrl` на краткий отчет об остатках, отфильтрованный по изменившимся товарам. Кодом сущности в составе JSON API является ключевое слово webhookstock.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{reports::stock::StockType, MoySkladApiClient, StockReportType, WebhookStock};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let webhook = WebhookStock::create(
+///         "https://example.com/moysklad/stock",
+///         StockType::Stock,
+///         StockReportType::ByStore,
+///     )
+///     .build();
+///     let webhook: WebhookStock = client.create(webhook).await?;
+///     dbg!(webhook.id);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookStock {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Метаданные приложения, создавшего вебхук
+    pub author_application: Option<MetaWrapper>,
+    /// Включен ли вебхук
+    pub enabled: bool,
+    /// ID вебхука
+    pub id: Uuid,
+    /// Метаданные вебхука
+    pub meta: Meta,
+    /// Вид отчета, на который указывает `reportUrl`
+    pub report_type: StockReportType,
+    /// Вид остатка
+    pub stock_type: StockType,
+    /// URL, по которому будет происходить запрос
+    pub url: String,
+}
+impl WebhookStock {
+    pub fn create(
+        url: impl Into<String>,
+        stock_type: StockType,
+        report_type: StockReportType,
+    ) -> CreateWebhookStockBuilder {
+        CreateWebhookStockBuilder::new(url, stock_type, report_type)
+    }
+    pub fn update() -> UpdateWebhookStockBuilder {
+        UpdateWebhookStockBuilder::default()
+    }
+}
+impl MsEntity for WebhookStock {
+    fn path() -> String {
+        String::from("entity/webhookstock")
+    }
+}
+/// Вид отчета об остатках в вебхуке
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StockReportType {
+    /// `report/stock/all/current`
+    #[default]
+    All,
+    /// `report/stock/bystore/current`
+    ByStore,
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateWebhookStock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    report_type: StockReportType,
+    stock_type: StockType,
+    url: String,
+}
+#[derive(Default)]
+pub struct CreateWebhookStockBuilder {
+    enabled: Option<bool>,
+    report_type: StockReportType,
+    stock_type: StockType,
+    url: String,
+}
+impl CreateWebhookStockBuilder {
+    pub fn new(
+        url: impl Into<String>,
+        stock_type: StockType,
+        report_type: StockReportType,
+    ) -> Self {
+        Self {
+            report_type,
+            stock_type,
+            url: url.into(),
+            ..Default::default()
+        }
+    }
+    /// Включен ли вебхук
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        let _ = self.enabled.insert(enabled);
+        self
+    }
+    pub fn build(&self) -> CreateWebhookStock {
+        CreateWebhookStock {
+            enabled: self.enabled,
+            report_type: self.report_type,
+            stock_type: self.stock_type,
+            url: self.url.to_owned(),
+        }
+    }
+}
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateWebhookStock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    report_type: Option<StockReportType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stock_type: Option<StockType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+#[derive(Default)]
+pub struct UpdateWebhookStockBuilder {
+    enabled: Option<bool>,
+    report_type: Option<StockReportType>,
+    stock_type: Option<StockType>,
+    url: Option<String>,
+}
+impl UpdateWebhookStockBuilder {
+    /// Включен ли вебхук
+    pub fn enabled(&mut self, enabled: bool) -> &mut Self {
+        let _ = self.enabled.insert(enabled);
+        self
+    }
+    /// Вид отчета об остатках
+    pub fn report_type(&mut self, report_type: StockReportType) -> &mut Self {
+        let _ = self.report_type.insert(report_type);
+        self
+    }
+    /// Вид остатка
+    pub fn stock_type(&mut self, stock_type: StockType) -> &mut Self {
+        let _ = self.stock_type.insert(stock_type);
+        self
+    }
+    /// URL, по которому будет происходить запрос
+    pub fn url(&mut self, url: impl Into<String>) -> &mut Self {
+        let _ = self.url.insert(url.into());
+        self
+    }
+    pub fn build(&self) -> UpdateWebhookStock {
+        UpdateWebhookStock {
+            enabled: self.enabled,
+            report_type: self.report_type,
+            stock_type: self.stock_type,
+            url: self.url.to_owned(),
+        }
+    }
+}
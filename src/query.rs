@@ -0,0 +1,353 @@
+use std::fmt::Display;
+
+use crate::error::{MoySkladError, Result};
+
+/// Максимальный размер страницы
+pub(crate) const MAX_LIMIT: usize = 1000;
+/// Максимальный размер страницы при использовании expand
+pub(crate) const MAX_EXPAND_LIMIT: usize = 100;
+/// Максимальный размер страницы аудита
+pub(crate) const MAX_AUDIT_LIMIT: usize = 100;
+/// Максимальный уровень вложенности expand
+pub(crate) const MAX_EXPAND_DEPTH: usize = 3;
+
+/// Параметры запроса списка сущностей: фильтры, сортировка, expand, fields, limit/offset и поиск
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{FilterOperator, MoySkladApiClient, Order, Product, Query};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let mut query = Query::new();
+///     query
+///         .filter("archived", FilterOperator::Equal, "false")
+///         .filter("name", FilterOperator::PartialMatch, "ковер")
+///         .order("name", Order::Asc)
+///         .order("updated", Order::Desc)
+///         .expand("productFolder")
+///         .expand("uom");
+///     let products = client.get_all_with::<Product>(&query).await?;
+///     dbg!(products.len());
+///     let first_page = client.list_with::<Product>(query.limit(10)).await?;
+///     dbg!(first_page.rows.len());
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct Query {
+    filters: Vec<String>,
+    order: Vec<(String, Order)>,
+    expand: Vec<String>,
+    fields: Vec<String>,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    search: Option<String>,
+    params: Vec<(String, String)>,
+}
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Условие фильтрации `field operator value`. Условия объединяются через `;`
+    pub fn filter(
+        &mut self,
+        field: impl Into<String>,
+        operator: FilterOperator,
+        value: impl Into<String>,
+    ) -> &mut Self {
+        let value = value.into().replace(';', "\\;");
+        self.filters
+            .push(format!("{}{}{}", field.into(), operator, value));
+        self
+    }
+    /// Сортировка по полю. Порядок вызовов задает приоритет полей
+    pub fn order(&mut self, field: impl Into<String>, order: Order) -> &mut Self {
+        self.order.push((field.into(), order));
+        self
+    }
+    /// Раскрыть вложенную сущность, например `agent` или `positions.assortment`.
+    /// Максимальный уровень вложенности - 3, размер страницы при этом не больше 100
+    pub fn expand(&mut self, path: impl Into<String>) -> &mut Self {
+        self.expand.push(path.into());
+        self
+    }
+    /// Вернуть только указанные поля
+    pub fn fields(&mut self, field: impl Into<String>) -> &mut Self {
+        self.fields.push(field.into());
+        self
+    }
+    /// Количество элементов на странице (максимум 1000, с expand - 100)
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        let _ = self.limit.insert(limit);
+        self
+    }
+    /// Отступ в выдаваемом списке
+    pub fn offset(&mut self, offset: usize) -> &mut Self {
+        let _ = self.offset.insert(offset);
+        self
+    }
+    /// Контекстный поиск
+    pub fn search(&mut self, search: impl Into<String>) -> &mut Self {
+        let _ = self.search.insert(search.into());
+        self
+    }
+    /// Произвольный параметр запроса, например `groupBy` для отчетов
+    pub fn param(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.params.push((key.into(), value.into()));
+        self
+    }
+    /// Копия запроса с размером страницы по умолчанию, если limit не задан
+    pub(crate) fn with_default_limit(&self, limit: usize) -> Query {
+        let mut query = self.clone();
+        let _ = query.limit.get_or_insert(limit);
+        query
+    }
+    pub(crate) fn get_offset(&self) -> usize {
+        self.offset.unwrap_or_default()
+    }
+    /// Размер страницы с учетом ограничений API
+    pub(crate) fn page_limit(&self) -> Result<usize> {
+        let max = if self.expand.is_empty() {
+            MAX_LIMIT
+        } else {
+            MAX_EXPAND_LIMIT
+        };
+        match self.limit {
+            None => Ok(max),
+            Some(limit) if limit > 0 && limit <= max => Ok(limit),
+            Some(limit) => Err(MoySkladError::InvalidArgument(format!(
+                "limit must be in 1..={max}, got {limit}"
+            ))),
+        }
+    }
+    /// Параметры запроса без limit и offset
+    pub(crate) fn params(&self) -> Result<Vec<(String, String)>> {
+        let mut params = Vec::new();
+        if !self.filters.is_empty() {
+            params.push(("filter".to_string(), self.filters.join(";")));
+        }
+        if !self.order.is_empty() {
+            let order = self
+                .order
+                .iter()
+                .map(|(field, order)| format!("{field},{order}"))
+                .collect::<Vec<_>>()
+                .join(";");
+            params.push(("order".to_string(), order));
+        }
+        if !self.expand.is_empty() {
+            if let Some(path) = self
+                .expand
+                .iter()
+                .find(|path| path.split('.').count() > MAX_EXPAND_DEPTH)
+            {
+                return Err(MoySkladError::InvalidArgument(format!(
+                    "expand depth is limited to {MAX_EXPAND_DEPTH} levels, got '{path}'"
+                )));
+            }
+            params.push(("expand".to_string(), self.expand.join(",")));
+        }
+        if !self.fields.is_empty() {
+            params.push(("fields".to_string(), self.fields.join(",")));
+        }
+        if let Some(search) = &self.search {
+            params.push(("search".to_string(), search.to_owned()));
+        }
+        params.extend(self.params.iter().cloned());
+        Ok(params)
+    }
+}
+
+/// Направление сортировки
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum Order {
+    /// По возрастанию
+    #[default]
+    Asc,
+    /// По убыванию
+    Desc,
+}
+impl Display for Order {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Order::Asc => write!(f, "asc"),
+            Order::Desc => write!(f, "desc"),
+        }
+    }
+}
+
+/// Доступные операторы для фильтрации
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FilterOperator {
+    /// `=` - фильтрация по значению
+    Equal,
+    /// `!=` - не равно
+    NotEqual,
+    /// `~` - частичное совпадение
+    PartialMatch,
+    /// `!~` - частичное совпадение не выводится
+    NoPartialMatch,
+    /// `~=` - полное совпадение в начале значения
+    FullMatchAtTheBeginning,
+    /// `=~` - полное совпадение в конце значения
+    CompleteMatchAtTheEnd,
+    /// `>` - больше
+    GreaterThan,
+    /// `<` - меньше
+    LesserThan,
+    /// `>=` - больше или равно
+    GreaterThanOrEqual,
+    /// `<=` - меньше или равно
+    LesserThanOrEqual,
+}
+impl Display for FilterOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FilterOperator::Equal => write!(f, "="),
+            FilterOperator::NotEqual => write!(f, "!="),
+            FilterOperator::PartialMatch => write!(f, "~"),
+            FilterOperator::NoPartialMatch => write!(f, "!~"),
+            FilterOperator::FullMatchAtTheBeginning => write!(f, "~="),
+            FilterOperator::CompleteMatchAtTheEnd => write!(f, "=~"),
+            FilterOperator::GreaterThan => write!(f, ">"),
+            FilterOperator::LesserThan => write!(f, "<"),
+            FilterOperator::GreaterThanOrEqual => write!(f, ">="),
+            FilterOperator::LesserThanOrEqual => write!(f, "<="),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn param<'a>(params: &'a [(String, String)], key: &str) -> Option<&'a str> {
+        params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    #[test]
+    fn empty_query_has_no_params() {
+        assert!(Query::new().params().unwrap().is_empty());
+    }
+
+    #[test]
+    fn filter_value_semicolon_is_escaped() {
+        let mut query = Query::new();
+        query.filter("name", FilterOperator::Equal, "a;b");
+        let params = query.params().unwrap();
+        assert_eq!(param(&params, "filter"), Some(r"name=a\;b"));
+    }
+
+    #[test]
+    fn filters_are_joined_with_semicolon() {
+        let mut query = Query::new();
+        query
+            .filter("archived", FilterOperator::Equal, "false")
+            .filter("name", FilterOperator::PartialMatch, "ковер")
+            .filter("updated", FilterOperator::GreaterThanOrEqual, "2024-01-01");
+        let params = query.params().unwrap();
+        assert_eq!(
+            param(&params, "filter"),
+            Some("archived=false;name~ковер;updated>=2024-01-01")
+        );
+    }
+
+    #[test]
+    fn order_keeps_call_priority() {
+        let mut query = Query::new();
+        query
+            .order("name", Order::Asc)
+            .order("updated", Order::Desc);
+        let params = query.params().unwrap();
+        assert_eq!(param(&params, "order"), Some("name,asc;updated,desc"));
+    }
+
+    #[test]
+    fn expand_fields_search_and_custom_params() {
+        let mut query = Query::new();
+        query
+            .expand("agent")
+            .expand("positions.assortment")
+            .fields("stock")
+            .search("ковер")
+            .param("groupBy", "variant");
+        let params = query.params().unwrap();
+        assert_eq!(param(&params, "expand"), Some("agent,positions.assortment"));
+        assert_eq!(param(&params, "fields"), Some("stock"));
+        assert_eq!(param(&params, "search"), Some("ковер"));
+        assert_eq!(param(&params, "groupBy"), Some("variant"));
+    }
+
+    #[test]
+    fn expand_depth_three_is_allowed() {
+        let mut query = Query::new();
+        query.expand("positions.assortment.uom");
+        assert!(query.params().is_ok());
+    }
+
+    #[test]
+    fn expand_depth_over_three_is_rejected() {
+        let mut query = Query::new();
+        query.expand("positions.assortment.uom.group");
+        assert!(matches!(
+            query.params(),
+            Err(MoySkladError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn limit_defaults_to_max() {
+        assert_eq!(Query::new().page_limit().unwrap(), MAX_LIMIT);
+        let mut query = Query::new();
+        query.expand("agent");
+        assert_eq!(query.page_limit().unwrap(), MAX_EXPAND_LIMIT);
+    }
+
+    #[test]
+    fn limit_is_capped_at_100_with_expand() {
+        let mut query = Query::new();
+        query.expand("agent").limit(100);
+        assert_eq!(query.page_limit().unwrap(), 100);
+        query.limit(101);
+        assert!(matches!(
+            query.page_limit(),
+            Err(MoySkladError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn limit_out_of_range_is_rejected() {
+        let mut query = Query::new();
+        query.limit(1000);
+        assert_eq!(query.page_limit().unwrap(), 1000);
+        query.limit(1001);
+        assert!(query.page_limit().is_err());
+        query.limit(0);
+        assert!(query.page_limit().is_err());
+    }
+
+    #[test]
+    fn default_limit_does_not_override_explicit() {
+        let mut query = Query::new();
+        assert_eq!(
+            query
+                .with_default_limit(MAX_AUDIT_LIMIT)
+                .page_limit()
+                .unwrap(),
+            MAX_AUDIT_LIMIT
+        );
+        query.limit(20);
+        assert_eq!(
+            query
+                .with_default_limit(MAX_AUDIT_LIMIT)
+                .page_limit()
+                .unwrap(),
+            20
+        );
+    }
+}
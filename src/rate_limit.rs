@@ -0,0 +1,355 @@
+use std::{collections::VecDeque, sync::Mutex, time::Duration};
+
+use reqwest::{header::HeaderMap, StatusCode};
+use tokio::{
+    sync::{Semaphore, SemaphorePermit},
+    time::Instant,
+};
+use tracing::warn;
+
+use crate::error::Result;
+
+/// Лимит запросов по умолчанию: не более 45 запросов за 3 секунды
+pub const DEFAULT_RATE_LIMIT: usize = 45;
+pub const DEFAULT_RATE_LIMIT_PERIOD: Duration = Duration::from_secs(3);
+/// Не более 5 параллельных запросов от одного пользователя
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 5;
+/// Количество повторов запроса после ответов 429 и 503
+pub const DEFAULT_MAX_RETRIES: u32 = 5;
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+const RATE_LIMIT_REMAINING: &str = "X-RateLimit-Remaining";
+const LOGNEX_RESET: &str = "X-Lognex-Reset";
+const LOGNEX_RETRY_AFTER: &str = "X-Lognex-Retry-After";
+
+/// Ограничитель запросов, общий для всех клонов клиента
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    semaphore: Semaphore,
+    state: Mutex<State>,
+    max_requests: usize,
+    period: Duration,
+    max_retries: u32,
+}
+#[derive(Debug, Default)]
+struct State {
+    /// Моменты отправки запросов в текущем окне
+    sent: VecDeque<Instant>,
+    /// API попросил не отправлять запросы до этого момента
+    blocked_until: Option<Instant>,
+}
+impl RateLimiter {
+    pub(crate) fn new(
+        max_requests: usize,
+        period: Duration,
+        max_concurrent_requests: usize,
+        max_retries: u32,
+    ) -> Self {
+        Self {
+            semaphore: Semaphore::new(max_concurrent_requests.max(1)),
+            state: Mutex::new(State::default()),
+            max_requests: max_requests.max(1),
+            period,
+            max_retries,
+        }
+    }
+    /// Отправляет запрос с соблюдением лимитов и повторяет его при ответах 429 и 503
+    pub(crate) async fn execute(
+        &self,
+        client: &reqwest::Client,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            let Some(current) = request.try_clone() else {
+                // тело запроса нельзя повторить - отправляем один раз
+                let _permit = self.acquire().await;
+                let response = client.execute(request).await?;
+                self.update(response.headers());
+                return Ok(response);
+            };
+            let permit = self.acquire().await;
+            let response = client.execute(current).await?;
+            drop(permit);
+            self.update(response.headers());
+            let status = response.status();
+            if !matches!(
+                status,
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+            ) || attempt >= self.max_retries
+            {
+                return Ok(response);
+            }
+            let delay = header_millis(response.headers(), LOGNEX_RETRY_AFTER)
+                .unwrap_or_else(|| backoff(attempt));
+            attempt += 1;
+            warn!(
+                "{status} from {url}, retry {attempt}/{max} in {delay:?}",
+                url = request.url(),
+                max = self.max_retries
+            );
+            self.block_for(delay);
+        }
+    }
+    async fn acquire(&self) -> SemaphorePermit<'_> {
+        let permit = self
+            .semaphore
+            .acquire()
+            .await
+            .expect("rate limiter semaphore is never closed");
+        loop {
+            match self.reserve() {
+                None => return permit,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+    /// Занимает место в окне лимита или возвращает время ожидания
+    fn reserve(&self) -> Option<Duration> {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        if let Some(until) = state.blocked_until {
+            if until > now {
+                return Some(until - now);
+            }
+            state.blocked_until = None;
+        }
+        while let Some(first) = state.sent.front() {
+            if now.duration_since(*first) >= self.period {
+                state.sent.pop_front();
+            } else {
+                break;
+            }
+        }
+        if state.sent.len() < self.max_requests {
+            state.sent.push_back(now);
+            return None;
+        }
+        state
+            .sent
+            .front()
+            .map(|first| self.period.saturating_sub(now.duration_since(*first)))
+    }
+    fn block_for(&self, delay: Duration) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let until = Instant::now() + delay;
+        if state.blocked_until.is_none_or(|current| current < until) {
+            state.blocked_until = Some(until);
+        }
+    }
+    /// Учитывает заголовки лимитов из ответа API
+    fn update(&self, headers: &HeaderMap) {
+        let remaining = headers
+            .get(RATE_LIMIT_REMAINING)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+        if remaining == Some(0) {
+            let delay = header_millis(headers, LOGNEX_RESET).unwrap_or(self.period);
+            self.block_for(delay);
+        }
+    }
+}
+fn header_millis(headers: &HeaderMap, name: &str) -> Option<Duration> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_millis)
+}
+fn backoff(attempt: u32) -> Duration {
+    BASE_BACKOFF
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(MAX_BACKOFF)
+}
+
+#[cfg(test)]
+mod tests {
+    use reqwest::header::HeaderValue;
+    use wiremock::{matchers::method, Mock, MockServer, ResponseTemplate};
+
+    use super::*;
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn window_delays_requests_over_limit() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(1), 5, 0);
+        let start = Instant::now();
+        drop(limiter.acquire().await);
+        drop(limiter.acquire().await);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+        drop(limiter.acquire().await);
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn window_slides_with_first_request() {
+        let limiter = RateLimiter::new(2, Duration::from_secs(1), 5, 0);
+        let start = Instant::now();
+        drop(limiter.acquire().await);
+        tokio::time::sleep(Duration::from_millis(600)).await;
+        drop(limiter.acquire().await);
+        // место освобождается через период после первого запроса, а не второго
+        drop(limiter.acquire().await);
+        assert_eq!(start.elapsed(), Duration::from_secs(1));
+        drop(limiter.acquire().await);
+        assert_eq!(start.elapsed(), Duration::from_millis(1600));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exhausted_remaining_blocks_until_reset() {
+        let limiter = RateLimiter::new(45, Duration::from_secs(3), 5, 0);
+        limiter.update(&headers(&[
+            (RATE_LIMIT_REMAINING, "0"),
+            (LOGNEX_RESET, "1500"),
+        ]));
+        let start = Instant::now();
+        drop(limiter.acquire().await);
+        assert_eq!(start.elapsed(), Duration::from_millis(1500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn exhausted_remaining_without_reset_blocks_for_period() {
+        let limiter = RateLimiter::new(45, Duration::from_secs(3), 5, 0);
+        limiter.update(&headers(&[(RATE_LIMIT_REMAINING, "0")]));
+        let start = Instant::now();
+        drop(limiter.acquire().await);
+        assert_eq!(start.elapsed(), Duration::from_secs(3));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn remaining_requests_do_not_block() {
+        let limiter = RateLimiter::new(45, Duration::from_secs(3), 5, 0);
+        limiter.update(&headers(&[
+            (RATE_LIMIT_REMAINING, "12"),
+            (LOGNEX_RESET, "1500"),
+        ]));
+        let start = Instant::now();
+        drop(limiter.acquire().await);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn longer_block_is_kept() {
+        let limiter = RateLimiter::new(45, Duration::from_secs(3), 5, 0);
+        limiter.block_for(Duration::from_secs(2));
+        limiter.block_for(Duration::from_millis(500));
+        let start = Instant::now();
+        drop(limiter.acquire().await);
+        assert_eq!(start.elapsed(), Duration::from_secs(2));
+    }
+
+    #[test]
+    fn backoff_doubles_and_is_capped() {
+        assert_eq!(backoff(0), Duration::from_millis(500));
+        assert_eq!(backoff(1), Duration::from_secs(1));
+        assert_eq!(backoff(3), Duration::from_secs(4));
+        assert_eq!(backoff(6), MAX_BACKOFF);
+        assert_eq!(backoff(40), MAX_BACKOFF);
+    }
+
+    async fn execute(server: &MockServer, limiter: &RateLimiter) -> reqwest::Response {
+        let client = reqwest::Client::new();
+        let request = client.get(server.uri()).build().unwrap();
+        limiter.execute(&client, request).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn retries_stop_after_max_retries() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header(LOGNEX_RETRY_AFTER, "1"))
+            .expect(3)
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new(45, Duration::from_secs(3), 5, 2);
+
+        let response = execute(&server, &limiter).await;
+
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn unavailable_is_retried_until_success() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503).insert_header(LOGNEX_RETRY_AFTER, "1"))
+            .up_to_n_times(2)
+            .expect(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new(45, Duration::from_secs(3), 5, 5);
+
+        let response = execute(&server, &limiter).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn other_errors_are_not_retried() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(1)
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new(45, Duration::from_secs(3), 5, 5);
+
+        let response = execute(&server, &limiter).await;
+
+        assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_waits_for_retry_after_header() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(429).insert_header(LOGNEX_RETRY_AFTER, "2500"))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new(45, Duration::from_secs(3), 5, 5);
+        let start = Instant::now();
+
+        let response = execute(&server, &limiter).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(start.elapsed() >= Duration::from_millis(2500));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn retry_without_header_uses_backoff() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(503))
+            .up_to_n_times(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .respond_with(ResponseTemplate::new(200))
+            .mount(&server)
+            .await;
+        let limiter = RateLimiter::new(45, Duration::from_secs(3), 5, 5);
+        let start = Instant::now();
+
+        let response = execute(&server, &limiter).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(start.elapsed() >= backoff(0) + backoff(1));
+    }
+}
@@ -0,0 +1,163 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api_client::MsReport,
+    models::{counterparty::Counterparty, deserialize_option_date_from_str, Meta},
+};
+
+use super::ReportRef;
+
+/// Строка отчета по покупателям `report/counterparty`. Суммы в копейках
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{reports::counterparty::CounterpartyReport, MoySkladApiClient, Query};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let rows = client.get_report::<CounterpartyReport>(&Query::new()).await?;
+///     let top = rows.iter().max_by(|a, b| a.demands_sum.total_cmp(&b.demands_sum));
+///     dbg!(top.map(|row| &row.counterparty.name));
+///     let report = client.get_counterparty_report(uuid::Uuid::nil()).await?;
+///     dbg!(report.demands_sum, report.last_demand_date);
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CounterpartyReport {
+    /// Средний чек
+    pub average_receipt: f64,
+    /// Баланс
+    pub balance: f64,
+    /// Контрагент
+    pub counterparty: ReportRef<Counterparty>,
+    /// Количество продаж
+    pub demands_count: i64,
+    /// Сумма продаж
+    pub demands_sum: f64,
+    /// Сумма скидок
+    pub discounts_sum: f64,
+    /// Дата первой продажи
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub first_demand_date: Option<NaiveDateTime>,
+    /// Дата последней продажи
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub last_demand_date: Option<NaiveDateTime>,
+    /// Дата последнего события
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub last_event_date: Option<NaiveDateTime>,
+    /// Текст последнего события
+    pub last_event_text: Option<String>,
+    /// Метаданные строки отчета
+    pub meta: Meta,
+    /// Прибыль
+    pub profit: f64,
+    /// Количество возвратов
+    pub returns_count: i64,
+    /// Сумма возвратов
+    pub returns_sum: f64,
+    /// Момент последнего изменения контрагента
+    #[serde(default, deserialize_with = "deserialize_option_date_from_str")]
+    pub updated: Option<NaiveDateTime>,
+}
+impl MsReport for CounterpartyReport {
+    fn path() -> String {
+        String::from("report/counterparty")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use super::*;
+
+    const COUNTERPARTY_ID: &str = "7a2c9b43-6c3e-11ee-0a80-0c5e00000006";
+
+    #[test]
+    fn report_row_from_documented_response() {
+        let row: CounterpartyReport = serde_json::from_value(json!({
+            "meta": {
+                "href": format!("https://api.moysklad.ru/api/remap/1.2/report/counterparty/{COUNTERPARTY_ID}"),
+                "type": "counterparty",
+                "mediaType": "application/json"
+            },
+            "counterparty": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/counterparty/{COUNTERPARTY_ID}"),
+                    "metadataHref": "https://api.moysklad.ru/api/remap/1.2/entity/counterparty/metadata",
+                    "type": "counterparty",
+                    "mediaType": "application/json"
+                },
+                "id": COUNTERPARTY_ID,
+                "name": "ООО \"Покупатель\"",
+                "externalCode": "cp-1",
+                "companyType": "legal"
+            },
+            "firstDemandDate": "2018-01-18 16:01:35.000",
+            "lastDemandDate": "2018-02-01 10:12:00.000",
+            "demandsCount": 2,
+            "demandsSum": 1200000.0,
+            "averageReceipt": 600000.0,
+            "returnsCount": 0,
+            "returnsSum": 0.0,
+            "discountsSum": 0.0,
+            "balance": -1200000.0,
+            "profit": 1100000.0,
+            "lastEventDate": "2018-02-01 10:15:00.000",
+            "lastEventText": "Звонок клиенту",
+            "updated": "2018-02-01 10:15:30.000"
+        }))
+        .unwrap();
+        assert_eq!(row.counterparty.name, "ООО \"Покупатель\"");
+        assert_eq!(
+            row.counterparty.reference().id().map(|id| id.to_string()),
+            Some(COUNTERPARTY_ID.to_string())
+        );
+        assert_eq!(row.demands_count, 2);
+        assert_eq!(row.average_receipt, 600000.0);
+        assert_eq!(
+            row.first_demand_date,
+            NaiveDate::from_ymd_opt(2018, 1, 18)
+                .unwrap()
+                .and_hms_opt(16, 1, 35)
+        );
+        assert_eq!(row.last_event_text.as_deref(), Some("Звонок клиенту"));
+    }
+
+    #[test]
+    fn report_row_without_sales() {
+        let row: CounterpartyReport = serde_json::from_value(json!({
+            "meta": {
+                "href": format!("https://api.moysklad.ru/api/remap/1.2/report/counterparty/{COUNTERPARTY_ID}"),
+                "type": "counterparty",
+                "mediaType": "application/json"
+            },
+            "counterparty": {
+                "meta": {
+                    "href": format!("https://api.moysklad.ru/api/remap/1.2/entity/counterparty/{COUNTERPARTY_ID}"),
+                    "type": "counterparty",
+                    "mediaType": "application/json"
+                },
+                "name": "Новый покупатель"
+            },
+            "demandsCount": 0,
+            "demandsSum": 0.0,
+            "averageReceipt": 0.0,
+            "returnsCount": 0,
+            "returnsSum": 0.0,
+            "discountsSum": 0.0,
+            "balance": 0.0,
+            "profit": 0.0
+        }))
+        .unwrap();
+        assert!(row.first_demand_date.is_none());
+        assert!(row.last_demand_date.is_none());
+        assert!(row.last_event_date.is_none());
+        assert!(row.updated.is_none());
+    }
+}
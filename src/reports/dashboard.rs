@@ -0,0 +1,119 @@
+use serde::{Deserialize, Serialize};
+
+/// Показатели за период `report/dashboard/{day|week|month}`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{reports::dashboard::DashboardPeriod, MoySkladApiClient};
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let today = client.get_dashboard(DashboardPeriod::Day).await?;
+///     println!(
+///         "Продажи: {} на {:.2} руб., остаток денег {:.2} руб.",
+///         today.sales.count,
+///         today.sales.amount / 100.0,
+///         today.money.balance / 100.0
+///     );
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Dashboard {
+    /// Продажи
+    pub sales: DashboardSales,
+    /// Заказы покупателей
+    pub orders: DashboardSales,
+    /// Деньги
+    pub money: DashboardMoney,
+}
+/// Продажи или заказы за период. Суммы в копейках
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardSales {
+    /// Количество
+    pub count: i64,
+    /// Сумма
+    pub amount: f64,
+    /// Изменение суммы по сравнению с прошлым периодом
+    pub movement_amount: f64,
+}
+/// Движение денег за период. Суммы в копейках
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardMoney {
+    /// Доходы
+    pub income: f64,
+    /// Расходы
+    pub outcome: f64,
+    /// Текущий баланс
+    pub balance: f64,
+    /// Изменение баланса за сегодня
+    pub today_movement: f64,
+    /// Изменение баланса за период
+    pub movement: f64,
+}
+/// Период показателей
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DashboardPeriod {
+    /// Сегодня
+    Day,
+    /// Неделя
+    Week,
+    /// Месяц
+    Month,
+}
+impl DashboardPeriod {
+    pub(crate) fn path(&self) -> &'static str {
+        match self {
+            DashboardPeriod::Day => "report/dashboard/day",
+            DashboardPeriod::Week => "report/dashboard/week",
+            DashboardPeriod::Month => "report/dashboard/month",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn dashboard_from_documented_response() {
+        let dashboard: Dashboard = serde_json::from_value(json!({
+            "sales": {
+                "count": 2,
+                "amount": 1220000.0,
+                "movementAmount": -10000.0
+            },
+            "orders": {
+                "count": 0,
+                "amount": 0.0,
+                "movementAmount": 0.0
+            },
+            "money": {
+                "income": 1220000.0,
+                "outcome": 35000.0,
+                "balance": 9215000.0,
+                "todayMovement": 1185000.0,
+                "movement": 1185000.0
+            }
+        }))
+        .unwrap();
+        assert_eq!(dashboard.sales.count, 2);
+        assert_eq!(dashboard.sales.movement_amount, -10000.0);
+        assert_eq!(dashboard.orders, DashboardSales::default());
+        assert_eq!(dashboard.money.balance, 9215000.0);
+        assert_eq!(dashboard.money.today_movement, 1185000.0);
+    }
+
+    #[test]
+    fn period_paths() {
+        assert_eq!(DashboardPeriod::Day.path(), "report/dashboard/day");
+        assert_eq!(DashboardPeriod::Week.path(), "report/dashboard/week");
+        assert_eq!(DashboardPeriod::Month.path(), "report/dashboard/month");
+    }
+}
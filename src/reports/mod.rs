@@ -0,0 +1,243 @@
+//! Отчеты `report/...`
+use std::marker::PhantomData;
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::{ms_ref::MsRef, Meta},
+    query::{FilterOperator, Query},
+};
+
+pub mod counterparty;
+pub mod dashboard;
+pub mod money;
+pub mod profit;
+pub mod stock;
+pub mod turnover;
+
+/// Формат момента в параметрах отчетов
+pub(crate) const MOMENT_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Сущность в строке отчета: метаданные и основные поля без полного объекта
+///
+/// Полную сущность можно загрузить через [`ReportRef::reference`] и [`crate::MoySkladApiClient::resolve`]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportRef<T> {
+    /// Метаданные сущности
+    pub meta: Meta,
+    /// Наименование
+    pub name: String,
+    /// Код
+    pub code: Option<String>,
+    /// Артикул
+    pub article: Option<String>,
+    #[serde(skip)]
+    entity: PhantomData<T>,
+}
+impl<T> ReportRef<T> {
+    /// Типизированная ссылка на сущность
+    pub fn reference(&self) -> MsRef<T> {
+        MsRef::new(self.meta.to_owned())
+    }
+}
+/// Параметры отчетов за период: прибыльность и обороты
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::NaiveDate;
+/// use rust_moysklad::{
+///     reports::{profit::ProductProfit, ReportQuery},
+///     MoySkladApiClient, Store,
+/// };
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let store = client.get::<Store>(uuid::Uuid::nil()).await?;
+///     let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+///     let to = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+///     let query = ReportQuery::new().period(from, to).store(&store.meta).build();
+///     let rows = client.get_report::<ProductProfit>(&query).await?;
+///     for row in rows {
+///         dbg!(row.assortment.name, row.profit);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ReportQuery {
+    query: Query,
+}
+impl ReportQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Начало периода
+    pub fn moment_from(&mut self, moment_from: NaiveDateTime) -> &mut Self {
+        self.query
+            .param("momentFrom", moment_from.format(MOMENT_FORMAT).to_string());
+        self
+    }
+    /// Конец периода
+    pub fn moment_to(&mut self, moment_to: NaiveDateTime) -> &mut Self {
+        self.query
+            .param("momentTo", moment_to.format(MOMENT_FORMAT).to_string());
+        self
+    }
+    /// Период по дням: с начала дня `from` до конца дня `to`
+    pub fn period(&mut self, from: NaiveDate, to: NaiveDate) -> &mut Self {
+        let end_of_day = NaiveTime::from_hms_opt(23, 59, 59).unwrap_or_default();
+        self.moment_from(from.and_time(NaiveTime::MIN))
+            .moment_to(to.and_time(end_of_day))
+    }
+    /// Склад
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        self.filter("store", store_meta)
+    }
+    /// Проект
+    pub fn project(&mut self, project_meta: &Meta) -> &mut Self {
+        self.filter("project", project_meta)
+    }
+    /// Юрлицо
+    pub fn organization(&mut self, organization_meta: &Meta) -> &mut Self {
+        self.filter("organization", organization_meta)
+    }
+    /// Контрагент
+    pub fn counterparty(&mut self, counterparty_meta: &Meta) -> &mut Self {
+        self.filter("counterparty", counterparty_meta)
+    }
+    /// Товар, модификация или серия
+    pub fn product(&mut self, product_meta: &Meta) -> &mut Self {
+        self.filter("product", product_meta)
+    }
+    /// Группа товаров
+    pub fn product_folder(&mut self, product_folder_meta: &Meta) -> &mut Self {
+        self.filter("productFolder", product_folder_meta)
+    }
+    /// Интервал, с которым будет построен отчет динамики
+    pub fn interval(&mut self, interval: ReportInterval) -> &mut Self {
+        self.query.param("interval", interval.as_str());
+        self
+    }
+    /// Количество строк на странице
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.query.limit(limit);
+        self
+    }
+    pub fn build(&self) -> Query {
+        self.query.to_owned()
+    }
+    fn filter(&mut self, field: &str, meta: &Meta) -> &mut Self {
+        self.query
+            .filter(field, FilterOperator::Equal, meta.href.to_owned());
+        self
+    }
+}
+/// Интервал отчетов динамики
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportInterval {
+    /// Час
+    Hour,
+    /// День
+    Day,
+    /// Месяц
+    Month,
+}
+impl ReportInterval {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ReportInterval::Hour => "hour",
+            ReportInterval::Day => "day",
+            ReportInterval::Month => "month",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STORE: &str =
+        "https://api.moysklad.ru/api/remap/1.2/entity/store/6f4d2e2a-6c3e-11ee-0a80-0c5e00000001";
+    const PRODUCT: &str =
+        "https://api.moysklad.ru/api/remap/1.2/entity/product/6f4d2e2a-6c3e-11ee-0a80-0c5e00000002";
+
+    fn meta(href: &str) -> Meta {
+        Meta {
+            href: href.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Строка запроса в том виде, в каком ее отправит клиент
+    fn query_string(query: &Query) -> String {
+        reqwest::Client::new()
+            .get("https://api.moysklad.ru/api/remap/1.2/report/turnover/all")
+            .query(&query.params().unwrap())
+            .build()
+            .unwrap()
+            .url()
+            .query()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    #[test]
+    fn empty_report_query() {
+        let query = ReportQuery::new().build();
+        assert!(query.params().unwrap().is_empty());
+    }
+
+    #[test]
+    fn period_covers_whole_days() {
+        let query = ReportQuery::new()
+            .period(
+                NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+            )
+            .build();
+        assert_eq!(
+            query.params().unwrap(),
+            vec![
+                ("momentFrom".to_string(), "2024-01-01 00:00:00".to_string()),
+                ("momentTo".to_string(), "2024-01-31 23:59:59".to_string()),
+            ]
+        );
+        assert_eq!(
+            query_string(&query),
+            "momentFrom=2024-01-01+00%3A00%3A00&momentTo=2024-01-31+23%3A59%3A59"
+        );
+    }
+
+    #[test]
+    fn filters_are_entity_hrefs() {
+        let query = ReportQuery::new()
+            .store(&meta(STORE))
+            .product(&meta(PRODUCT))
+            .build();
+        assert_eq!(
+            query.params().unwrap(),
+            vec![(
+                "filter".to_string(),
+                format!("store={STORE};product={PRODUCT}")
+            )]
+        );
+    }
+
+    #[test]
+    fn interval_and_limit() {
+        for (interval, value) in [
+            (ReportInterval::Hour, "hour"),
+            (ReportInterval::Day, "day"),
+            (ReportInterval::Month, "month"),
+        ] {
+            let query = ReportQuery::new().interval(interval).build();
+            assert_eq!(query_string(&query), format!("interval={value}"));
+        }
+        let query = ReportQuery::new().limit(50).build();
+        assert!(query.params().unwrap().is_empty());
+        assert_eq!(query.page_limit().unwrap(), 50);
+    }
+}
@@ -0,0 +1,184 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api_client::MsReport,
+    models::{
+        deserialize_date_from_str,
+        organization::{Organization, OrganizationAccount},
+    },
+};
+
+use super::ReportRef;
+
+/// Динамика движения денег `report/money/plotseries`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::NaiveDate;
+/// use rust_moysklad::{
+///     reports::{ReportInterval, ReportQuery},
+///     MoySkladApiClient,
+/// };
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let from = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+///     let to = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+///     let query = ReportQuery::new()
+///         .period(from, to)
+///         .interval(ReportInterval::Day)
+///         .build();
+///     let series = client.get_money_plot_series(&query).await?;
+///     for point in series.series {
+///         dbg!(point.date, point.balance);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoneyPlotSeries {
+    /// Доход за период в копейках
+    pub credit: f64,
+    /// Расход за период в копейках
+    pub debit: f64,
+    /// Показатели по интервалам
+    pub series: Vec<MoneySeriesPoint>,
+}
+/// Показатели за интервал. Суммы в копейках
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoneySeriesPoint {
+    /// Дата начала интервала
+    #[serde(deserialize_with = "deserialize_date_from_str")]
+    pub date: NaiveDateTime,
+    /// Доход
+    pub credit: f64,
+    /// Расход
+    pub debit: f64,
+    /// Баланс на конец интервала
+    pub balance: f64,
+}
+/// Строка отчета об остатках денег по кассам и счетам `report/money/byaccount`
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoneyByAccount {
+    /// Юрлицо
+    pub organization: ReportRef<Organization>,
+    /// Счет юрлица. Не заполняется для кассы
+    pub account: Option<ReportRef<OrganizationAccount>>,
+    /// Остаток в копейках
+    pub balance: f64,
+}
+impl MsReport for MoneyByAccount {
+    fn path() -> String {
+        String::from("report/money/byaccount")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn plot_series_from_documented_response() {
+        let series: MoneyPlotSeries = serde_json::from_value(json!({
+            "context": {
+                "employee": {
+                    "meta": {
+                        "href": "https://api.moysklad.ru/api/remap/1.2/context/employee",
+                        "metadataHref": "https://api.moysklad.ru/api/remap/1.2/entity/employee/metadata",
+                        "type": "employee",
+                        "mediaType": "application/json"
+                    }
+                }
+            },
+            "meta": {
+                "href": "https://api.moysklad.ru/api/remap/1.2/report/money/plotseries?momentFrom=2016-10-16 00:00:00&momentTo=2016-10-17 23:59:59&interval=day",
+                "type": "moneyplotseries",
+                "mediaType": "application/json"
+            },
+            "credit": 159800.0,
+            "debit": 5000.0,
+            "series": [
+                {
+                    "date": "2016-10-16 00:00:00.000",
+                    "credit": 0.0,
+                    "debit": 0.0,
+                    "balance": -22000.0
+                },
+                {
+                    "date": "2016-10-17 00:00:00.000",
+                    "credit": 159800.0,
+                    "debit": 5000.0,
+                    "balance": 132800.0
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(series.credit, 159800.0);
+        assert_eq!(series.debit, 5000.0);
+        assert_eq!(series.series.len(), 2);
+        assert_eq!(
+            series.series[1].date,
+            NaiveDate::from_ymd_opt(2016, 10, 17)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+        assert_eq!(series.series[1].balance, 132800.0);
+    }
+
+    #[test]
+    fn by_account_rows_with_and_without_account() {
+        let rows: Vec<MoneyByAccount> = serde_json::from_value(json!([
+            {
+                "organization": {
+                    "meta": {
+                        "href": "https://api.moysklad.ru/api/remap/1.2/entity/organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007",
+                        "type": "organization",
+                        "mediaType": "application/json"
+                    },
+                    "name": "ООО \"Ромашка\""
+                },
+                "account": {
+                    "meta": {
+                        "href": "https://api.moysklad.ru/api/remap/1.2/entity/organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007/accounts/7a2c9b43-6c3e-11ee-0a80-0c5e00000008",
+                        "type": "account",
+                        "mediaType": "application/json"
+                    },
+                    "name": "40702810000000000001"
+                },
+                "balance": 9215000.0
+            },
+            {
+                "organization": {
+                    "meta": {
+                        "href": "https://api.moysklad.ru/api/remap/1.2/entity/organization/7a2c9b43-6c3e-11ee-0a80-0c5e00000007",
+                        "type": "organization",
+                        "mediaType": "application/json"
+                    },
+                    "name": "ООО \"Ромашка\""
+                },
+                "balance": 150000.0
+            }
+        ]))
+        .unwrap();
+        assert_eq!(rows[0].organization.name, "ООО \"Ромашка\"");
+        assert_eq!(
+            rows[0]
+                .account
+                .as_ref()
+                .map(|account| account.name.as_str()),
+            Some("40702810000000000001")
+        );
+        assert_eq!(rows[0].balance, 9215000.0);
+        assert!(rows[1].account.is_none());
+        assert_eq!(rows[1].balance, 150000.0);
+    }
+}
@@ -0,0 +1,97 @@
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api_client::MsReport,
+    models::{
+        assortment::Assortment, counterparty::Counterparty, product::Product, variant::Variant,
+    },
+};
+
+use super::ReportRef;
+
+/// Строка отчета о прибыльности по товарам или модификациям
+///
+/// Тип параметра задает отчет: [`ProductProfit`] (`report/profit/byproduct`)
+/// или [`VariantProfit`] (`report/profit/byvariant`). Суммы и цены в копейках
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssortmentProfit<K> {
+    /// Товар, услуга, модификация или комплект
+    pub assortment: ReportRef<Assortment>,
+    /// Рентабельность товаров
+    pub margin: f64,
+    /// Прибыль
+    pub profit: f64,
+    /// Себестоимость возвратов
+    pub return_cost: f64,
+    /// Сумма себестоимостей возвратов
+    pub return_cost_sum: f64,
+    /// Цена возвратов
+    pub return_price: f64,
+    /// Количество возвратов
+    pub return_quantity: f64,
+    /// Сумма возвратов
+    pub return_sum: f64,
+    /// Рентабельность продаж
+    pub sales_margin: Option<f64>,
+    /// Себестоимость
+    pub sell_cost: f64,
+    /// Сумма себестоимостей продаж
+    pub sell_cost_sum: f64,
+    /// Цена продаж (средняя)
+    pub sell_price: f64,
+    /// Проданное количество
+    pub sell_quantity: f64,
+    /// Сумма продаж
+    pub sell_sum: f64,
+    #[serde(skip)]
+    kind: PhantomData<K>,
+}
+/// Прибыльность по товарам
+pub type ProductProfit = AssortmentProfit<Product>;
+/// Прибыльность по модификациям
+pub type VariantProfit = AssortmentProfit<Variant>;
+impl MsReport for ProductProfit {
+    fn path() -> String {
+        String::from("report/profit/byproduct")
+    }
+}
+impl MsReport for VariantProfit {
+    fn path() -> String {
+        String::from("report/profit/byvariant")
+    }
+}
+/// Строка отчета о прибыльности по покупателям `report/profit/bycounterparty`. Суммы в копейках
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CounterpartyProfit {
+    /// Контрагент
+    pub counterparty: ReportRef<Counterparty>,
+    /// Рентабельность
+    pub margin: f64,
+    /// Прибыль
+    pub profit: f64,
+    /// Средний чек возврата
+    pub return_avg_check: f64,
+    /// Сумма себестоимостей возвратов
+    pub return_cost_sum: f64,
+    /// Количество возвратов
+    pub return_count: f64,
+    /// Сумма возвратов
+    pub return_sum: f64,
+    /// Средний чек продаж
+    pub sales_avg_check: f64,
+    /// Количество продаж
+    pub sales_count: f64,
+    /// Сумма себестоимостей продаж
+    pub sell_cost_sum: f64,
+    /// Сумма продаж
+    pub sell_sum: f64,
+}
+impl MsReport for CounterpartyProfit {
+    fn path() -> String {
+        String::from("report/profit/bycounterparty")
+    }
+}
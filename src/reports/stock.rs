@@ -0,0 +1,550 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    api_client::MsReport,
+    models::Meta,
+    query::{FilterOperator, Query},
+};
+
+use super::MOMENT_FORMAT;
+
+/// Строка расширенного отчета об остатках `report/stock/all`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{
+///     reports::stock::{StockMode, StockQuery, StockRow},
+///     MoySkladApiClient, Store,
+/// };
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let store = client.get::<Store>(uuid::Uuid::nil()).await?;
+///     let query = StockQuery::new()
+///         .stock_mode(StockMode::PositiveOnly)
+///         .store(&store.meta)
+///         .build();
+///     let rows = client.get_report::<StockRow>(&query).await?;
+///     for row in rows {
+///         dbg!(row.name, row.stock, row.reserve);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockRow {
+    /// Артикул
+    pub article: Option<String>,
+    /// Код
+    pub code: Option<String>,
+    /// Внешний код сущности, по которой выводится остаток
+    pub external_code: Option<String>,
+    /// Группа товара/модификации/серии
+    pub folder: Option<StockFolder>,
+    /// Ожидание
+    pub in_transit: f64,
+    /// Метаданные товара/модификации/серии
+    pub meta: Meta,
+    /// Наименование
+    pub name: String,
+    /// Себестоимость в копейках
+    pub price: Option<f64>,
+    /// Доступно
+    pub quantity: f64,
+    /// Резерв
+    pub reserve: f64,
+    /// Цена продажи в копейках
+    pub sale_price: Option<f64>,
+    /// Остаток
+    pub stock: f64,
+    /// Количество дней на складе
+    pub stock_days: Option<f64>,
+    /// Единица измерения
+    pub uom: Option<StockUom>,
+}
+impl MsReport for StockRow {
+    fn path() -> String {
+        String::from("report/stock/all")
+    }
+}
+/// Группа товара в отчете об остатках
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockFolder {
+    /// Метаданные группы
+    pub meta: Meta,
+    /// Наименование группы
+    pub name: String,
+    /// Наименование группы, в которую входит данная группа
+    pub path_name: Option<String>,
+}
+/// Единица измерения в отчете об остатках
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockUom {
+    /// Метаданные единицы измерения
+    pub meta: Meta,
+    /// Наименование единицы измерения
+    pub name: String,
+}
+/// Строка отчета об остатках по складам `report/stock/bystore`
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StockByStoreRow {
+    /// Метаданные товара/модификации/серии
+    pub meta: Meta,
+    /// Остатки по складам
+    pub stock_by_store: Vec<StoreStock>,
+}
+impl MsReport for StockByStoreRow {
+    fn path() -> String {
+        String::from("report/stock/bystore")
+    }
+}
+/// Остаток на складе
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreStock {
+    /// Ожидание
+    pub in_transit: f64,
+    /// Метаданные склада
+    pub meta: Meta,
+    /// Наименование склада
+    pub name: String,
+    /// Резерв
+    pub reserve: f64,
+    /// Остаток
+    pub stock: f64,
+}
+/// Вид остатка, по которому фильтруются строки отчета
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StockMode {
+    /// Любое значение остатка
+    All,
+    /// Положительный остаток
+    PositiveOnly,
+    /// Отрицательный остаток
+    NegativeOnly,
+    /// Нулевой остаток
+    Empty,
+    /// Ненулевой остаток
+    NonEmpty,
+    /// Остаток ниже неснижаемого
+    UnderMinimum,
+}
+impl StockMode {
+    fn as_str(&self) -> &'static str {
+        match self {
+            StockMode::All => "all",
+            StockMode::PositiveOnly => "positiveOnly",
+            StockMode::NegativeOnly => "negativeOnly",
+            StockMode::Empty => "empty",
+            StockMode::NonEmpty => "nonEmpty",
+            StockMode::UnderMinimum => "underMinimum",
+        }
+    }
+}
+/// Группировка строк отчета
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StockGroupBy {
+    /// Только товары
+    Product,
+    /// Товары и модификации
+    Variant,
+    /// Товары, модификации и серии
+    Consignment,
+}
+impl StockGroupBy {
+    fn as_str(&self) -> &'static str {
+        match self {
+            StockGroupBy::Product => "product",
+            StockGroupBy::Variant => "variant",
+            StockGroupBy::Consignment => "consignment",
+        }
+    }
+}
+/// Параметры отчетов `report/stock/all` и `report/stock/bystore`
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StockQuery {
+    query: Query,
+}
+impl StockQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Момент, на который нужно вывести остатки
+    pub fn moment(&mut self, moment: NaiveDateTime) -> &mut Self {
+        self.query.filter(
+            "moment",
+            FilterOperator::Equal,
+            moment.format(MOMENT_FORMAT).to_string(),
+        );
+        self
+    }
+    /// Вид остатка по складам
+    pub fn stock_mode(&mut self, stock_mode: StockMode) -> &mut Self {
+        self.query
+            .filter("stockMode", FilterOperator::Equal, stock_mode.as_str());
+        self
+    }
+    /// Вид доступного количества
+    pub fn quantity_mode(&mut self, quantity_mode: StockMode) -> &mut Self {
+        self.query.filter(
+            "quantityMode",
+            FilterOperator::Equal,
+            quantity_mode.as_str(),
+        );
+        self
+    }
+    /// Склад. Можно указать несколько складов
+    pub fn store(&mut self, store_meta: &Meta) -> &mut Self {
+        self.query
+            .filter("store", FilterOperator::Equal, store_meta.href.to_owned());
+        self
+    }
+    /// Группа товаров. Можно указать несколько групп
+    pub fn product_folder(&mut self, product_folder_meta: &Meta) -> &mut Self {
+        self.query.filter(
+            "productFolder",
+            FilterOperator::Equal,
+            product_folder_meta.href.to_owned(),
+        );
+        self
+    }
+    /// Товар, модификация или серия
+    pub fn product(&mut self, product_meta: &Meta) -> &mut Self {
+        self.query.filter(
+            "product",
+            FilterOperator::Equal,
+            product_meta.href.to_owned(),
+        );
+        self
+    }
+    /// Поставщик
+    pub fn supplier(&mut self, supplier_meta: &Meta) -> &mut Self {
+        self.query.filter(
+            "supplier",
+            FilterOperator::Equal,
+            supplier_meta.href.to_owned(),
+        );
+        self
+    }
+    /// Выводить ли архивные товары
+    pub fn archived(&mut self, archived: bool) -> &mut Self {
+        self.query
+            .filter("archived", FilterOperator::Equal, archived.to_string());
+        self
+    }
+    /// Группировка строк отчета
+    pub fn group_by(&mut self, group_by: StockGroupBy) -> &mut Self {
+        self.query.param("groupBy", group_by.as_str());
+        self
+    }
+    /// Контекстный поиск по наименованию, коду и артикулу
+    pub fn search(&mut self, search: impl Into<String>) -> &mut Self {
+        self.query.search(search);
+        self
+    }
+    /// Количество строк на странице
+    pub fn limit(&mut self, limit: usize) -> &mut Self {
+        self.query.limit(limit);
+        self
+    }
+    pub fn build(&self) -> Query {
+        self.query.to_owned()
+    }
+}
+/// Краткий остаток из отчетов `report/stock/all/current` и `report/stock/bystore/current`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use rust_moysklad::{
+///     reports::stock::{CurrentStockQuery, StockType},
+///     MoySkladApiClient,
+/// };
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     // время в API московское
+///     let since = chrono::Utc::now().naive_utc() + chrono::Duration::minutes(3 * 60 - 5);
+///     let query = CurrentStockQuery::new()
+///         .stock_type(StockType::FreeStock)
+///         .changed_since(since)
+///         .build();
+///     let changed = client.get_current_stock(&query).await?;
+///     dbg!(changed.len());
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentStock {
+    /// ID товара/модификации/серии
+    pub assortment_id: Uuid,
+    /// ID склада. Заполняется только в отчете по складам
+    pub store_id: Option<Uuid>,
+    /// Значение остатка выбранного вида
+    #[serde(
+        alias = "freeStock",
+        alias = "quantity",
+        alias = "reserve",
+        alias = "inTransit"
+    )]
+    pub stock: f64,
+}
+/// Вид остатка в кратком отчете
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StockType {
+    /// Физический остаток
+    #[default]
+    Stock,
+    /// Остаток за вычетом резерва
+    FreeStock,
+    /// Доступно: остаток за вычетом резерва плюс ожидание
+    Quantity,
+    /// Резерв
+    Reserve,
+    /// Ожидание
+    InTransit,
+}
+impl StockType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            StockType::Stock => "stock",
+            StockType::FreeStock => "freeStock",
+            StockType::Quantity => "quantity",
+            StockType::Reserve => "reserve",
+            StockType::InTransit => "inTransit",
+        }
+    }
+}
+/// Параметры краткого отчета об остатках
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct CurrentStockQuery {
+    by_store: bool,
+    stock_type: Option<StockType>,
+    include_zero_lines: bool,
+    changed_since: Option<NaiveDateTime>,
+    assortment_ids: Vec<Uuid>,
+    store_ids: Vec<Uuid>,
+}
+impl CurrentStockQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Выводить остатки в разрезе складов (`report/stock/bystore/current`)
+    pub fn by_store(&mut self, by_store: bool) -> &mut Self {
+        self.by_store = by_store;
+        self
+    }
+    /// Вид остатка, по умолчанию физический остаток
+    pub fn stock_type(&mut self, stock_type: StockType) -> &mut Self {
+        let _ = self.stock_type.insert(stock_type);
+        self
+    }
+    /// Выводить ли нулевые остатки
+    pub fn include_zero_lines(&mut self, include_zero_lines: bool) -> &mut Self {
+        self.include_zero_lines = include_zero_lines;
+        self
+    }
+    /// Только остатки, изменившиеся после указанного момента по московскому времени (не ранее суток назад)
+    pub fn changed_since(&mut self, changed_since: NaiveDateTime) -> &mut Self {
+        let _ = self.changed_since.insert(changed_since);
+        self
+    }
+    /// Остаток по указанному товару/модификации/серии
+    pub fn assortment_id(&mut self, assortment_id: Uuid) -> &mut Self {
+        self.assortment_ids.push(assortment_id);
+        self
+    }
+    /// Остаток на указанном складе
+    pub fn store_id(&mut self, store_id: Uuid) -> &mut Self {
+        self.store_ids.push(store_id);
+        self
+    }
+    pub fn build(&self) -> CurrentStockQuery {
+        self.to_owned()
+    }
+    pub(crate) fn path(&self) -> &'static str {
+        if self.by_store {
+            "report/stock/bystore/current"
+        } else {
+            "report/stock/all/current"
+        }
+    }
+    pub(crate) fn params(&self) -> Vec<(String, String)> {
+        let mut params = Vec::new();
+        if let Some(stock_type) = self.stock_type {
+            params.push(("stockType".to_string(), stock_type.as_str().to_string()));
+        }
+        if self.include_zero_lines {
+            params.push(("include".to_string(), "zeroLines".to_string()));
+        }
+        if let Some(changed_since) = self.changed_since {
+            let moment = changed_since.format(MOMENT_FORMAT).to_string();
+            params.push(("changedSince".to_string(), moment));
+        }
+        let filters = self
+            .assortment_ids
+            .iter()
+            .map(|id| format!("assortmentId={id}"))
+            .chain(self.store_ids.iter().map(|id| format!("storeId={id}")))
+            .collect::<Vec<_>>();
+        if !filters.is_empty() {
+            params.push(("filter".to_string(), filters.join(";")));
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    const STORE: &str =
+        "https://api.moysklad.ru/api/remap/1.2/entity/store/6f4d2e2a-6c3e-11ee-0a80-0c5e00000001";
+    const PRODUCT: &str =
+        "https://api.moysklad.ru/api/remap/1.2/entity/product/6f4d2e2a-6c3e-11ee-0a80-0c5e00000002";
+
+    fn meta(href: &str) -> Meta {
+        Meta {
+            href: href.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Строка запроса в том виде, в каком ее отправит клиент
+    fn query_string(params: &[(String, String)]) -> String {
+        reqwest::Client::new()
+            .get("https://api.moysklad.ru/api/remap/1.2/report/stock/all")
+            .query(params)
+            .build()
+            .unwrap()
+            .url()
+            .query()
+            .unwrap_or_default()
+            .to_string()
+    }
+
+    #[test]
+    fn current_stock_defaults() {
+        let query = CurrentStockQuery::new();
+        assert_eq!(query.path(), "report/stock/all/current");
+        assert!(query.params().is_empty());
+        assert_eq!(
+            CurrentStockQuery::new().by_store(true).build().path(),
+            "report/stock/bystore/current"
+        );
+    }
+
+    #[test]
+    fn current_stock_type_and_include() {
+        let params = CurrentStockQuery::new()
+            .stock_type(StockType::FreeStock)
+            .include_zero_lines(true)
+            .build()
+            .params();
+        assert_eq!(
+            query_string(&params),
+            "stockType=freeStock&include=zeroLines"
+        );
+        for (stock_type, value) in [
+            (StockType::Stock, "stock"),
+            (StockType::Quantity, "quantity"),
+            (StockType::Reserve, "reserve"),
+            (StockType::InTransit, "inTransit"),
+        ] {
+            let params = CurrentStockQuery::new().stock_type(stock_type).params();
+            assert_eq!(params, vec![("stockType".to_string(), value.to_string())]);
+        }
+    }
+
+    #[test]
+    fn current_stock_filters_are_joined() {
+        let assortment = Uuid::from_u128(1);
+        let store = Uuid::from_u128(2);
+        let changed_since = NaiveDate::from_ymd_opt(2024, 5, 1)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        let params = CurrentStockQuery::new()
+            .assortment_id(assortment)
+            .store_id(store)
+            .changed_since(changed_since)
+            .params();
+        assert_eq!(
+            params,
+            vec![
+                (
+                    "changedSince".to_string(),
+                    "2024-05-01 09:30:00".to_string()
+                ),
+                (
+                    "filter".to_string(),
+                    format!("assortmentId={assortment};storeId={store}")
+                ),
+            ]
+        );
+        assert_eq!(
+            query_string(&params),
+            format!(
+                "changedSince=2024-05-01+09%3A30%3A00&filter=assortmentId%3D{assortment}%3BstoreId%3D{store}"
+            )
+        );
+    }
+
+    #[test]
+    fn stock_query_filters_by_href() {
+        let params = StockQuery::new()
+            .store(&meta(STORE))
+            .product(&meta(PRODUCT))
+            .stock_mode(StockMode::PositiveOnly)
+            .group_by(StockGroupBy::Variant)
+            .build()
+            .params()
+            .unwrap();
+        assert_eq!(
+            params,
+            vec![
+                (
+                    "filter".to_string(),
+                    format!("store={STORE};product={PRODUCT};stockMode=positiveOnly")
+                ),
+                ("groupBy".to_string(), "variant".to_string()),
+            ]
+        );
+        let encoded = query_string(&params);
+        assert!(encoded.starts_with("filter=store%3Dhttps%3A%2F%2Fapi.moysklad.ru%2F"));
+        assert!(encoded.ends_with("%3BstockMode%3DpositiveOnly&groupBy=variant"));
+    }
+
+    #[test]
+    fn stock_query_moment_and_quantity_mode() {
+        let moment = NaiveDate::from_ymd_opt(2024, 1, 31)
+            .unwrap()
+            .and_hms_opt(23, 59, 59)
+            .unwrap();
+        let params = StockQuery::new()
+            .moment(moment)
+            .quantity_mode(StockMode::NonEmpty)
+            .archived(false)
+            .build()
+            .params()
+            .unwrap();
+        assert_eq!(
+            params,
+            vec![(
+                "filter".to_string(),
+                "moment=2024-01-31 23:59:59;quantityMode=nonEmpty;archived=false".to_string()
+            )]
+        );
+    }
+}
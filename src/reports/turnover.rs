@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    api_client::MsReport,
+    models::{assortment::Assortment, store::Store},
+};
+
+use super::ReportRef;
+
+/// Строка отчета об оборотах `report/turnover/all`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::NaiveDate;
+/// use rust_moysklad::{
+///     reports::{turnover::TurnoverRow, ReportQuery},
+///     MoySkladApiClient,
+/// };
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let from = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+///     let to = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+///     let query = ReportQuery::new().period(from, to).build();
+///     for row in client.get_report::<TurnoverRow>(&query).await? {
+///         dbg!(&row.assortment.name, row.income.quantity, row.outcome.quantity);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TurnoverRow {
+    /// Товар, услуга, модификация или комплект
+    pub assortment: ReportRef<Assortment>,
+    /// Приход за период
+    pub income: TurnoverValue,
+    /// Показатели на конец периода
+    pub on_period_end: TurnoverValue,
+    /// Показатели на начало периода
+    pub on_period_start: TurnoverValue,
+    /// Расход за период
+    pub outcome: TurnoverValue,
+}
+impl MsReport for TurnoverRow {
+    fn path() -> String {
+        String::from("report/turnover/all")
+    }
+}
+/// Строка отчета об оборотах по складам `report/turnover/bystore`
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chrono::NaiveDate;
+/// use rust_moysklad::{
+///     reports::{turnover::TurnoverByStoreRow, ReportQuery},
+///     MoySkladApiClient,
+/// };
+/// #[tokio::main]
+/// async fn main() -> anyhow::Result<()> {
+///     let client = MoySkladApiClient::from_env().expect("MS_TOKEN env var not set!");
+///     let day = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+///     let query = ReportQuery::new().period(day, day).build();
+///     for row in client.get_report::<TurnoverByStoreRow>(&query).await? {
+///         for store in row.stock_by_store {
+///             dbg!(&row.assortment.name, store.store.name, store.outcome.quantity);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TurnoverByStoreRow {
+    /// Товар, услуга, модификация или комплект
+    pub assortment: ReportRef<Assortment>,
+    /// Обороты по складам
+    pub stock_by_store: Vec<StoreTurnover>,
+}
+impl MsReport for TurnoverByStoreRow {
+    fn path() -> String {
+        String::from("report/turnover/bystore")
+    }
+}
+/// Обороты на складе
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreTurnover {
+    /// Склад
+    pub store: ReportRef<Store>,
+    /// Приход за период
+    pub income: TurnoverValue,
+    /// Показатели на конец периода
+    pub on_period_end: TurnoverValue,
+    /// Показатели на начало периода
+    pub on_period_start: TurnoverValue,
+    /// Расход за период
+    pub outcome: TurnoverValue,
+}
+/// Количество и сумма
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TurnoverValue {
+    /// Количество
+    pub quantity: f64,
+    /// Сумма в копейках
+    pub sum: f64,
+}
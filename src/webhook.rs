@@ -0,0 +1,213 @@
+//! Разбор входящих запросов вебхуков
+//!
+//! Модуль не зависит от HTTP фреймворка: тело запроса передается в [`WebhookPayload::from_slice`],
+//! а полная сущность загружается через [`WebhookEvent::fetch`] только при необходимости.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use rust_moysklad::{
+//!     webhook::WebhookPayload, CustomerOrder, MoySkladApiClient, Product, WebhookAction,
+//! };
+//! async fn handle(client: &MoySkladApiClient, body: &[u8]) -> anyhow::Result<()> {
+//!     let payload = WebhookPayload::from_slice(body)?;
+//!     for event in payload.events {
+//!         if let Some(product) = event.entity::<Product>() {
+//!             dbg!(product.id(), event.action, &event.updated_fields);
+//!         } else if event.action == WebhookAction::Create {
+//!             if let Some(order) = event.fetch::<CustomerOrder>(client).await? {
+//!                 dbg!(order.name);
+//!             }
+//!         }
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Вебхук на изменение остатков присылает ссылку на отчет, строки которого загружаются через
+//! [`WebhookStockPayload::fetch`]:
+//!
+//! ```rust,no_run
+//! use rust_moysklad::{webhook::WebhookStockPayload, MoySkladApiClient};
+//! async fn handle(client: &MoySkladApiClient, body: &[u8]) -> anyhow::Result<()> {
+//!     let payload = WebhookStockPayload::from_slice(body)?;
+//!     for stock in payload.fetch(client).await? {
+//!         dbg!(stock.assortment_id, stock.store_id, stock.stock);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+use chrono::NaiveDateTime;
+use serde::{de::IntoDeserializer, Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    api_client::{MoySkladApiClient, MsEntity},
+    error::{MoySkladError, Result},
+    models::{
+        audit::AuditContext,
+        ms_ref::MsRef,
+        webhook::{StockReportType, WebhookAction},
+        Meta,
+    },
+    reports::stock::{CurrentStock, CurrentStockQuery, StockType},
+};
+
+/// Тело запроса вебхука
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    /// Контекст изменения: кто и когда изменил сущности
+    pub audit_context: Option<AuditContext>,
+    /// События
+    pub events: Vec<WebhookEvent>,
+}
+impl WebhookPayload {
+    /// Разобрать тело запроса
+    pub fn from_slice(body: &[u8]) -> Result<Self> {
+        serde_json::from_slice(body).map_err(|source| MoySkladError::Deserialize {
+            source,
+            body: String::from_utf8_lossy(body).into_owned(),
+        })
+    }
+}
+/// Событие вебхука
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEvent {
+    /// Метаданные измененной сущности
+    pub meta: Meta,
+    /// Действие
+    pub action: WebhookAction,
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Измененные поля. Заполняется для вебхуков с `diffType` FIELDS
+    pub updated_fields: Option<Vec<String>>,
+}
+impl WebhookEvent {
+    /// Тип измененной сущности, например `product`
+    pub fn entity_type(&self) -> Option<&str> {
+        self.meta.meta_type.as_deref()
+    }
+    /// Относится ли событие к сущности `E`
+    pub fn is<E: MsEntity>(&self) -> bool {
+        self.entity_type() == Some(E::entity_type().as_str())
+    }
+    /// Ссылка на измененную сущность, если событие относится к сущности `E`
+    pub fn entity<E: MsEntity>(&self) -> Option<MsRef<E>> {
+        self.is::<E>().then(|| MsRef::new(self.meta.to_owned()))
+    }
+    /// Загрузить измененную сущность, если событие относится к сущности `E`.
+    /// Для удаленной сущности API вернет ошибку
+    ///
+    /// Тело вебхука не подписано, поэтому токен отправляется только по адресу, собранному
+    /// из корня API клиента и ID сущности. Ссылка `meta.href` на другой адрес отклоняется
+    /// с [`MoySkladError::InvalidArgument`]
+    pub async fn fetch<E: MsEntity>(&self, client: &MoySkladApiClient) -> Result<Option<E>> {
+        if !self.is::<E>() {
+            return Ok(None);
+        }
+        let prefix = format!("{}/", client.url::<E>());
+        let id = if self.meta.href.starts_with(&prefix) {
+            self.meta.id()
+        } else {
+            None
+        };
+        let id = id.ok_or_else(|| {
+            MoySkladError::InvalidArgument(format!(
+                "webhook entity href '{}' is not under '{prefix}'",
+                self.meta.href
+            ))
+        })?;
+        client.get::<E>(id).await.map(Some)
+    }
+}
+/// Тело запроса вебхука на изменение остатков
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookStockPayload {
+    /// ID учетной записи
+    pub account_id: Uuid,
+    /// Вид остатка
+    pub stock_type: StockType,
+    /// Вид отчета
+    pub report_type: StockReportType,
+    /// Ссылка на краткий отчет об остатках по изменившимся товарам
+    pub report_url: String,
+}
+impl WebhookStockPayload {
+    /// Разобрать тело запроса
+    pub fn from_slice(body: &[u8]) -> Result<Self> {
+        serde_json::from_slice(body).map_err(|source| MoySkladError::Deserialize {
+            source,
+            body: String::from_utf8_lossy(body).into_owned(),
+        })
+    }
+    /// Загрузить остатки по ссылке `reportUrl`
+    ///
+    /// Параметры отчета разбираются из ссылки и запрашиваются через
+    /// [`MoySkladApiClient::get_current_stock`], поэтому токен не уходит по адресу из тела вебхука.
+    /// Ссылка вне `{base_url}/report/stock/` отклоняется с [`MoySkladError::InvalidArgument`]
+    pub async fn fetch(&self, client: &MoySkladApiClient) -> Result<Vec<CurrentStock>> {
+        let query = self.current_stock_query(client.base_url())?;
+        client.get_current_stock(&query).await
+    }
+    /// Параметры краткого отчета из ссылки `reportUrl`
+    fn current_stock_query(&self, base_url: &str) -> Result<CurrentStockQuery> {
+        let invalid = |reason: &str| {
+            MoySkladError::InvalidArgument(format!(
+                "unsupported stock report url '{}': {reason}",
+                self.report_url
+            ))
+        };
+        let report = self
+            .report_url
+            .strip_prefix(&format!("{base_url}/report/stock/"))
+            .ok_or_else(|| invalid("not under the client base url"))?;
+        let mut query = CurrentStockQuery::new();
+        query.stock_type(self.stock_type);
+        match report.split('?').next() {
+            Some("all/current") => query.by_store(false),
+            Some("bystore/current") => query.by_store(true),
+            _ => return Err(invalid("unknown report")),
+        };
+        let url = reqwest::Url::parse(&self.report_url).map_err(|_| invalid("malformed url"))?;
+        for (key, value) in url.query_pairs() {
+            match key.as_ref() {
+                "stockType" => {
+                    let stock_type = StockType::deserialize(value.as_ref().into_deserializer())
+                        .map_err(|_: serde::de::value::Error| invalid("unknown stockType"))?;
+                    query.stock_type(stock_type);
+                }
+                "include" if value == "zeroLines" => {
+                    query.include_zero_lines(true);
+                }
+                "changedSince" => {
+                    let changed_since =
+                        NaiveDateTime::parse_from_str(&value, "%Y-%m-%d %H:%M:%S%.f")
+                            .map_err(|_| invalid("malformed changedSince"))?;
+                    query.changed_since(changed_since);
+                }
+                "filter" => {
+                    for filter in value.split(';') {
+                        match filter.split_once('=') {
+                            Some(("assortmentId", id)) => {
+                                query.assortment_id(
+                                    id.parse().map_err(|_| invalid("malformed assortmentId"))?,
+                                );
+                            }
+                            Some(("storeId", id)) => {
+                                query.store_id(
+                                    id.parse().map_err(|_| invalid("malformed storeId"))?,
+                                );
+                            }
+                            _ => return Err(invalid("unknown filter")),
+                        }
+                    }
+                }
+                _ => return Err(invalid("unknown parameter")),
+            }
+        }
+        Ok(query.build())
+    }
+}
@@ -0,0 +1,278 @@
+use reqwest::StatusCode;
+use rust_moysklad::{
+    MoySkladApiClient, MoySkladError, MsEntity, Permission, PermissionScope, Query, Uom,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use uuid::Uuid;
+use wiremock::{
+    matchers::{body_json, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const TOKEN: &str = "test-token";
+
+fn client(server: &MockServer) -> MoySkladApiClient {
+    MoySkladApiClient::builder(TOKEN)
+        .base_url(server.uri())
+        .max_retries(0)
+        .build()
+        .expect("client")
+}
+
+fn uom_json(server: &MockServer, id: Uuid, name: &str) -> Value {
+    json!({
+        "meta": {
+            "href": format!("{}/entity/uom/{id}", server.uri()),
+            "type": "uom",
+            "mediaType": "application/json"
+        },
+        "id": id,
+        "name": name,
+        "code": "055",
+        "description": name,
+        "externalCode": "055",
+        "updated": "2024-05-01 12:00:00.000"
+    })
+}
+
+fn page(server: &MockServer, size: usize, rows: Vec<Value>) -> Value {
+    json!({
+        "meta": {
+            "href": format!("{}/entity/uom", server.uri()),
+            "type": "uom",
+            "mediaType": "application/json",
+            "size": size,
+        },
+        "rows": rows
+    })
+}
+
+#[tokio::test]
+async fn get_sends_token_and_parses_entity() {
+    let server = MockServer::start().await;
+    let id = Uuid::from_u128(42);
+    Mock::given(method("GET"))
+        .and(path(format!("/entity/uom/{id}")))
+        .and(header("authorization", format!("Bearer {TOKEN}").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(uom_json(&server, id, "м2")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let uom = client(&server).get::<Uom>(id).await.unwrap();
+
+    assert_eq!(uom.id, id);
+    assert_eq!(uom.name, "м2");
+    assert_eq!(uom.meta.id(), Some(id));
+}
+
+#[tokio::test]
+async fn create_posts_body_to_collection() {
+    let server = MockServer::start().await;
+    let id = Uuid::from_u128(42);
+    let body = json!({ "name": "рулон", "code": "736" });
+    Mock::given(method("POST"))
+        .and(path("/entity/uom"))
+        .and(body_json(&body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(uom_json(&server, id, "рулон")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let created: Uom = client(&server).create(body).await.unwrap();
+
+    assert_eq!(created.id, id);
+    assert_eq!(created.name, "рулон");
+}
+
+#[tokio::test]
+async fn update_puts_body_to_entity() {
+    let server = MockServer::start().await;
+    let id = Uuid::from_u128(42);
+    let body = json!({ "name": "погонный метр" });
+    Mock::given(method("PUT"))
+        .and(path(format!("/entity/uom/{id}")))
+        .and(body_json(&body))
+        .respond_with(ResponseTemplate::new(200).set_body_json(uom_json(
+            &server,
+            id,
+            "погонный метр",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let updated: Uom = client(&server).update(id, body).await.unwrap();
+
+    assert_eq!(updated.name, "погонный метр");
+}
+
+#[tokio::test]
+async fn get_all_with_walks_every_page() {
+    let server = MockServer::start().await;
+    let ids: Vec<Uuid> = (1..=3).map(Uuid::from_u128).collect();
+    Mock::given(method("GET"))
+        .and(path("/entity/uom"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "0"))
+        .and(query_param("search", "метр"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(
+            &server,
+            3,
+            vec![
+                uom_json(&server, ids[0], "метр"),
+                uom_json(&server, ids[1], "квадратный метр"),
+            ],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/entity/uom"))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(
+            &server,
+            3,
+            vec![uom_json(&server, ids[2], "кубический метр")],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut query = Query::new();
+    query.search("метр").limit(2);
+    let uoms = client(&server).get_all_with::<Uom>(&query).await.unwrap();
+
+    assert_eq!(uoms.iter().map(|u| u.id).collect::<Vec<_>>(), ids);
+}
+
+#[tokio::test]
+async fn api_error_response_is_typed() {
+    let server = MockServer::start().await;
+    let id = Uuid::from_u128(42);
+    Mock::given(method("GET"))
+        .and(path(format!("/entity/uom/{id}")))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "errors": [{
+                "error": "Объект с UUID не найден",
+                "code": 1021,
+                "moreInfo": "https://dev.moysklad.ru/doc/api/remap/1.2/#error_1021"
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let error = client(&server).get::<Uom>(id).await.unwrap_err();
+
+    assert_eq!(error.status(), Some(StatusCode::NOT_FOUND));
+    match error {
+        MoySkladError::Api { errors, .. } => assert_eq!(errors[0].code, Some(1021)),
+        other => panic!("expected API error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn status_error_keeps_body() {
+    let server = MockServer::start().await;
+    let id = Uuid::from_u128(42);
+    Mock::given(method("GET"))
+        .and(path(format!("/entity/uom/{id}")))
+        .respond_with(ResponseTemplate::new(500).set_body_string("internal error"))
+        .mount(&server)
+        .await;
+
+    let error = client(&server).get::<Uom>(id).await.unwrap_err();
+
+    match error {
+        MoySkladError::Status { status, body } => {
+            assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+            assert_eq!(body, "internal error");
+        }
+        other => panic!("expected status error, got {other:?}"),
+    }
+}
+
+/// Сущность, определенная вне библиотеки
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CustomUom {
+    id: Uuid,
+    name: String,
+}
+impl MsEntity for CustomUom {
+    fn path() -> String {
+        String::from("entity/uom")
+    }
+}
+
+#[tokio::test]
+async fn custom_entity_uses_configured_base_url() {
+    let server = MockServer::start().await;
+    let id = Uuid::from_u128(42);
+    Mock::given(method("GET"))
+        .and(path(format!("/entity/uom/{id}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(uom_json(&server, id, "шт")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let uom = client(&server).get::<CustomUom>(id).await.unwrap();
+
+    assert_eq!(uom.name, "шт");
+    assert_eq!(CustomUom::entity_type(), "uom");
+    #[allow(deprecated)]
+    let url = CustomUom::url();
+    assert_eq!(url, "https://api.moysklad.ru/api/remap/1.2/entity/uom");
+}
+
+#[tokio::test]
+async fn current_employee_reads_context() {
+    let server = MockServer::start().await;
+    let id = Uuid::from_u128(2);
+    Mock::given(method("GET"))
+        .and(path("/context/employee"))
+        .and(header("authorization", format!("Bearer {TOKEN}").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": {
+                "href": format!("{}/context/employee", server.uri()),
+                "type": "employee",
+                "mediaType": "application/json"
+            },
+            "id": id,
+            "accountId": Uuid::from_u128(1),
+            "shared": true,
+            "group": {
+                "meta": {
+                    "href": format!("{}/entity/group/{}", server.uri(), Uuid::from_u128(3)),
+                    "type": "group",
+                    "mediaType": "application/json"
+                }
+            },
+            "updated": "2024-05-01 12:00:00.000",
+            "created": "2023-01-10 09:00:00.000",
+            "name": "Администратор",
+            "externalCode": "admin",
+            "archived": false,
+            "uid": "admin@romashka",
+            "lastName": "Администратор",
+            "permissions": {
+                "product": { "view": "ALL", "create": "OWN" },
+                "viewDashboard": true
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let employee = client(&server).current_employee().await.unwrap();
+
+    assert_eq!(employee.id, id);
+    assert_eq!(employee.uid.as_deref(), Some("admin@romashka"));
+    let permissions = employee.permissions.unwrap();
+    assert_eq!(permissions["viewDashboard"], Permission::Flag(true));
+    assert!(matches!(
+        &permissions["product"],
+        Permission::Entity(product) if product.create == Some(PermissionScope::Own)
+    ));
+}
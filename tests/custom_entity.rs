@@ -0,0 +1,263 @@
+use rust_moysklad::{CustomEntity, MoySkladApiClient, Query};
+use serde_json::{json, Value};
+use uuid::Uuid;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn client(server: &MockServer) -> MoySkladApiClient {
+    MoySkladApiClient::builder("test-token")
+        .base_url(server.uri())
+        .max_retries(0)
+        .build()
+        .expect("client")
+}
+
+fn meta_json(server: &MockServer, path: &str, meta_type: &str) -> Value {
+    json!({
+        "href": format!("{}/{path}", server.uri()),
+        "type": meta_type,
+        "mediaType": "application/json"
+    })
+}
+
+fn dictionary_json(server: &MockServer, id: Uuid, name: &str) -> Value {
+    json!({
+        "meta": meta_json(server, &format!("entity/customentity/{id}"), "customentity"),
+        "id": id,
+        "name": name
+    })
+}
+
+fn element_json(server: &MockServer, dictionary_id: Uuid, id: Uuid, name: &str) -> Value {
+    json!({
+        "meta": meta_json(
+            server,
+            &format!("entity/customentity/{dictionary_id}/{id}"),
+            "customentity"
+        ),
+        "id": id,
+        "accountId": Uuid::from_u128(1),
+        "owner": { "meta": meta_json(server, &format!("entity/employee/{}", Uuid::from_u128(2)), "employee") },
+        "shared": true,
+        "group": { "meta": meta_json(server, &format!("entity/group/{}", Uuid::from_u128(3)), "group") },
+        "updated": "2024-05-01 12:00:00.000",
+        "name": name,
+        "code": "red",
+        "externalCode": "ext-red"
+    })
+}
+
+#[tokio::test]
+async fn get_custom_dictionaries_reads_company_settings() {
+    let server = MockServer::start().await;
+    let dictionary_id = Uuid::from_u128(10);
+    Mock::given(method("GET"))
+        .and(path("/context/companysettings/metadata"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": meta_json(&server, "context/companysettings/metadata", "companysettings"),
+            "customEntities": [{
+                "meta": meta_json(
+                    &server,
+                    &format!("context/companysettings/metadata/customEntities/{dictionary_id}"),
+                    "customentitymetadata"
+                ),
+                "entityMeta": meta_json(
+                    &server,
+                    &format!("entity/customentity/{dictionary_id}"),
+                    "customentity"
+                ),
+                "name": "Цвета",
+                "createShared": true
+            }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dictionaries = client(&server).get_custom_dictionaries().await.unwrap();
+
+    assert_eq!(dictionaries.len(), 1);
+    assert_eq!(dictionaries[0].name, "Цвета");
+    assert_eq!(dictionaries[0].id(), Some(dictionary_id));
+    assert_eq!(dictionaries[0].create_shared, Some(true));
+}
+
+#[tokio::test]
+async fn dictionary_create_rename_delete() {
+    let server = MockServer::start().await;
+    let dictionary_id = Uuid::from_u128(10);
+    let dictionary_path = format!("/entity/customentity/{dictionary_id}");
+    Mock::given(method("POST"))
+        .and(path("/entity/customentity"))
+        .and(body_json(json!({ "name": "Цвета" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(dictionary_json(
+            &server,
+            dictionary_id,
+            "Цвета",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(dictionary_path.as_str()))
+        .and(body_json(json!({ "name": "Оттенки" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(dictionary_json(
+            &server,
+            dictionary_id,
+            "Оттенки",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(dictionary_path.as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    let created = client.create_custom_dictionary("Цвета").await.unwrap();
+    let renamed = client
+        .rename_custom_dictionary(created.id, "Оттенки")
+        .await
+        .unwrap();
+    client.delete_custom_dictionary(created.id).await.unwrap();
+
+    assert_eq!(created.id, dictionary_id);
+    assert_eq!(renamed.name, "Оттенки");
+}
+
+#[tokio::test]
+async fn get_custom_elements_walks_every_page() {
+    let server = MockServer::start().await;
+    let dictionary_id = Uuid::from_u128(10);
+    let ids: Vec<Uuid> = (11..=13).map(Uuid::from_u128).collect();
+    let page = |rows: Vec<Value>| {
+        json!({
+            "meta": {
+                "href": format!("{}/entity/customentity/{dictionary_id}", server.uri()),
+                "type": "customentity",
+                "mediaType": "application/json",
+                "size": 3
+            },
+            "rows": rows
+        })
+    };
+    Mock::given(method("GET"))
+        .and(path(format!("/entity/customentity/{dictionary_id}")))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(vec![
+            element_json(&server, dictionary_id, ids[0], "Красный"),
+            element_json(&server, dictionary_id, ids[1], "Зеленый"),
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/entity/customentity/{dictionary_id}")))
+        .and(query_param("limit", "2"))
+        .and(query_param("offset", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(page(vec![element_json(
+                &server,
+                dictionary_id,
+                ids[2],
+                "Синий",
+            )])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut query = Query::new();
+    query.limit(2);
+    let elements = client(&server)
+        .get_custom_elements(dictionary_id, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(elements.iter().map(|e| e.id).collect::<Vec<_>>(), ids);
+}
+
+#[tokio::test]
+async fn element_crud_uses_dictionary_path() {
+    let server = MockServer::start().await;
+    let dictionary_id = Uuid::from_u128(10);
+    let element_id = Uuid::from_u128(11);
+    let elements_path = format!("/entity/customentity/{dictionary_id}");
+    let element_path = format!("{elements_path}/{element_id}");
+    Mock::given(method("POST"))
+        .and(path(elements_path.as_str()))
+        .and(body_json(json!({ "name": "Красный", "code": "red" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(element_json(
+            &server,
+            dictionary_id,
+            element_id,
+            "Красный",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(element_path.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(element_json(
+            &server,
+            dictionary_id,
+            element_id,
+            "Красный",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(element_path.as_str()))
+        .and(body_json(json!({ "name": "Алый" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(element_json(
+            &server,
+            dictionary_id,
+            element_id,
+            "Алый",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(element_path.as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    let created = client
+        .create_custom_element(
+            dictionary_id,
+            CustomEntity::create("Красный").code("red").build(),
+        )
+        .await
+        .unwrap();
+    let element = client
+        .get_custom_element(dictionary_id, element_id)
+        .await
+        .unwrap();
+    let renamed = client
+        .update_custom_element(
+            dictionary_id,
+            element_id,
+            CustomEntity::update().name("Алый").build(),
+        )
+        .await
+        .unwrap();
+    client
+        .delete_custom_element(dictionary_id, element_id)
+        .await
+        .unwrap();
+
+    assert_eq!(created.id, element_id);
+    assert_eq!(element.code.as_deref(), Some("red"));
+    assert_eq!(renamed.name, "Алый");
+}
@@ -0,0 +1,273 @@
+use rust_moysklad::{
+    CustomerOrder, CustomerOrderPosition, Demand, Meta, MoySkladApiClient, Template,
+};
+use serde_json::{json, Value};
+use uuid::Uuid;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn client(server: &MockServer) -> MoySkladApiClient {
+    MoySkladApiClient::builder("test-token")
+        .base_url(server.uri())
+        .max_retries(0)
+        .build()
+        .expect("client")
+}
+
+fn meta_json(server: &MockServer, path: &str) -> Value {
+    json!({
+        "href": format!("{}/entity/{path}", server.uri()),
+        "type": path.split('/').next(),
+        "mediaType": "application/json"
+    })
+}
+
+fn meta(server: &MockServer, path: &str) -> Meta {
+    serde_json::from_value(meta_json(server, path)).unwrap()
+}
+
+fn order_position_json(server: &MockServer, order_id: Uuid, id: Uuid, quantity: f64) -> Value {
+    json!({
+        "meta": meta_json(server, &format!("customerorder/{order_id}/positions/{id}")),
+        "id": id,
+        "accountId": Uuid::from_u128(1),
+        "quantity": quantity,
+        "price": 150000.0,
+        "discount": 0.0,
+        "vat": 20,
+        "vatEnabled": true,
+        "assortment": { "meta": meta_json(server, &format!("product/{}", Uuid::from_u128(9))) },
+        "shipped": 0.0,
+        "reserve": quantity
+    })
+}
+
+#[tokio::test]
+async fn get_positions_reads_positions_collection() {
+    let server = MockServer::start().await;
+    let order_id = Uuid::from_u128(100);
+    let ids: Vec<Uuid> = (101..=102).map(Uuid::from_u128).collect();
+    Mock::given(method("GET"))
+        .and(path(format!("/entity/customerorder/{order_id}/positions")))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": {
+                "href": format!("{}/entity/customerorder/{order_id}/positions", server.uri()),
+                "type": "customerorderposition",
+                "mediaType": "application/json",
+                "size": 2,
+                "limit": 1000,
+                "offset": 0
+            },
+            "rows": ids
+                .iter()
+                .map(|id| order_position_json(&server, order_id, *id, 1.0))
+                .collect::<Vec<_>>()
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let positions = client(&server)
+        .get_positions::<CustomerOrder>(order_id)
+        .await
+        .unwrap();
+
+    assert_eq!(positions.iter().map(|p| p.id).collect::<Vec<_>>(), ids);
+    assert_eq!(positions[0].reserve, Some(1.0));
+}
+
+#[tokio::test]
+async fn position_crud_uses_positions_sub_resource() {
+    let server = MockServer::start().await;
+    let order_id = Uuid::from_u128(100);
+    let position_id = Uuid::from_u128(101);
+    let product = meta(&server, &format!("product/{}", Uuid::from_u128(9)));
+    let positions_path = format!("/entity/customerorder/{order_id}/positions");
+    let position_path = format!("{positions_path}/{position_id}");
+    Mock::given(method("POST"))
+        .and(path(positions_path.as_str()))
+        .and(body_json(json!([{
+            "assortment": { "meta": serde_json::to_value(&product).unwrap() },
+            "quantity": 2.0,
+            "reserve": 2.0
+        }])))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([order_position_json(
+                &server,
+                order_id,
+                position_id,
+                2.0
+            )])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(position_path.as_str()))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(order_position_json(
+                &server,
+                order_id,
+                position_id,
+                2.0,
+            )),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(position_path.as_str()))
+        .and(body_json(json!({ "quantity": 3.0 })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(order_position_json(
+                &server,
+                order_id,
+                position_id,
+                3.0,
+            )),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(position_path.as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    let added = client
+        .add_positions::<CustomerOrder, _>(
+            order_id,
+            vec![CustomerOrderPosition::create(&product, 2.0)
+                .reserve(2.0)
+                .build()],
+        )
+        .await
+        .unwrap();
+    let position = client
+        .get_position::<CustomerOrder>(order_id, position_id)
+        .await
+        .unwrap();
+    let updated = client
+        .update_position::<CustomerOrder, _>(
+            order_id,
+            position_id,
+            CustomerOrderPosition::update().quantity(3.0).build(),
+        )
+        .await
+        .unwrap();
+    client
+        .delete_position::<CustomerOrder>(order_id, position_id)
+        .await
+        .unwrap();
+
+    assert_eq!(added[0].id, position_id);
+    assert_eq!(position.quantity, 2.0);
+    assert_eq!(updated.quantity, 3.0);
+}
+
+#[tokio::test]
+async fn template_puts_base_document_to_new() {
+    let server = MockServer::start().await;
+    let order = meta(&server, &format!("customerorder/{}", Uuid::from_u128(100)));
+    let prefilled = json!({
+        "customerOrder": { "meta": serde_json::to_value(&order).unwrap() },
+        "sum": 300000.0,
+        "positions": { "rows": [] }
+    });
+    Mock::given(method("PUT"))
+        .and(path("/entity/demand/new"))
+        .and(body_json(json!({
+            "customerOrder": { "meta": serde_json::to_value(&order).unwrap() }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&prefilled))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let template = client(&server)
+        .template::<Demand>(&Template::customer_order(&order))
+        .await
+        .unwrap();
+
+    assert_eq!(template, prefilled);
+}
+
+fn demand_json(server: &MockServer, id: Uuid, order: &Meta) -> Value {
+    json!({
+        "meta": meta_json(server, &format!("demand/{id}")),
+        "id": id,
+        "accountId": Uuid::from_u128(1),
+        "shared": false,
+        "group": { "meta": meta_json(server, &format!("group/{}", Uuid::from_u128(3))) },
+        "updated": "2024-05-02 10:00:00.000",
+        "name": "00017",
+        "externalCode": "ext-17",
+        "moment": "2024-05-02 09:58:00.000",
+        "applicable": true,
+        "rate": { "currency": { "meta": meta_json(server, &format!("currency/{}", Uuid::from_u128(4))) } },
+        "sum": 300000.0,
+        "store": { "meta": meta_json(server, &format!("store/{}", Uuid::from_u128(5))) },
+        "agent": { "meta": meta_json(server, &format!("counterparty/{}", Uuid::from_u128(6))) },
+        "organization": { "meta": meta_json(server, &format!("organization/{}", Uuid::from_u128(7))) },
+        "created": "2024-05-02 09:58:30.000",
+        "printed": false,
+        "published": false,
+        "customerOrder": { "meta": order },
+        "positions": {
+            "meta": {
+                "href": format!("{}/entity/demand/{id}/positions", server.uri()),
+                "type": "demandposition",
+                "mediaType": "application/json",
+                "size": 2
+            }
+        },
+        "vatEnabled": true,
+        "vatSum": 50000.0,
+        "payedSum": 0.0
+    })
+}
+
+#[tokio::test]
+async fn create_from_template_posts_prefilled_document() {
+    let server = MockServer::start().await;
+    let order = meta(&server, &format!("customerorder/{}", Uuid::from_u128(100)));
+    let demand_id = Uuid::from_u128(200);
+    let prefilled = json!({
+        "customerOrder": { "meta": serde_json::to_value(&order).unwrap() },
+        "agent": { "meta": meta_json(&server, &format!("counterparty/{}", Uuid::from_u128(6))) },
+        "sum": 300000.0
+    });
+    Mock::given(method("PUT"))
+        .and(path("/entity/demand/new"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&prefilled))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/entity/demand"))
+        .and(body_json(&prefilled))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(demand_json(&server, demand_id, &order)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let demand: Demand = client(&server)
+        .create_from_template(&Template::customer_order(&order))
+        .await
+        .unwrap();
+
+    assert_eq!(demand.id, demand_id);
+    assert_eq!(
+        demand.customer_order.and_then(|order| order.id()),
+        Some(Uuid::from_u128(100))
+    );
+    assert_eq!(demand.positions.meta.size, Some(2));
+}
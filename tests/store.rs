@@ -0,0 +1,204 @@
+use rust_moysklad::{Meta, MoySkladApiClient, StoreSlot, StoreZone};
+use serde_json::{json, Value};
+use uuid::Uuid;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn client(server: &MockServer) -> MoySkladApiClient {
+    MoySkladApiClient::builder("test-token")
+        .base_url(server.uri())
+        .max_retries(0)
+        .build()
+        .expect("client")
+}
+
+fn meta_json(server: &MockServer, path: &str) -> Value {
+    json!({
+        "href": format!("{}/entity/{path}", server.uri()),
+        "type": path.split('/').next(),
+        "mediaType": "application/json"
+    })
+}
+
+fn zone_json(server: &MockServer, store_id: Uuid, id: Uuid, name: &str) -> Value {
+    json!({
+        "meta": meta_json(server, &format!("store/{store_id}/zones/{id}")),
+        "id": id,
+        "accountId": Uuid::from_u128(1),
+        "name": name,
+        "externalCode": "zone-1",
+        "updated": "2024-05-01 12:00:00.000"
+    })
+}
+
+fn slot_json(server: &MockServer, store_id: Uuid, id: Uuid, zone_id: Uuid, name: &str) -> Value {
+    json!({
+        "meta": meta_json(server, &format!("store/{store_id}/slots/{id}")),
+        "id": id,
+        "accountId": Uuid::from_u128(1),
+        "name": name,
+        "externalCode": "slot-1",
+        "barcode": "2000000000015",
+        "zone": { "meta": meta_json(server, &format!("store/{store_id}/zones/{zone_id}")) },
+        "updated": "2024-05-01 12:00:00.000"
+    })
+}
+
+#[tokio::test]
+async fn zone_crud_uses_store_zones() {
+    let server = MockServer::start().await;
+    let store_id = Uuid::from_u128(10);
+    let zone_id = Uuid::from_u128(11);
+    let zones_path = format!("/entity/store/{store_id}/zones");
+    let zone_path = format!("{zones_path}/{zone_id}");
+    Mock::given(method("POST"))
+        .and(path(zones_path.as_str()))
+        .and(body_json(json!({ "name": "Стеллажи" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(zone_json(
+            &server,
+            store_id,
+            zone_id,
+            "Стеллажи",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(zones_path.as_str()))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "meta": {
+                "href": format!("{}{zones_path}", server.uri()),
+                "type": "storezone",
+                "mediaType": "application/json",
+                "size": 1,
+                "limit": 1000,
+                "offset": 0
+            },
+            "rows": [zone_json(&server, store_id, zone_id, "Стеллажи")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(zone_path.as_str()))
+        .and(body_json(json!({ "name": "Паллеты" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(zone_json(
+            &server,
+            store_id,
+            zone_id,
+            "Паллеты",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(zone_path.as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    let created: StoreZone = client
+        .create_nested(store_id, StoreZone::create("Стеллажи").build())
+        .await
+        .unwrap();
+    let zones = client.get_all_nested::<StoreZone>(store_id).await.unwrap();
+    let updated: StoreZone = client
+        .update_nested(
+            store_id,
+            zone_id,
+            StoreZone::update().name("Паллеты").build(),
+        )
+        .await
+        .unwrap();
+    client
+        .delete_nested::<StoreZone>(store_id, zone_id)
+        .await
+        .unwrap();
+
+    assert_eq!(created.id, zone_id);
+    assert_eq!(zones.len(), 1);
+    assert_eq!(updated.name, "Паллеты");
+}
+
+#[tokio::test]
+async fn slot_crud_uses_store_slots() {
+    let server = MockServer::start().await;
+    let store_id = Uuid::from_u128(10);
+    let zone_id = Uuid::from_u128(11);
+    let slot_id = Uuid::from_u128(12);
+    let zone: Meta = serde_json::from_value(meta_json(
+        &server,
+        &format!("store/{store_id}/zones/{zone_id}"),
+    ))
+    .unwrap();
+    let slots_path = format!("/entity/store/{store_id}/slots");
+    let slot_path = format!("{slots_path}/{slot_id}");
+    Mock::given(method("POST"))
+        .and(path(slots_path.as_str()))
+        .and(body_json(json!({
+            "name": "A-01",
+            "zone": { "meta": serde_json::to_value(&zone).unwrap() }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(slot_json(&server, store_id, slot_id, zone_id, "A-01")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(slot_path.as_str()))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(slot_json(&server, store_id, slot_id, zone_id, "A-01")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(slot_path.as_str()))
+        .and(body_json(json!({ "barcode": "2000000000022" })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(slot_json(&server, store_id, slot_id, zone_id, "A-01")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(slot_path.as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let client = client(&server);
+
+    let created: StoreSlot = client
+        .create_nested(store_id, StoreSlot::create("A-01").zone(&zone).build())
+        .await
+        .unwrap();
+    let slot = client
+        .get_nested::<StoreSlot>(store_id, slot_id)
+        .await
+        .unwrap();
+    client
+        .update_nested::<StoreSlot, _>(
+            store_id,
+            slot_id,
+            StoreSlot::update().barcode("2000000000022").build(),
+        )
+        .await
+        .unwrap();
+    client
+        .delete_nested::<StoreSlot>(store_id, slot_id)
+        .await
+        .unwrap();
+
+    assert_eq!(created.id, slot_id);
+    assert_eq!(slot.zone.as_ref().and_then(|zone| zone.id()), Some(zone_id));
+}
@@ -0,0 +1,275 @@
+#![cfg(feature = "webhook")]
+
+use chrono::NaiveDate;
+use rust_moysklad::{
+    reports::stock::StockType,
+    webhook::{WebhookPayload, WebhookStockPayload},
+    MoySkladApiClient, MoySkladError, Product, StockReportType, Uom, WebhookAction,
+};
+use serde_json::{json, Value};
+use uuid::Uuid;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const TOKEN: &str = "test-token";
+
+fn client(server: &MockServer) -> MoySkladApiClient {
+    MoySkladApiClient::builder(TOKEN)
+        .base_url(server.uri())
+        .max_retries(0)
+        .build()
+        .expect("client")
+}
+
+fn event_body(href: &str) -> Vec<u8> {
+    json!({
+        "events": [{
+            "meta": { "type": "uom", "href": href, "mediaType": "application/json" },
+            "action": "CREATE",
+            "accountId": Uuid::from_u128(1)
+        }]
+    })
+    .to_string()
+    .into_bytes()
+}
+
+fn uom_json(server: &MockServer, id: Uuid) -> Value {
+    json!({
+        "meta": {
+            "href": format!("{}/entity/uom/{id}", server.uri()),
+            "type": "uom",
+            "mediaType": "application/json"
+        },
+        "id": id,
+        "name": "шт",
+        "code": "796",
+        "description": "Штука",
+        "externalCode": "796",
+        "updated": "2024-05-01 12:00:00.000"
+    })
+}
+
+#[tokio::test]
+async fn event_fetch_loads_entity_from_client_base_url() {
+    let server = MockServer::start().await;
+    let id = Uuid::from_u128(42);
+    Mock::given(method("GET"))
+        .and(path(format!("/entity/uom/{id}")))
+        .and(header("authorization", format!("Bearer {TOKEN}").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(uom_json(&server, id)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let payload =
+        WebhookPayload::from_slice(&event_body(&format!("{}/entity/uom/{id}", server.uri())))
+            .unwrap();
+
+    let uom = payload.events[0]
+        .fetch::<Uom>(&client(&server))
+        .await
+        .unwrap();
+
+    assert_eq!(uom.map(|uom| uom.id), Some(id));
+}
+
+#[tokio::test]
+async fn event_fetch_rejects_foreign_host() {
+    let server = MockServer::start().await;
+    let foreign = MockServer::start().await;
+    let id = Uuid::from_u128(42);
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(uom_json(&foreign, id)))
+        .expect(0)
+        .mount(&foreign)
+        .await;
+    let payload =
+        WebhookPayload::from_slice(&event_body(&format!("{}/entity/uom/{id}", foreign.uri())))
+            .unwrap();
+
+    let error = payload.events[0]
+        .fetch::<Uom>(&client(&server))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, MoySkladError::InvalidArgument(_)));
+}
+
+#[tokio::test]
+async fn event_fetch_skips_other_entity_types() {
+    let server = MockServer::start().await;
+    let payload =
+        WebhookPayload::from_slice(&event_body("https://example.com/entity/uom/1")).unwrap();
+    let mut event = payload.events[0].clone();
+    event.meta.meta_type = Some("product".to_string());
+
+    assert!(event
+        .fetch::<Uom>(&client(&server))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[test]
+fn payload_parses_documented_body() {
+    let body = br#"{
+        "auditContext": {
+            "meta": {
+                "type": "audit",
+                "href": "https://api.moysklad.ru/api/remap/1.2/audit/0fb1e2a2-fa44-11e9-ac12-000800000001"
+            },
+            "uid": "admin@admin",
+            "moment": "2019-10-29 14:20:05"
+        },
+        "events": [
+            {
+                "meta": {
+                    "type": "product",
+                    "href": "https://api.moysklad.ru/api/remap/1.2/entity/product/ef4a7f05-fa44-11e9-ac12-000800000002"
+                },
+                "updatedFields": ["minPrice", "salePrices"],
+                "action": "UPDATE",
+                "accountId": "84e60e93-f2d1-11e9-ac12-000800000000"
+            },
+            {
+                "meta": {
+                    "type": "customerorder",
+                    "href": "https://api.moysklad.ru/api/remap/1.2/entity/customerorder/ef4a7f05-fa44-11e9-ac12-000800000003"
+                },
+                "action": "CREATE",
+                "accountId": "84e60e93-f2d1-11e9-ac12-000800000000"
+            }
+        ]
+    }"#;
+
+    let payload = WebhookPayload::from_slice(body).unwrap();
+
+    let context = payload.audit_context.unwrap();
+    assert_eq!(context.uid, "admin@admin");
+    assert_eq!(
+        context.moment,
+        NaiveDate::from_ymd_opt(2019, 10, 29)
+            .unwrap()
+            .and_hms_opt(14, 20, 5)
+            .unwrap()
+    );
+    let [update, create] = payload.events.as_slice() else {
+        panic!("expected two events");
+    };
+    assert_eq!(update.action, WebhookAction::Update);
+    assert_eq!(update.entity_type(), Some("product"));
+    assert!(update.is::<Product>());
+    assert_eq!(
+        update.updated_fields.as_deref(),
+        Some(["minPrice".to_string(), "salePrices".to_string()].as_slice())
+    );
+    assert_eq!(
+        update.entity::<Product>().map(|product| product.id()),
+        Some(Some(Uuid::from_u128(
+            0xef4a7f05_fa44_11e9_ac12_000800000002
+        )))
+    );
+    assert_eq!(create.action, WebhookAction::Create);
+    assert!(create.updated_fields.is_none());
+    assert!(create.entity::<Product>().is_none());
+}
+
+#[test]
+fn payload_parse_error_keeps_body() {
+    let error = WebhookPayload::from_slice(b"{\"events\": 1}").unwrap_err();
+
+    match error {
+        MoySkladError::Deserialize { body, .. } => assert_eq!(body, "{\"events\": 1}"),
+        other => panic!("expected deserialize error, got {other:?}"),
+    }
+}
+
+#[test]
+fn stock_payload_parses_documented_body() {
+    let body = br#"{
+        "accountId": "84e60e93-f2d1-11e9-ac12-000800000000",
+        "stockType": "stock",
+        "reportType": "bystore",
+        "reportUrl": "https://api.moysklad.ru/api/remap/1.2/report/stock/bystore/current?include=zeroLines&changedSince=2023-03-14 17:36:38"
+    }"#;
+
+    let payload = WebhookStockPayload::from_slice(body).unwrap();
+
+    assert_eq!(
+        payload.account_id,
+        Uuid::from_u128(0x84e60e93_f2d1_11e9_ac12_000800000000)
+    );
+    assert_eq!(payload.stock_type, StockType::Stock);
+    assert_eq!(payload.report_type, StockReportType::ByStore);
+    assert!(payload
+        .report_url
+        .ends_with("changedSince=2023-03-14 17:36:38"));
+}
+
+fn stock_payload(report_url: String) -> WebhookStockPayload {
+    WebhookStockPayload {
+        account_id: Uuid::from_u128(1),
+        stock_type: StockType::FreeStock,
+        report_type: StockReportType::All,
+        report_url,
+    }
+}
+
+#[tokio::test]
+async fn stock_fetch_rebuilds_report_query() {
+    let server = MockServer::start().await;
+    let assortment = Uuid::from_u128(7);
+    Mock::given(method("GET"))
+        .and(path("/report/stock/all/current"))
+        .and(header("authorization", format!("Bearer {TOKEN}").as_str()))
+        .and(query_param("stockType", "freeStock"))
+        .and(query_param("include", "zeroLines"))
+        .and(query_param("changedSince", "2023-03-14 17:36:38"))
+        .and(query_param("filter", format!("assortmentId={assortment}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            { "assortmentId": assortment, "freeStock": 3.0 }
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    let payload = stock_payload(format!(
+        "{}/report/stock/all/current?include=zeroLines&changedSince=2023-03-14 17:36:38&filter=assortmentId={assortment}",
+        server.uri()
+    ));
+
+    let stock = payload.fetch(&client(&server)).await.unwrap();
+
+    assert_eq!(stock.len(), 1);
+    assert_eq!(stock[0].assortment_id, assortment);
+    assert_eq!(stock[0].stock, 3.0);
+}
+
+#[tokio::test]
+async fn stock_fetch_rejects_foreign_or_unknown_url() {
+    let server = MockServer::start().await;
+    let foreign = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(0)
+        .mount(&foreign)
+        .await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    for report_url in [
+        format!("{}/report/stock/all/current", foreign.uri()),
+        format!("{}/entity/product", server.uri()),
+        format!("{}/report/stock/all", server.uri()),
+        format!("{}/report/stock/all/current?expand=agent", server.uri()),
+    ] {
+        let error = stock_payload(report_url)
+            .fetch(&client(&server))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, MoySkladError::InvalidArgument(_)));
+    }
+}